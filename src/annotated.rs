@@ -0,0 +1,244 @@
+use std::{
+    borrow::Borrow,
+    cmp::Ordering,
+    hash::{Hash, Hasher},
+    ops::{Deref, DerefMut},
+};
+
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::Timestamp;
+
+/// Where a value, typically a [`Record`](crate::Record), originates from.
+#[derive(Default, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub struct Provenance {
+    /// Object the value was produced from, such as `Record/default/www`.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub source: Option<String>,
+    /// Controller which produced the value.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub controller: Option<String>,
+    /// Last time the value was observed at its source.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub last_seen: Option<Timestamp>,
+}
+
+impl Provenance {
+    /// Returns true if nothing is known about the origin of the value.
+    pub fn is_empty(&self) -> bool {
+        self.source.is_none() && self.controller.is_none() && self.last_seen.is_none()
+    }
+}
+
+/// Value carrying its [`Provenance`], which is ignored when comparing and
+/// hashing, such that annotated records can be stored in sets and maps
+/// without duplicates, while tracking where each of them came from.
+///
+/// Provenance is preserved by [`Zone::insert_annotated`](crate::Zone::insert_annotated),
+/// by merging zones, and by diffing annotated identities with
+/// [`Diff::between`](crate::diff::Diff::between).
+///
+/// Serialized as the value, with an additional `provenance` field. Since
+/// the fields of the value are flattened into the same map, only values
+/// serialized as maps or structs, such as [`Record`](crate::Record), are
+/// supported, while others fail to serialize:
+///
+/// ```yaml
+/// fqdn: www.example.org.
+/// type: A
+/// ttl: 300
+/// rdata: 192.0.2.1
+/// provenance:
+///   source: Record/default/www
+///   controller: kubizone
+///   last_seen: 2024-01-01T00:00:00Z
+/// ```
+#[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub struct Annotated<T> {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub value: T,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Provenance::is_empty")
+    )]
+    pub provenance: Provenance,
+}
+
+impl<T> Annotated<T> {
+    /// Annotates the value with its provenance.
+    pub fn new(value: T, provenance: Provenance) -> Self {
+        Annotated { value, provenance }
+    }
+
+    /// Discards the provenance, returning the value.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+
+    /// Transforms the value, preserving its provenance.
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Annotated<U> {
+        Annotated {
+            value: f(self.value),
+            provenance: self.provenance,
+        }
+    }
+}
+
+impl<T> From<T> for Annotated<T> {
+    fn from(value: T) -> Self {
+        Annotated::new(value, Provenance::default())
+    }
+}
+
+impl<T> Deref for Annotated<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<T> DerefMut for Annotated<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.value
+    }
+}
+
+/// Comparisons ignore the provenance, so annotated values can be looked
+/// up by their value within sets and maps, and be diffed as such.
+impl<T> Borrow<T> for Annotated<T> {
+    fn borrow(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T: PartialEq> PartialEq for Annotated<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<T: Eq> Eq for Annotated<T> {}
+
+impl<T: PartialOrd> PartialOrd for Annotated<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+impl<T: Ord> Ord for Annotated<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value.cmp(&other.value)
+    }
+}
+
+impl<T: Hash> Hash for Annotated<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use crate::{
+        diff::Diff, fqdn, Annotated, MergeStrategy, Provenance, Record, RecordIdent, Zone,
+    };
+
+    fn annotated(source: &str) -> Annotated<Record> {
+        Annotated::new(
+            "www.example.org. 300 IN A 192.0.2.1".parse().unwrap(),
+            Provenance {
+                source: Some(source.to_string()),
+                ..Default::default()
+            },
+        )
+    }
+
+    #[test]
+    fn provenance_is_ignored() {
+        assert_eq!(annotated("Record/default/a"), annotated("Record/default/b"));
+
+        // Inserting an equal value keeps the provenance of the first.
+        let mut set = BTreeSet::new();
+        assert!(set.insert(annotated("Record/default/a")));
+        assert!(!set.insert(annotated("Record/default/b")));
+        assert_eq!(
+            set.first().unwrap().provenance.source.as_deref(),
+            Some("Record/default/a")
+        );
+
+        let ttl = annotated("Record/default/a").map(|record| record.ttl);
+        assert_eq!(*ttl, 300);
+        assert_eq!(ttl.provenance.source.as_deref(), Some("Record/default/a"));
+    }
+
+    #[test]
+    fn preserved_by_zones() {
+        let mut zone = Zone::new(fqdn!("example.org."));
+        zone.insert_annotated(annotated("Record/default/www"))
+            .unwrap();
+
+        let merged = zone
+            .merge(
+                &Zone::new(fqdn!("example.org.")),
+                MergeStrategy::MergeRecordSets,
+            )
+            .unwrap();
+        assert_eq!(merged, zone);
+
+        let record = merged.iter_annotated().next().unwrap();
+        assert_eq!(
+            record.provenance.source.as_deref(),
+            Some("Record/default/www")
+        );
+        assert_eq!(
+            merged.get_annotated(&record.ident()).unwrap().provenance,
+            record.provenance
+        );
+
+        let desired = [annotated("Record/default/www").map(RecordIdent::from)];
+        let diff = Diff::between(&[], &desired);
+        assert_eq!(
+            diff.added.first().unwrap().provenance.source.as_deref(),
+            Some("Record/default/www")
+        );
+        assert!(diff.unified().to_string().contains("+www.example.org. A"));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let record = annotated("Record/default/www");
+        let yaml = serde_yaml::to_string(&record).unwrap();
+
+        assert!(yaml.contains("rdata: 192.0.2.1"));
+        assert!(yaml.contains("source: Record/default/www"));
+
+        let parsed: Annotated<Record> = serde_yaml::from_str(&yaml).unwrap();
+        assert_eq!(parsed.provenance, record.provenance);
+        assert_eq!(parsed.into_inner(), record.into_inner());
+
+        let plain: Annotated<Record> =
+            serde_yaml::from_str("fqdn: www.example.org.\ntype: A\nttl: 300\nrdata: 192.0.2.1\n")
+                .unwrap();
+        assert!(plain.provenance.is_empty());
+    }
+}
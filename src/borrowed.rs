@@ -0,0 +1,340 @@
+use std::{
+    fmt::{Debug, Display},
+    hash::{Hash, Hasher},
+};
+
+use crate::{
+    error::{DomainNameError, FullyQualifiedDomainNameError},
+    segment::{label_offsets, split_labels, strip_root, validate_name_length, DomainSegment},
+    DomainName, FullyQualifiedDomainName, PartiallyQualifiedDomainName,
+};
+
+/// Validates the dot-separated `labels`, without allocating.
+fn validate(labels: &str) -> Result<(), DomainNameError> {
+    for (index, (offset, label)) in label_offsets(labels).enumerate() {
+        let segment =
+            DomainSegment::try_from(label).map_err(|error| DomainNameError::SegmentError {
+                offset,
+                label: label.to_string(),
+                error,
+            })?;
+
+        if index > 0 && segment.is_wildcard() {
+            return Err(DomainNameError::NonLeadingWildcard);
+        }
+    }
+
+    validate_name_length(segments(labels).map(|segment| segment.len()))?;
+
+    Ok(())
+}
+
+/// Decodes each previously validated label into an owned [`DomainSegment`].
+fn segments(labels: &str) -> impl Iterator<Item = DomainSegment> + '_ {
+    split_labels(labels).map(|label| DomainSegment::try_from(label).expect("label was validated"))
+}
+
+/// Hashes the decoded labels exactly like [`FullyQualifiedDomainName`]
+/// does, such that equal views and names produce equal hashes, regardless
+/// of case or how their labels are escaped.
+fn hash_labels<H: Hasher>(labels: &str, state: &mut H) {
+    state.write_usize(split_labels(labels).count());
+    segments(labels).for_each(|segment| segment.hash(state));
+}
+
+/// Borrowed view of a [`FullyQualifiedDomainName`].
+///
+/// The view is validated using the same rules as the owned type, but
+/// without allocating. The case of the original string is preserved,
+/// although comparisons are case-insensitive.
+#[derive(Clone, Copy)]
+pub struct FqdnRef<'a>(&'a str);
+
+impl<'a> FqdnRef<'a> {
+    /// Returns the underlying string, including the trailing dot.
+    pub fn as_str(&self) -> &'a str {
+        self.0
+    }
+
+    /// Iterates over the labels of the domain name.
+    pub fn iter(&self) -> impl Iterator<Item = &'a str> {
+        split_labels(self.labels())
+    }
+
+    /// Labels of the name, without the trailing dot.
+    fn labels(&self) -> &'a str {
+        &self.0[..self.0.len() - 1]
+    }
+
+    /// Length of the domain name as a string, *including* the trailing dot.
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Converts the view into an owned [`FullyQualifiedDomainName`].
+    pub fn to_owned(&self) -> FullyQualifiedDomainName {
+        segments(self.labels()).collect()
+    }
+}
+
+impl<'a> TryFrom<&'a str> for FqdnRef<'a> {
+    type Error = FullyQualifiedDomainNameError;
+
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        let Some(labels) = strip_root(value) else {
+            return Err(FullyQualifiedDomainNameError::DomainIsPartiallyQualified);
+        };
+
+        validate(labels).map_err(|err| match err {
+            DomainNameError::SegmentError {
+                offset,
+                label,
+                error,
+            } => FullyQualifiedDomainNameError::SegmentError {
+                offset,
+                label,
+                error,
+            },
+            DomainNameError::NonLeadingWildcard => {
+                FullyQualifiedDomainNameError::NonLeadingWildcard
+            }
+            DomainNameError::WildcardNotPermitted => {
+                FullyQualifiedDomainNameError::WildcardNotPermitted
+            }
+            DomainNameError::TooLong(length) => FullyQualifiedDomainNameError::TooLong(length),
+            DomainNameError::TooManyLabels(count) => {
+                FullyQualifiedDomainNameError::TooManyLabels(count)
+            }
+        })?;
+
+        Ok(FqdnRef(value))
+    }
+}
+
+impl Display for FqdnRef<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+impl Debug for FqdnRef<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("FqdnRef").field(&self.0).finish()
+    }
+}
+
+/// Views are compared by their decoded labels, like the owned type,
+/// so `A\.b.org.` equals `a\046b.org.`
+impl PartialEq for FqdnRef<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        segments(self.labels()).eq(segments(other.labels()))
+    }
+}
+
+impl Eq for FqdnRef<'_> {}
+
+impl Hash for FqdnRef<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        hash_labels(self.labels(), state)
+    }
+}
+
+impl PartialEq<FullyQualifiedDomainName> for FqdnRef<'_> {
+    fn eq(&self, other: &FullyQualifiedDomainName) -> bool {
+        segments(self.labels()).eq(other.iter().cloned())
+    }
+}
+
+impl From<FqdnRef<'_>> for FullyQualifiedDomainName {
+    fn from(value: FqdnRef<'_>) -> Self {
+        value.to_owned()
+    }
+}
+
+/// Borrowed view of a [`DomainName`], which may be either fully
+/// or partially qualified.
+///
+/// See [`FqdnRef`].
+#[derive(Clone, Copy)]
+pub struct DomainNameRef<'a>(&'a str);
+
+impl<'a> DomainNameRef<'a> {
+    /// Returns the underlying string.
+    pub fn as_str(&self) -> &'a str {
+        self.0
+    }
+
+    /// Returns true if domain is fully qualified.
+    pub fn is_fully_qualified(&self) -> bool {
+        strip_root(self.0).is_some()
+    }
+
+    /// Returns true if domain is only partially qualified.
+    pub fn is_partially_qualified(&self) -> bool {
+        !self.is_fully_qualified()
+    }
+
+    /// Returns [`None`] if partially qualified, or a view of the fully qualified domain otherwise.
+    pub fn as_full(&self) -> Option<FqdnRef<'a>> {
+        self.is_fully_qualified().then_some(FqdnRef(self.0))
+    }
+
+    /// Iterates over the labels of the domain name.
+    pub fn iter(&self) -> impl Iterator<Item = &'a str> {
+        split_labels(self.labels())
+    }
+
+    /// Labels of the name, without any trailing dot.
+    fn labels(&self) -> &'a str {
+        strip_root(self.0).unwrap_or(self.0)
+    }
+
+    /// Length of the domain name as a string.
+    ///
+    /// Note that fully qualified domain names will include the trailing dot
+    /// in this measurement.
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Converts the view into an owned [`DomainName`].
+    pub fn to_owned(&self) -> DomainName {
+        match strip_root(self.0) {
+            Some(labels) => DomainName::Full(segments(labels).collect()),
+            None => DomainName::Partial(segments(self.0).collect::<PartiallyQualifiedDomainName>()),
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a str> for DomainNameRef<'a> {
+    type Error = DomainNameError;
+
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        validate(strip_root(value).unwrap_or(value))?;
+
+        Ok(DomainNameRef(value))
+    }
+}
+
+impl<'a> From<FqdnRef<'a>> for DomainNameRef<'a> {
+    fn from(value: FqdnRef<'a>) -> Self {
+        DomainNameRef(value.0)
+    }
+}
+
+impl Display for DomainNameRef<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+impl Debug for DomainNameRef<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("DomainNameRef").field(&self.0).finish()
+    }
+}
+
+impl PartialEq for DomainNameRef<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.is_fully_qualified() == other.is_fully_qualified()
+            && segments(self.labels()).eq(segments(other.labels()))
+    }
+}
+
+impl Eq for DomainNameRef<'_> {}
+
+impl Hash for DomainNameRef<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.is_fully_qualified().hash(state);
+        hash_labels(self.labels(), state)
+    }
+}
+
+impl From<DomainNameRef<'_>> for DomainName {
+    fn from(value: DomainNameRef<'_>) -> Self {
+        value.to_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::hash_map::DefaultHasher,
+        hash::{Hash, Hasher},
+    };
+
+    use crate::{
+        error::{DomainNameError, FullyQualifiedDomainNameError},
+        DomainName, DomainNameRef, FqdnRef, FullyQualifiedDomainName,
+    };
+
+    #[test]
+    fn fqdn_ref() {
+        let view = FqdnRef::try_from("WWW.Example.org.").unwrap();
+
+        assert_eq!(view.as_str(), "WWW.Example.org.");
+        assert_eq!(view.iter().collect::<Vec<_>>(), ["WWW", "Example", "org"]);
+        assert_eq!(view, FqdnRef::try_from("www.example.org.").unwrap());
+        assert_eq!(
+            view.to_owned(),
+            FullyQualifiedDomainName::try_from("www.example.org.").unwrap()
+        );
+        assert_eq!(view, view.to_owned());
+
+        assert_eq!(
+            FqdnRef::try_from("www.example.org"),
+            Err(FullyQualifiedDomainNameError::DomainIsPartiallyQualified)
+        );
+        assert_eq!(
+            FqdnRef::try_from("www.*.org."),
+            Err(FullyQualifiedDomainNameError::NonLeadingWildcard)
+        );
+        // Compared and hashed by their decoded labels, like the owned type.
+        let escaped = FqdnRef::try_from("A\\.b.org.").unwrap();
+        let hash = |value: &dyn Fn(&mut DefaultHasher)| {
+            let mut hasher = DefaultHasher::new();
+            value(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(escaped, FqdnRef::try_from("a\\046b.org.").unwrap());
+        assert_ne!(escaped, FqdnRef::try_from("a.b.org.").unwrap());
+        assert_eq!(escaped, escaped.to_owned());
+        assert_eq!(
+            hash(&|hasher| escaped.hash(hasher)),
+            hash(&|hasher| escaped.to_owned().hash(hasher))
+        );
+
+        assert!(matches!(
+            FqdnRef::try_from("www..org."),
+            Err(FullyQualifiedDomainNameError::SegmentError { offset: 4, .. })
+        ));
+    }
+
+    #[test]
+    fn domain_name_ref() {
+        let partial = DomainNameRef::try_from("*.Example").unwrap();
+        assert!(partial.is_partially_qualified());
+        assert_eq!(partial.as_full(), None);
+        assert_eq!(
+            partial.to_owned(),
+            DomainName::try_from("*.example").unwrap()
+        );
+
+        let full = DomainNameRef::try_from("example.org.").unwrap();
+        assert_eq!(
+            full.as_full(),
+            Some(FqdnRef::try_from("example.org.").unwrap())
+        );
+        assert_eq!(
+            full.to_owned(),
+            DomainName::try_from("example.org.").unwrap()
+        );
+
+        assert_eq!(
+            DomainNameRef::try_from("example.*"),
+            Err(DomainNameError::NonLeadingWildcard)
+        );
+    }
+}
@@ -0,0 +1,290 @@
+use std::cmp::Ordering;
+
+use crate::{
+    segment::DomainSegment, DomainName, FullyQualifiedDomainName, PartiallyQualifiedDomainName,
+    Pattern, PatternSegment, Record, RecordData, RecordIdent,
+};
+
+/// Produces the fully normalized form of a value.
+///
+/// Two values which canonicalize to the same result describe the same
+/// thing, so higher layers should canonicalize before comparing or
+/// hashing values which may have been constructed without validation
+/// (through [`DomainSegment::new_unchecked`], for example).
+pub trait Canonicalize: Sized {
+    /// Returns the canonical form of `self`.
+    fn canonicalize(&self) -> Self;
+
+    /// Returns true if `self` is already in its canonical form.
+    fn is_canonical(&self) -> bool
+    where
+        Self: PartialEq,
+    {
+        self.canonicalize() == *self
+    }
+}
+
+/// Ordering of values as defined for DNSSEC in RFC 4034, section 6.
+///
+/// This differs from the lexical [`Ord`] implementations, and is the order
+/// required when constructing NSEC chains or computing zone digests.
+pub trait CanonicalOrd {
+    /// Compares `self` and `other` in canonical DNS order.
+    fn canonical_cmp(&self, other: &Self) -> Ordering;
+}
+
+impl CanonicalOrd for DomainSegment {
+    /// Labels are compared as case-insensitive octet strings.
+    fn canonical_cmp(&self, other: &Self) -> Ordering {
+        let left = self.as_raw().iter().map(u8::to_ascii_lowercase);
+        let right = other.as_raw().iter().map(u8::to_ascii_lowercase);
+
+        left.cmp(right)
+    }
+}
+
+impl CanonicalOrd for FullyQualifiedDomainName {
+    /// Names are compared label by label, starting from the rightmost label,
+    /// with names which run out of labels sorting first.
+    fn canonical_cmp(&self, other: &Self) -> Ordering {
+        let mut left = self.iter().rev();
+        let mut right = other.iter().rev();
+
+        loop {
+            match (left.next(), right.next()) {
+                (Some(left), Some(right)) => match left.canonical_cmp(right) {
+                    Ordering::Equal => continue,
+                    ordering => return ordering,
+                },
+                (None, Some(_)) => return Ordering::Less,
+                (Some(_), None) => return Ordering::Greater,
+                (None, None) => return Ordering::Equal,
+            }
+        }
+    }
+}
+
+impl Canonicalize for DomainSegment {
+    fn canonicalize(&self) -> Self {
+        self.to_lowercase()
+    }
+}
+
+impl Canonicalize for FullyQualifiedDomainName {
+    fn canonicalize(&self) -> Self {
+        self.iter().map(DomainSegment::canonicalize).collect()
+    }
+}
+
+impl Canonicalize for PartiallyQualifiedDomainName {
+    fn canonicalize(&self) -> Self {
+        self.iter().map(DomainSegment::canonicalize).collect()
+    }
+}
+
+impl Canonicalize for DomainName {
+    fn canonicalize(&self) -> Self {
+        match self {
+            DomainName::Full(full) => DomainName::Full(full.canonicalize()),
+            DomainName::Partial(partial) => DomainName::Partial(partial.canonicalize()),
+        }
+    }
+}
+
+impl Canonicalize for PatternSegment {
+    fn canonicalize(&self) -> Self {
+        // Regular expressions are already matched case-insensitively, and
+        // lowercasing them would change escapes such as `\D`.
+        match self {
+            PatternSegment::Label(label) => PatternSegment::Label(label.to_ascii_lowercase()),
+            PatternSegment::Glob { source, .. } => {
+                PatternSegment::glob(source.to_ascii_lowercase())
+            }
+            #[cfg(feature = "regex")]
+            PatternSegment::Regex { .. } => self.clone(),
+        }
+    }
+}
+
+impl Canonicalize for Pattern {
+    fn canonicalize(&self) -> Self {
+        self.iter().map(PatternSegment::canonicalize).collect()
+    }
+}
+
+impl Canonicalize for RecordIdent {
+    fn canonicalize(&self) -> Self {
+        RecordIdent {
+            fqdn: self.fqdn.canonicalize(),
+            r#type: self.r#type,
+            rdata: self.rdata.canonicalize(),
+        }
+    }
+}
+
+impl Canonicalize for Record {
+    fn canonicalize(&self) -> Self {
+        Record {
+            fqdn: self.fqdn.canonicalize(),
+            class: self.class,
+            r#type: self.r#type,
+            ttl: self.ttl,
+            rdata: self.rdata.canonicalize(),
+        }
+    }
+}
+
+impl Canonicalize for RecordData {
+    fn canonicalize(&self) -> Self {
+        match self {
+            RecordData::AFSDB { subtype, hostname } => RecordData::AFSDB {
+                subtype: *subtype,
+                hostname: hostname.canonicalize(),
+            },
+            RecordData::CNAME(target) => RecordData::CNAME(target.canonicalize()),
+            RecordData::DNAME(target) => RecordData::DNAME(target.canonicalize()),
+            RecordData::HIP {
+                algorithm,
+                hit,
+                public_key,
+                rendezvous_servers,
+            } => RecordData::HIP {
+                algorithm: *algorithm,
+                hit: hit.clone(),
+                public_key: public_key.clone(),
+                rendezvous_servers: rendezvous_servers
+                    .iter()
+                    .map(DomainName::canonicalize)
+                    .collect(),
+            },
+            RecordData::KX {
+                preference,
+                exchanger,
+            } => RecordData::KX {
+                preference: *preference,
+                exchanger: exchanger.canonicalize(),
+            },
+            RecordData::MX {
+                preference,
+                exchange,
+            } => RecordData::MX {
+                preference: *preference,
+                exchange: exchange.canonicalize(),
+            },
+            RecordData::NS(target) => RecordData::NS(target.canonicalize()),
+            RecordData::PTR(target) => RecordData::PTR(target.canonicalize()),
+            RecordData::SOA {
+                mname,
+                rname,
+                serial,
+                refresh,
+                retry,
+                expire,
+                minimum,
+            } => RecordData::SOA {
+                mname: mname.canonicalize(),
+                rname: rname.canonicalize(),
+                serial: *serial,
+                refresh: *refresh,
+                retry: *retry,
+                expire: *expire,
+                minimum: *minimum,
+            },
+            RecordData::SRV {
+                priority,
+                weight,
+                port,
+                target,
+            } => RecordData::SRV {
+                priority: *priority,
+                weight: *weight,
+                port: *port,
+                target: target.canonicalize(),
+            },
+            RecordData::Other(rdata) => {
+                RecordData::Other(rdata.split_whitespace().collect::<Vec<_>>().join(" "))
+            }
+            RecordData::A(_)
+            | RecordData::AAAA(_)
+            | RecordData::CAA { .. }
+            | RecordData::DLV { .. }
+            | RecordData::TA { .. }
+            | RecordData::TXT(_) => self.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        segment::DomainSegment, CanonicalOrd, Canonicalize, DomainName, FullyQualifiedDomainName,
+        RecordData, RecordIdent, Type,
+    };
+
+    #[test]
+    fn unchecked_segments() {
+        let fqdn = FullyQualifiedDomainName::from_iter([
+            DomainSegment::new_unchecked("WWW"),
+            DomainSegment::new_unchecked("Example"),
+            DomainSegment::new_unchecked("org"),
+        ]);
+
+        assert!(!fqdn.is_canonical());
+        assert_eq!(
+            fqdn.canonicalize(),
+            FullyQualifiedDomainName::try_from("www.example.org.").unwrap()
+        );
+    }
+
+    #[test]
+    fn record_ident_rdata() {
+        let ident = RecordIdent {
+            fqdn: FullyQualifiedDomainName::try_from("www.example.org.").unwrap(),
+            r#type: Type::CNAME,
+            rdata: RecordData::CNAME(DomainName::from(FullyQualifiedDomainName::from_iter([
+                DomainSegment::new_unchecked("Target"),
+                DomainSegment::new_unchecked("Example"),
+                DomainSegment::new_unchecked("org"),
+            ]))),
+        };
+
+        assert_eq!(
+            ident.canonicalize().rdata.to_string(),
+            "target.example.org."
+        );
+
+        let ident = RecordIdent {
+            fqdn: FullyQualifiedDomainName::try_from("www.example.org.").unwrap(),
+            r#type: Type::SSHFP,
+            rdata: RecordData::Other(String::from("2  1 123456789abcdef ")),
+        };
+
+        assert_eq!(
+            ident.canonicalize().rdata.to_string(),
+            "2 1 123456789abcdef"
+        );
+    }
+
+    #[test]
+    fn canonical_ordering() {
+        // Example from RFC 4034, section 6.1.
+        let expected: Vec<FullyQualifiedDomainName> = [
+            "example.",
+            "a.example.",
+            "yljkjljk.a.example.",
+            "Z.a.example.",
+            "zABC.a.EXAMPLE.",
+            "z.example.",
+            "*.z.example.",
+        ]
+        .into_iter()
+        .map(|name| FullyQualifiedDomainName::try_from(name).unwrap())
+        .collect();
+
+        let mut names = expected.clone();
+        names.reverse();
+        names.sort_by(CanonicalOrd::canonical_cmp);
+
+        assert_eq!(names, expected);
+    }
+}
@@ -0,0 +1,104 @@
+use rand_core::RngCore;
+
+use crate::FullyQualifiedDomainName;
+
+impl FullyQualifiedDomainName {
+    /// Renders the name in presentation format with the case of each letter
+    /// randomized, for use as the question of an outgoing query (DNS 0x20).
+    ///
+    /// Servers echo the question verbatim, so an off-path attacker spoofing
+    /// a response must also guess the case of every letter. Verify the
+    /// question of the response using [`FullyQualifiedDomainName::verify_case`].
+    ///
+    /// ```text
+    /// www.example.org. => wWw.ExaMPlE.oRG.
+    /// ```
+    pub fn randomize_case<R: RngCore + ?Sized>(&self, rng: &mut R) -> String {
+        let mut bits = 0;
+        let mut remaining = 0;
+
+        self.to_string()
+            .chars()
+            .map(|c| {
+                if !c.is_ascii_alphabetic() {
+                    return c;
+                }
+
+                if remaining == 0 {
+                    bits = rng.next_u32();
+                    remaining = u32::BITS;
+                }
+
+                let upper = bits & 1 == 1;
+                bits >>= 1;
+                remaining -= 1;
+
+                if upper {
+                    c.to_ascii_uppercase()
+                } else {
+                    c
+                }
+            })
+            .collect()
+    }
+
+    /// Checks that the question of a response, `received`, repeats the
+    /// case-randomized question which was `sent` exactly, and that both
+    /// refer to this name regardless of case.
+    pub fn verify_case(&self, sent: &str, received: &str) -> bool {
+        sent == received
+            && FullyQualifiedDomainName::try_from(received).is_ok_and(|name| name == *self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand_core::{impls, Error, RngCore};
+
+    use crate::FullyQualifiedDomainName;
+
+    /// Alternates between all-zero and all-one words.
+    struct Alternating(bool);
+
+    impl RngCore for Alternating {
+        fn next_u32(&mut self) -> u32 {
+            self.0 = !self.0;
+
+            if self.0 {
+                u32::MAX
+            } else {
+                0
+            }
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            impls::next_u64_via_u32(self)
+        }
+
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            impls::fill_bytes_via_next(self, dest)
+        }
+
+        fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+            self.fill_bytes(dest);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn randomization() {
+        let fqdn = FullyQualifiedDomainName::try_from("www.example.org.").unwrap();
+        let long = FullyQualifiedDomainName::try_from(format!("{}.", "a".repeat(40))).unwrap();
+
+        let sent = fqdn.randomize_case(&mut Alternating(false));
+        assert_eq!(sent, "WWW.EXAMPLE.ORG.");
+        assert!(fqdn.verify_case(&sent, "WWW.EXAMPLE.ORG."));
+        assert!(!fqdn.verify_case(&sent, "www.example.org."));
+        assert!(!fqdn.verify_case(&sent, "WWW.EXAMPLE.COM."));
+
+        assert_eq!(
+            long.randomize_case(&mut Alternating(false)),
+            format!("{}{}.", "A".repeat(32), "a".repeat(8))
+        );
+    }
+}
@@ -1,34 +1,40 @@
-use std::fmt::Display;
+use std::{fmt::Display, str::FromStr};
 
-use schemars::JsonSchema;
-use serde::{Deserialize, Serialize};
+#[cfg(feature = "schemars")]
+use schemars::{
+    schema::{InstanceType, Schema, SchemaObject, StringValidation},
+    JsonSchema,
+};
+#[cfg(feature = "serde")]
+use serde::{de::Error, Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::MnemonicRegistry;
 
 /// Domain Name System class.
-#[derive(
-    Default,
-    Serialize,
-    Deserialize,
-    JsonSchema,
-    Clone,
-    Copy,
-    Debug,
-    Hash,
-    PartialEq,
-    Eq,
-    PartialOrd,
-    Ord,
-)]
+#[allow(clippy::upper_case_acronyms)]
+#[cfg_attr(feature = "valuable", derive(valuable::Valuable))]
+#[derive(Default, Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub enum Class {
     /// Internet
     #[default]
-    #[serde(rename = "IN")]
     IN,
     /// Chaos
-    #[serde(rename = "CH")]
     CH,
     /// Hesiod
-    #[serde(rename = "HS")]
     HS,
+    /// Used in dynamic update prerequisites and deletions (RFC 2136).
+    NONE,
+    /// Matches any class in queries (RFC 1035).
+    ANY,
+    /// Class not covered by any of the other variants, identified
+    /// by its numeric code.
+    ///
+    /// Displayed and parsed using the generic `CLASS123` syntax, unless
+    /// a mnemonic is registered for it with the [`MnemonicRegistry`].
+    ///
+    /// [3597](https://datatracker.ietf.org/doc/html/rfc3597)
+    Unknown(u16),
 }
 
 impl Class {
@@ -46,6 +52,59 @@ impl Class {
     pub fn is_hesiod(&self) -> bool {
         *self == Class::HS
     }
+
+    ///  Returns true if `self` is [`Class::Unknown`]
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Class::Unknown(_))
+    }
+
+    /// Returns the numeric code identifying the class.
+    pub const fn code(&self) -> u16 {
+        match self {
+            Class::IN => 1,
+            Class::CH => 3,
+            Class::HS => 4,
+            Class::NONE => 254,
+            Class::ANY => 255,
+            Class::Unknown(code) => *code,
+        }
+    }
+
+    /// Returns the class identified by the numeric code, or [`Class::Unknown`]
+    /// if the code does not correspond to any other variant.
+    pub const fn from_code(code: u16) -> Self {
+        match code {
+            1 => Class::IN,
+            3 => Class::CH,
+            4 => Class::HS,
+            254 => Class::NONE,
+            255 => Class::ANY,
+            code => Class::Unknown(code),
+        }
+    }
+
+    /// Parses a class from its mnemonic, or the generic `CLASS123` syntax,
+    /// ignoring case.
+    pub(crate) fn from_mnemonic(value: &str) -> Option<Self> {
+        let class = match value.to_ascii_uppercase().as_str() {
+            "IN" => Class::IN,
+            "CH" => Class::CH,
+            "HS" => Class::HS,
+            "NONE" => Class::NONE,
+            "ANY" => Class::ANY,
+            generic => {
+                let code = generic.strip_prefix("CLASS")?;
+
+                if !code.starts_with(|c: char| c.is_ascii_digit()) {
+                    return None;
+                }
+
+                Class::from_code(code.parse().ok()?)
+            }
+        };
+
+        Some(class)
+    }
 }
 
 impl Display for Class {
@@ -54,24 +113,147 @@ impl Display for Class {
             Class::IN => f.write_str("IN"),
             Class::CH => f.write_str("CH"),
             Class::HS => f.write_str("HS"),
+            Class::NONE => f.write_str("NONE"),
+            Class::ANY => f.write_str("ANY"),
+            Class::Unknown(code) => match MnemonicRegistry::global().class_mnemonic(*code) {
+                Some(mnemonic) => f.write_str(&mnemonic),
+                None => write!(f, "CLASS{code}"),
+            },
         }
     }
 }
 
+/// Produced when attempting to parse an unknown [`Class`].
+#[derive(Error, Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ClassError {
+    /// The string is neither a known mnemonic, nor a generic `CLASS123` class.
+    #[error("unknown class {0:?}")]
+    UnknownClass(String),
+}
+
+impl TryFrom<&str> for Class {
+    type Error = ClassError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Class::from_mnemonic(value)
+            .or_else(|| {
+                MnemonicRegistry::global()
+                    .class_code(value)
+                    .map(Class::Unknown)
+            })
+            .ok_or_else(|| ClassError::UnknownClass(value.to_string()))
+    }
+}
+
+impl TryFrom<String> for Class {
+    type Error = ClassError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+
+impl FromStr for Class {
+    type Err = ClassError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl JsonSchema for Class {
+    fn schema_name() -> String {
+        String::from("Class")
+    }
+
+    fn json_schema(_: &mut schemars::gen::SchemaGenerator) -> Schema {
+        SchemaObject {
+            instance_type: Some(InstanceType::String.into()),
+            string: Some(Box::new(StringValidation {
+                pattern: Some(String::from("^(IN|CH|HS|NONE|ANY|CLASS[0-9]+)$")),
+                ..Default::default()
+            })),
+            ..Default::default()
+        }
+        .into()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Class {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+
+        Class::try_from(value.as_str()).map_err(D::Error::custom)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Class {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.to_string().serialize(serializer)
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    #[cfg(feature = "serde")]
     use serde::{Deserialize, Serialize};
 
-    use crate::Class;
+    use crate::{error::ClassError, Class};
+
+    #[test]
+    fn from_str() {
+        assert_eq!("in".parse(), Ok(Class::IN));
+        assert_eq!("CH".parse(), Ok(Class::CH));
+        assert_eq!(
+            "CHAOS".parse::<Class>(),
+            Err(ClassError::UnknownClass(String::from("CHAOS")))
+        );
+    }
+
+    #[test]
+    fn codes() {
+        assert_eq!(Class::from_code(255), Class::ANY);
+        assert_eq!(Class::from_code(65280), Class::Unknown(65280));
+
+        for code in [1, 3, 4, 254, 255, 65280] {
+            assert_eq!(Class::from_code(code).code(), code);
+        }
+
+        assert_eq!(Class::Unknown(65280).to_string(), "CLASS65280");
+        assert_eq!("class65280".parse(), Ok(Class::Unknown(65280)));
+        assert_eq!("CLASS1".parse(), Ok(Class::IN));
+
+        for invalid in ["CLASS", "CLASS+1", "CLASS65536"] {
+            assert_eq!(
+                invalid.parse::<Class>(),
+                Err(ClassError::UnknownClass(invalid.to_string()))
+            );
+        }
+    }
 
     #[test]
+    #[cfg(feature = "serde")]
     fn deser() {
         #[derive(Debug, Serialize, Deserialize, PartialEq, Eq)]
         struct ExampleStruct {
             class: Class,
         }
 
-        let example = vec![ExampleStruct { class: Class::IN }];
+        let example = vec![
+            ExampleStruct { class: Class::IN },
+            ExampleStruct {
+                class: Class::Unknown(65280),
+            },
+        ];
 
         let serialized = serde_yaml::to_string(&example).unwrap();
         println!("{serialized}");
@@ -0,0 +1,204 @@
+use std::fmt::{Debug, Display};
+
+use crate::{
+    pattern::{affix_matches, glob_matches, labels_match, GlobToken, SegmentMatcher},
+    segment::DomainSegment,
+    FullyQualifiedDomainName, Pattern, PatternSegment,
+};
+
+/// A [`Pattern`] lowered into matchers for each of its segments, produced
+/// by [`Pattern::compile`].
+///
+/// Matching a compiled pattern neither allocates, nor inspects the segments
+/// to determine how they should be matched, making it suitable for patterns
+/// which are matched against many names, such as those of delegations.
+#[derive(Clone)]
+pub struct CompiledPattern {
+    pattern: Pattern,
+    reversed: Vec<Matcher>,
+}
+
+/// Matcher for a single [`PatternSegment`].
+#[derive(Debug, Clone)]
+enum Matcher {
+    /// Matches a label equal to the segment.
+    Literal(String),
+    /// Matches a label surrounding a single `*` with a prefix and suffix.
+    Affix { prefix: String, suffix: String },
+    /// Matches a label against the glob tokens of the segment.
+    Glob {
+        segment: String,
+        tokens: Vec<GlobToken>,
+    },
+    /// Leading `*`, matching one or more labels.
+    Wildcard,
+    /// `**`, matching any number of labels.
+    MultiLevelWildcard,
+    /// Matches a label against a regular expression.
+    #[cfg(feature = "regex")]
+    Regex(regex::Regex),
+}
+
+impl Matcher {
+    fn new(segment: &PatternSegment) -> Self {
+        let value = segment.as_ref();
+
+        if segment.is_multi_level_wildcard() {
+            return Matcher::MultiLevelWildcard;
+        }
+
+        if segment.is_standalone_wildcard() {
+            return Matcher::Wildcard;
+        }
+
+        #[cfg(feature = "regex")]
+        if let PatternSegment::Regex { regex, .. } = segment {
+            return Matcher::Regex(regex.clone());
+        }
+
+        if let PatternSegment::Glob { source, tokens } = segment {
+            return Matcher::Glob {
+                segment: source.clone(),
+                tokens: tokens.clone(),
+            };
+        }
+
+        if segment.is_literal() {
+            return Matcher::Literal(value.to_string());
+        }
+
+        match value.split_once('*') {
+            Some((prefix, suffix)) => Matcher::Affix {
+                prefix: prefix.to_string(),
+                suffix: suffix.to_string(),
+            },
+            None => Matcher::Literal(value.to_string()),
+        }
+    }
+}
+
+impl SegmentMatcher for Matcher {
+    fn is_multi_level_wildcard(&self) -> bool {
+        matches!(self, Matcher::MultiLevelWildcard)
+    }
+
+    fn is_standalone_wildcard(&self) -> bool {
+        matches!(self, Matcher::Wildcard | Matcher::MultiLevelWildcard)
+    }
+
+    fn matches_label(&self, label: &DomainSegment) -> bool {
+        let label = label.as_presentation();
+
+        match self {
+            Matcher::Literal(literal) => *literal == label,
+            Matcher::Affix { prefix, suffix } => affix_matches(prefix, suffix, &label),
+            Matcher::Glob { segment, tokens } => {
+                glob_matches(segment.as_bytes(), tokens, label.as_bytes())
+            }
+            Matcher::Wildcard | Matcher::MultiLevelWildcard => true,
+            #[cfg(feature = "regex")]
+            Matcher::Regex(regex) => regex.is_match(&label),
+        }
+    }
+}
+
+impl Pattern {
+    /// Compiles the pattern into a [`CompiledPattern`], which matches
+    /// names more efficiently when the same pattern is matched repeatedly.
+    pub fn compile(&self) -> CompiledPattern {
+        CompiledPattern::from(self.clone())
+    }
+}
+
+impl CompiledPattern {
+    /// Returns true if the pattern matches the given domain.
+    ///
+    /// Semantically identical to [`Pattern::matches`].
+    pub fn matches(&self, domain: &FullyQualifiedDomainName) -> bool {
+        labels_match(self.reversed.iter(), domain.as_ref().iter().rev())
+    }
+
+    /// Returns the pattern which was compiled.
+    pub fn pattern(&self) -> &Pattern {
+        &self.pattern
+    }
+}
+
+impl From<Pattern> for CompiledPattern {
+    fn from(value: Pattern) -> Self {
+        let mut reversed: Vec<Matcher> = value.iter().map(Matcher::new).collect();
+        reversed.reverse();
+
+        CompiledPattern {
+            pattern: value,
+            reversed,
+        }
+    }
+}
+
+impl Display for CompiledPattern {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.pattern, f)
+    }
+}
+
+impl Debug for CompiledPattern {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("CompiledPattern")
+            .field(&self.pattern.to_string())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{FullyQualifiedDomainName, Pattern, PatternSyntax};
+
+    #[test]
+    fn agrees_with_pattern() {
+        for pattern in [
+            "example.org",
+            "*.example.org",
+            "**.example.org",
+            "api.**.example.org",
+            "dev*.example.org",
+            "ab*ba.org",
+            "@",
+        ] {
+            let pattern = Pattern::try_from(pattern).unwrap();
+            let compiled = pattern.compile();
+            assert_eq!(compiled.pattern(), &pattern);
+
+            for domain in [
+                "example.org.",
+                "www.example.org.",
+                "api.eu.example.org.",
+                "dev-1.example.org.",
+                "aba.org.",
+                "abba.org.",
+                "example.com.",
+                "org.",
+            ] {
+                let domain = FullyQualifiedDomainName::try_from(domain).unwrap();
+
+                assert_eq!(
+                    compiled.matches(&domain),
+                    pattern.matches(&domain),
+                    "{pattern} {domain}"
+                );
+            }
+        }
+
+        // Prefix and suffix around the wildcard cannot overlap.
+        assert!(!Pattern::try_from("ab*ba.org")
+            .unwrap()
+            .compile()
+            .matches(&FullyQualifiedDomainName::try_from("aba.org.").unwrap()));
+
+        let glob = Pattern::parse("v[0-9]?-*.example.org", PatternSyntax::Glob)
+            .unwrap()
+            .compile();
+        assert!(glob.matches(&FullyQualifiedDomainName::try_from("v10-rc.example.org.").unwrap()));
+        assert!(!glob.matches(&FullyQualifiedDomainName::try_from("va0-rc.example.org.").unwrap()));
+    }
+}
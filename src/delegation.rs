@@ -0,0 +1,165 @@
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    diagnostic::Severity, Class, Diagnostic, FullyQualifiedDomainName, Pattern, Record, Type,
+    TypePattern,
+};
+
+/// Grants permission to manage records whose name matches a [`Pattern`],
+/// optionally restricted to certain types and classes.
+///
+/// ```yaml
+/// pattern: '*.example.org.'
+/// types: [A|AAAA, CNAME]
+/// classes: [IN]
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub struct DelegationRule {
+    /// Pattern which record names must match.
+    pub pattern: Pattern,
+    /// Record types must match at least one of these patterns.
+    /// All types are permitted if empty.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Vec::is_empty",
+            deserialize_with = "crate::list::deserialize"
+        )
+    )]
+    pub types: Vec<TypePattern>,
+    /// Classes which records may belong to.
+    /// All classes are permitted if empty.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Vec::is_empty",
+            deserialize_with = "crate::list::deserialize"
+        )
+    )]
+    pub classes: Vec<Class>,
+}
+
+impl DelegationRule {
+    /// Returns true if the rule permits managing records of the given
+    /// name, type and class.
+    pub fn permits(&self, fqdn: &FullyQualifiedDomainName, r#type: Type, class: Class) -> bool {
+        self.pattern.matches(fqdn)
+            && (self.types.is_empty() || self.types.iter().any(|pattern| pattern.matches(r#type)))
+            && (self.classes.is_empty() || self.classes.contains(&class))
+    }
+
+    /// Checks that at least one of the rules permits managing the record,
+    /// producing an error diagnostic if none of them do.
+    pub fn check(rules: &[DelegationRule], record: &Record) -> Option<Diagnostic> {
+        if rules
+            .iter()
+            .any(|rule| rule.permits(&record.fqdn, record.r#type, record.class))
+        {
+            return None;
+        }
+
+        Some(
+            Diagnostic::new(
+                Severity::Error,
+                "NotDelegated",
+                format!(
+                    "{} {} {} records are not permitted by any delegation",
+                    record.fqdn, record.class, record.r#type
+                ),
+            )
+            .with_subject(record.fqdn.clone()),
+        )
+    }
+}
+
+impl From<Pattern> for DelegationRule {
+    fn from(pattern: Pattern) -> Self {
+        DelegationRule {
+            pattern,
+            types: Vec::new(),
+            classes: Vec::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        fqdn, Class, DelegationRule, FullyQualifiedDomainName, Pattern, Record, RecordData, Type,
+        TypePattern,
+    };
+
+    #[test]
+    fn permits() {
+        let rule = DelegationRule {
+            pattern: Pattern::try_from("*.example.org.").unwrap(),
+            types: vec![
+                TypePattern::from_iter([Type::A, Type::AAAA]),
+                TypePattern::from(Type::CNAME),
+            ],
+            classes: vec![Class::IN],
+        };
+
+        let www = fqdn!("www.example.org.");
+        assert!(rule.permits(&www, Type::AAAA, Class::IN));
+        assert!(rule.permits(&www, Type::CNAME, Class::IN));
+        assert!(!rule.permits(&www, Type::MX, Class::IN));
+        assert!(!rule.permits(&www, Type::A, Class::CH));
+        assert!(!rule.permits(&fqdn!("www.example.com."), Type::A, Class::IN));
+
+        let unrestricted = DelegationRule::from(Pattern::try_from("*.example.org.").unwrap());
+        assert!(unrestricted.permits(&www, Type::MX, Class::HS));
+    }
+
+    #[test]
+    fn check() {
+        let rules = [DelegationRule::from(
+            Pattern::try_from("*.example.org.").unwrap(),
+        )];
+
+        let record = |name: &str| Record {
+            fqdn: FullyQualifiedDomainName::try_from(name).unwrap(),
+            class: Class::IN,
+            r#type: Type::TXT,
+            ttl: 300,
+            rdata: RecordData::parse(Type::TXT, "\"hello\"").unwrap(),
+        };
+
+        assert_eq!(
+            DelegationRule::check(&rules, &record("www.example.org.")),
+            None
+        );
+
+        let diagnostic = DelegationRule::check(&rules, &record("www.example.com.")).unwrap();
+        assert_eq!(diagnostic.code, "NotDelegated");
+        assert_eq!(diagnostic.subject, Some(fqdn!("www.example.com.")));
+        assert!(diagnostic.is_error());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let rule: DelegationRule = serde_yaml::from_str(
+            "pattern: '*.example.org.'\n\
+             types: [A|AAAA, CNAME]\n\
+             classes: [IN]\n",
+        )
+        .unwrap();
+
+        assert_eq!(rule.types.len(), 2);
+        assert_eq!(rule.classes, [Class::IN]);
+
+        let unrestricted = DelegationRule::from(Pattern::try_from("*.example.org.").unwrap());
+        assert_eq!(
+            serde_yaml::to_string(&unrestricted).unwrap(),
+            "pattern: '*.example.org.'\n"
+        );
+    }
+}
@@ -0,0 +1,210 @@
+use std::fmt::Display;
+
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::{PolicyViolation, RRSetError, ZoneError},
+    FullyQualifiedDomainName,
+};
+
+/// Severity of a [`Diagnostic`], ordered from least to most severe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub enum Severity {
+    /// Informational, requiring no action.
+    Info,
+    /// The zone is usable, but likely not as intended.
+    Warning,
+    /// The zone, or part of it, cannot be served as specified.
+    Error,
+}
+
+impl Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Severity::Info => f.write_str("info"),
+            Severity::Warning => f.write_str("warning"),
+            Severity::Error => f.write_str("error"),
+        }
+    }
+}
+
+/// Finding produced when validating zones, delegations or policies,
+/// such that results can uniformly be mapped to Kubernetes conditions,
+/// warnings or hard failures.
+///
+/// ```yaml
+/// severity: Warning
+/// code: TtlTooLow
+/// message: www.example.org. A record ttl 30 is below the minimum of 60
+/// subject: www.example.org.
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub struct Diagnostic {
+    pub severity: Severity,
+    /// Stable identifier of the kind of finding, in `UpperCamelCase`
+    /// such that it can be used as the reason of a condition.
+    pub code: String,
+    /// Human-readable description of the finding.
+    pub message: String,
+    /// Name which the finding concerns, if any.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub subject: Option<FullyQualifiedDomainName>,
+}
+
+impl Diagnostic {
+    /// Constructs a diagnostic without a subject.
+    pub fn new(severity: Severity, code: impl Into<String>, message: impl Into<String>) -> Self {
+        Diagnostic {
+            severity,
+            code: code.into(),
+            message: message.into(),
+            subject: None,
+        }
+    }
+
+    /// Sets the name which the diagnostic concerns.
+    pub fn with_subject(mut self, subject: FullyQualifiedDomainName) -> Self {
+        self.subject = Some(subject);
+        self
+    }
+
+    /// Returns true if the diagnostic is of [`Severity::Error`].
+    pub fn is_error(&self) -> bool {
+        self.severity == Severity::Error
+    }
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} [{}]: {}", self.severity, self.code, self.message)
+    }
+}
+
+/// Violations of limits are errors, while TTLs outside of the preferred
+/// range are warnings, since the records can still be served.
+impl From<PolicyViolation> for Diagnostic {
+    fn from(value: PolicyViolation) -> Self {
+        let (severity, code, subject) = match &value {
+            PolicyViolation::TooManyRecords { .. } => (Severity::Error, "TooManyRecords", None),
+            PolicyViolation::RecordSetTooLarge { fqdn, .. } => {
+                (Severity::Error, "RecordSetTooLarge", Some(fqdn))
+            }
+            PolicyViolation::TypeNotAllowed { fqdn, .. } => {
+                (Severity::Error, "TypeNotAllowed", Some(fqdn))
+            }
+            PolicyViolation::TtlTooLow { fqdn, .. } => (Severity::Warning, "TtlTooLow", Some(fqdn)),
+            PolicyViolation::TtlTooHigh { fqdn, .. } => {
+                (Severity::Warning, "TtlTooHigh", Some(fqdn))
+            }
+            PolicyViolation::NameNotAllowed(fqdn) => {
+                (Severity::Error, "NameNotAllowed", Some(fqdn))
+            }
+        };
+
+        Diagnostic {
+            severity,
+            code: code.to_string(),
+            message: value.to_string(),
+            subject: subject.cloned(),
+        }
+    }
+}
+
+impl From<ZoneError> for Diagnostic {
+    fn from(value: ZoneError) -> Self {
+        let (code, subject) = match &value {
+            ZoneError::OutOfZone(fqdn) => ("OutOfZone", Some(fqdn)),
+            ZoneError::ClassMismatch { .. } => ("ClassMismatch", None),
+            ZoneError::TypeMismatch { fqdn, .. } => ("TypeMismatch", Some(fqdn)),
+        };
+
+        Diagnostic {
+            severity: Severity::Error,
+            code: code.to_string(),
+            message: value.to_string(),
+            subject: subject.cloned(),
+        }
+    }
+}
+
+impl From<RRSetError> for Diagnostic {
+    fn from(value: RRSetError) -> Self {
+        let (code, fqdn) = match &value {
+            RRSetError::MultipleCnames(fqdn) => ("MultipleCnames", fqdn),
+            RRSetError::CnameConflict { fqdn, .. } => ("CnameConflict", fqdn),
+        };
+
+        Diagnostic {
+            severity: Severity::Error,
+            code: code.to_string(),
+            message: value.to_string(),
+            subject: Some(fqdn.clone()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::{PolicyViolation, RRSetError, ZoneError},
+        fqdn, Diagnostic, Severity, Type,
+    };
+
+    #[test]
+    fn conversions() {
+        let diagnostic = Diagnostic::from(PolicyViolation::TtlTooLow {
+            fqdn: fqdn!("www.example.org."),
+            r#type: Type::A,
+            ttl: 30,
+            min: 60,
+        });
+
+        assert_eq!(
+            diagnostic,
+            Diagnostic::new(
+                Severity::Warning,
+                "TtlTooLow",
+                "www.example.org. A record ttl 30 is below the minimum of 60"
+            )
+            .with_subject(fqdn!("www.example.org."))
+        );
+        assert_eq!(
+            diagnostic.to_string(),
+            "warning [TtlTooLow]: www.example.org. A record ttl 30 is below the minimum of 60"
+        );
+
+        let diagnostic = Diagnostic::from(ZoneError::OutOfZone(fqdn!("example.com.")));
+        assert_eq!(diagnostic.code, "OutOfZone");
+        assert!(diagnostic.is_error());
+
+        let diagnostic = Diagnostic::from(RRSetError::CnameConflict {
+            fqdn: fqdn!("www.example.org."),
+            r#type: Type::A,
+        });
+        assert_eq!(diagnostic.code, "CnameConflict");
+        assert_eq!(diagnostic.subject, Some(fqdn!("www.example.org.")));
+        assert!(diagnostic.is_error());
+    }
+
+    #[test]
+    fn severity_ordering() {
+        assert!(Severity::Info < Severity::Warning);
+        assert!(Severity::Warning < Severity::Error);
+        assert_eq!(
+            [Severity::Warning, Severity::Error, Severity::Info]
+                .into_iter()
+                .max(),
+            Some(Severity::Error)
+        );
+    }
+}
@@ -0,0 +1,689 @@
+use std::{
+    borrow::Borrow,
+    collections::{BTreeMap, BTreeSet},
+    fmt::{Display, Write},
+    sync::Arc,
+};
+
+use crate::{Class, FullyQualifiedDomainName, Record, RecordData, RecordIdent, Type};
+
+/// Maps record data to a representative of its equivalence class, such
+/// that record data mapped to the same value is considered equal when
+/// diffing, see [`EquivalenceRules`].
+pub type Equivalence = Arc<dyn Fn(&RecordData) -> RecordData + Send + Sync>;
+
+/// Treats domain names within record data which only differ by their
+/// trailing dot as equal, such as `mail.example.org` and `mail.example.org.`
+pub fn ignore_trailing_dots(rdata: &RecordData) -> RecordData {
+    rdata.with_origin(&FullyQualifiedDomainName::default())
+}
+
+/// Treats TXT record data as equal if the concatenation of its character
+/// strings is, such as `"v=spf1 " "-all"` and `"v=spf1 -all"`, since providers
+/// split long values into strings of different lengths.
+pub fn ignore_txt_splitting(rdata: &RecordData) -> RecordData {
+    match rdata {
+        RecordData::TXT(strings) => RecordData::TXT(vec![strings.concat()]),
+        rdata => rdata.clone(),
+    }
+}
+
+/// Per-[`Type`] [`Equivalence`] rules, for ignoring differences in
+/// record data which are not meaningful when comparing against providers
+/// that canonicalize record data differently.
+///
+/// Rules registered for a specific type take precedence over the
+/// registry-wide default rule. Types without any rule are compared exactly,
+/// though record data with a dedicated [`RecordData`] variant is compared as
+/// parsed, so addresses such as `2001:db8::1` and `2001:0db8:0:0:0:0:0:1`
+/// are always equal.
+///
+/// ```text
+/// let rules = EquivalenceRules::new()
+///     .with(Type::CNAME, ignore_trailing_dots)
+///     .with(Type::SSHFP, |rdata| ...);
+/// ```
+#[derive(Default, Clone)]
+pub struct EquivalenceRules {
+    default: Option<Equivalence>,
+    rules: BTreeMap<Type, Equivalence>,
+}
+
+impl EquivalenceRules {
+    /// Constructs an empty set of rules, comparing all record data exactly.
+    pub fn new() -> Self {
+        EquivalenceRules::default()
+    }
+
+    /// Sets the rule applied to all types, unless overridden.
+    pub fn with_default(
+        mut self,
+        rule: impl Fn(&RecordData) -> RecordData + Send + Sync + 'static,
+    ) -> Self {
+        self.default = Some(Arc::new(rule));
+        self
+    }
+
+    /// Registers the rule for the given type, replacing any previous one.
+    pub fn with(
+        mut self,
+        r#type: Type,
+        rule: impl Fn(&RecordData) -> RecordData + Send + Sync + 'static,
+    ) -> Self {
+        self.insert(r#type, Arc::new(rule));
+        self
+    }
+
+    /// Registers the rule for the given type, returning the rule it replaced.
+    pub fn insert(&mut self, r#type: Type, rule: Equivalence) -> Option<Equivalence> {
+        self.rules.insert(r#type, rule)
+    }
+
+    /// Removes the rule registered for the given type.
+    pub fn remove(&mut self, r#type: Type) -> Option<Equivalence> {
+        self.rules.remove(&r#type)
+    }
+
+    /// Returns the effective rule for the given type.
+    pub fn get(&self, r#type: Type) -> Option<&Equivalence> {
+        self.rules.get(&r#type).or(self.default.as_ref())
+    }
+
+    /// Returns the representative of the equivalence class of the record
+    /// data, which is the record data itself if no rule applies.
+    pub fn normalize(&self, r#type: Type, rdata: &RecordData) -> RecordData {
+        match self.get(r#type) {
+            Some(rule) => rule(rdata),
+            None => rdata.clone(),
+        }
+    }
+
+    /// Returns true if both record data are equivalent.
+    pub fn equivalent(&self, r#type: Type, a: &RecordData, b: &RecordData) -> bool {
+        a == b || self.normalize(r#type, a) == self.normalize(r#type, b)
+    }
+
+    fn normalize_ident(&self, ident: &RecordIdent) -> RecordIdent {
+        RecordIdent {
+            fqdn: ident.fqdn.clone(),
+            r#type: ident.r#type,
+            rdata: self.normalize(ident.r#type, &ident.rdata),
+        }
+    }
+
+    /// Returns true if both record sets have the same TTL, and their
+    /// record data is equivalent.
+    fn equivalent_sets(&self, a: &RecordSet, b: &RecordSet) -> bool {
+        if a == b {
+            return true;
+        }
+
+        let normalize = |set: &RecordSet| -> BTreeSet<RecordData> {
+            set.rdata
+                .iter()
+                .map(|rdata| self.normalize(set.r#type, rdata))
+                .collect()
+        };
+
+        a.ttl == b.ttl && normalize(a) == normalize(b)
+    }
+}
+
+impl std::fmt::Debug for EquivalenceRules {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EquivalenceRules")
+            .field("default", &self.default.as_ref().map(|_| ".."))
+            .field("types", &self.rules.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+/// Difference between two collections of records.
+///
+/// Records are compared by identity, so a record whose rdata changed
+/// shows up as one removal and one addition under the same owner and type.
+///
+/// Diffing [`Annotated`](crate::Annotated) identities produces a diff of
+/// the annotated identities, such that each change retains the provenance
+/// of the record it originates from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diff<T = RecordIdent> {
+    /// Records present in the desired state, but not the current one.
+    pub added: BTreeSet<T>,
+    /// Records present in the current state, but not the desired one.
+    pub removed: BTreeSet<T>,
+}
+
+impl<T> Default for Diff<T> {
+    fn default() -> Self {
+        Diff {
+            added: BTreeSet::new(),
+            removed: BTreeSet::new(),
+        }
+    }
+}
+
+impl<T: Borrow<RecordIdent> + Ord + Clone> Diff<T> {
+    /// Computes the changes required to go from `current` to `desired`.
+    pub fn between<'a>(
+        current: impl IntoIterator<Item = &'a T>,
+        desired: impl IntoIterator<Item = &'a T>,
+    ) -> Self
+    where
+        T: 'a,
+    {
+        let current: BTreeSet<&T> = current.into_iter().collect();
+        let desired: BTreeSet<&T> = desired.into_iter().collect();
+
+        Diff {
+            added: desired.difference(&current).copied().cloned().collect(),
+            removed: current.difference(&desired).copied().cloned().collect(),
+        }
+    }
+
+    /// Computes the changes required to go from `current` to `desired`,
+    /// ignoring differences in record data which are equivalent according
+    /// to the given rules.
+    ///
+    /// Records are reported as they appear in `current` and `desired`.
+    pub fn between_with<'a>(
+        current: impl IntoIterator<Item = &'a T>,
+        desired: impl IntoIterator<Item = &'a T>,
+        rules: &EquivalenceRules,
+    ) -> Self
+    where
+        T: 'a,
+    {
+        let normalize = |idents: &BTreeSet<&'a T>| -> BTreeSet<RecordIdent> {
+            idents
+                .iter()
+                .map(|ident| rules.normalize_ident((*ident).borrow()))
+                .collect()
+        };
+
+        let current: BTreeSet<&T> = current.into_iter().collect();
+        let desired: BTreeSet<&T> = desired.into_iter().collect();
+        let (normalized_current, normalized_desired) = (normalize(&current), normalize(&desired));
+
+        Diff {
+            added: desired
+                .into_iter()
+                .filter(|ident| {
+                    !normalized_current.contains(&rules.normalize_ident((*ident).borrow()))
+                })
+                .cloned()
+                .collect(),
+            removed: current
+                .into_iter()
+                .filter(|ident| {
+                    !normalized_desired.contains(&rules.normalize_ident((*ident).borrow()))
+                })
+                .cloned()
+                .collect(),
+        }
+    }
+
+    /// Returns true if there are no differences.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+
+    /// Returns the owner and type of record sets which have both
+    /// added and removed records, i.e. which were changed.
+    pub fn changed(&self) -> BTreeSet<(&FullyQualifiedDomainName, Type)> {
+        fn key<T: Borrow<RecordIdent>>(ident: &T) -> (&FullyQualifiedDomainName, Type) {
+            let ident: &RecordIdent = ident.borrow();
+            (&ident.fqdn, ident.r#type)
+        }
+
+        let added: BTreeSet<_> = self.added.iter().map(key).collect();
+
+        self.removed
+            .iter()
+            .map(key)
+            .filter(|key| added.contains(key))
+            .collect()
+    }
+
+    /// Iterates over all changes, ordered by owner and type, with removals
+    /// preceding additions for the same record set.
+    pub fn iter(&self) -> impl Iterator<Item = (Action, &T)> + '_ {
+        let mut entries: Vec<_> = self
+            .removed
+            .iter()
+            .map(|ident| (Action::Removed, ident))
+            .chain(self.added.iter().map(|ident| (Action::Added, ident)))
+            .collect();
+
+        entries.sort_by(|(a_action, a), (b_action, b)| {
+            let (a, b): (&RecordIdent, &RecordIdent) = ((*a).borrow(), (*b).borrow());
+            (&a.fqdn, a.r#type, a_action, &a.rdata).cmp(&(&b.fqdn, b.r#type, b_action, &b.rdata))
+        });
+
+        entries.into_iter()
+    }
+
+    /// Renders the diff similarly to a unified diff, with one hunk per
+    /// changed record set and records in zone-file syntax.
+    ///
+    /// ```text
+    /// @@ www.example.org. A @@
+    /// -www.example.org. A 192.168.0.1
+    /// +www.example.org. A 192.168.0.2
+    /// ```
+    pub fn unified(&self) -> Unified<'_, T> {
+        Unified(self)
+    }
+
+    /// Renders the diff as an aligned table.
+    ///
+    /// ```text
+    /// ACTION   NAME              TYPE  RDATA
+    /// removed  www.example.org.  A     192.168.0.1
+    /// added    www.example.org.  A     192.168.0.2
+    /// ```
+    pub fn table(&self) -> Table<'_, T> {
+        Table(self)
+    }
+}
+
+/// Kind of change applied to a record.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Action {
+    /// Record is removed.
+    Removed,
+    /// Record is added.
+    Added,
+}
+
+impl Display for Action {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Action::Removed => f.write_str("removed"),
+            Action::Added => f.write_str("added"),
+        }
+    }
+}
+
+/// Unified-diff-like rendering of a [`Diff`], produced by [`Diff::unified`].
+pub struct Unified<'a, T = RecordIdent>(&'a Diff<T>);
+
+impl<T: Borrow<RecordIdent> + Ord + Clone> Display for Unified<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut hunk = None;
+
+        for (action, ident) in self.0.iter() {
+            let ident: &RecordIdent = ident.borrow();
+            if hunk != Some((&ident.fqdn, ident.r#type)) {
+                hunk = Some((&ident.fqdn, ident.r#type));
+                writeln!(f, "@@ {} {} @@", ident.fqdn, ident.r#type)?;
+            }
+
+            match action {
+                Action::Removed => f.write_char('-')?,
+                Action::Added => f.write_char('+')?,
+            }
+
+            writeln!(f, "{} {} {}", ident.fqdn, ident.r#type, ident.rdata)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Tabular rendering of a [`Diff`], produced by [`Diff::table`].
+pub struct Table<'a, T = RecordIdent>(&'a Diff<T>);
+
+impl<T: Borrow<RecordIdent> + Ord + Clone> Display for Table<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let rows: Vec<_> = self
+            .0
+            .iter()
+            .map(|(action, ident)| {
+                let ident: &RecordIdent = ident.borrow();
+                [
+                    action.to_string(),
+                    ident.fqdn.to_string(),
+                    ident.r#type.to_string(),
+                    ident.rdata.to_string(),
+                ]
+            })
+            .collect();
+
+        let header = ["ACTION", "NAME", "TYPE", "RDATA"].map(String::from);
+
+        let mut widths = header.clone().map(|column| column.len());
+        for row in &rows {
+            for (width, column) in widths.iter_mut().zip(row) {
+                *width = (*width).max(column.len());
+            }
+        }
+
+        for row in std::iter::once(&header).chain(&rows) {
+            let [action, name, r#type, rdata] = row;
+            writeln!(
+                f,
+                "{action:<action_width$}  {name:<name_width$}  {type:<type_width$}  {rdata}",
+                action_width = widths[0],
+                name_width = widths[1],
+                type_width = widths[2],
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Records sharing the same owner, class and type, along with a single TTL.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RecordSet {
+    pub fqdn: FullyQualifiedDomainName,
+    pub class: Class,
+    pub r#type: Type,
+    pub ttl: u32,
+    pub rdata: BTreeSet<RecordData>,
+}
+
+impl RecordSet {
+    /// Iterates over the individual records of the set.
+    pub fn records(&self) -> impl Iterator<Item = Record> + '_ {
+        self.rdata.iter().map(|rdata| Record {
+            fqdn: self.fqdn.clone(),
+            class: self.class,
+            r#type: self.r#type,
+            ttl: self.ttl,
+            rdata: rdata.clone(),
+        })
+    }
+}
+
+/// Groups records into record sets, using the lowest TTL within
+/// each set for all of its records.
+fn record_sets<'a>(
+    records: impl IntoIterator<Item = &'a Record>,
+) -> BTreeMap<(FullyQualifiedDomainName, Class, Type), RecordSet> {
+    let mut sets = BTreeMap::new();
+
+    for record in records {
+        let set = sets
+            .entry((record.fqdn.clone(), record.class, record.r#type))
+            .or_insert_with(|| RecordSet {
+                fqdn: record.fqdn.clone(),
+                class: record.class,
+                r#type: record.r#type,
+                ttl: record.ttl,
+                rdata: BTreeSet::new(),
+            });
+
+        set.ttl = set.ttl.min(record.ttl);
+        set.rdata.insert(record.rdata.clone());
+    }
+
+    sets
+}
+
+/// Operation on a single [`RecordSet`].
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Change {
+    /// Create a record set which does not exist yet.
+    Create(RecordSet),
+    /// Replace an existing record set.
+    Upsert(RecordSet),
+    /// Delete an existing record set, which is provided as it currently exists.
+    Delete(RecordSet),
+}
+
+impl Change {
+    /// Record set the change applies to.
+    pub fn record_set(&self) -> &RecordSet {
+        match self {
+            Change::Create(set) | Change::Upsert(set) | Change::Delete(set) => set,
+        }
+    }
+}
+
+/// Provider-neutral batch of changes to record sets, for applying
+/// the difference between two collections of records to a provider.
+///
+/// Unlike [`Diff`], changes apply to whole record sets, and include
+/// changes to TTLs.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ChangeBatch {
+    pub changes: Vec<Change>,
+}
+
+impl ChangeBatch {
+    /// Computes the changes required to go from `current` to `desired`,
+    /// ordered by owner, class and type.
+    pub fn between<'a>(
+        current: impl IntoIterator<Item = &'a Record>,
+        desired: impl IntoIterator<Item = &'a Record>,
+    ) -> Self {
+        Self::between_sets(record_sets(current), record_sets(desired))
+    }
+
+    /// Computes the changes required to go from `current` to `desired`,
+    /// without upserting record sets whose record data is equivalent
+    /// according to the given rules.
+    pub fn between_with<'a>(
+        current: impl IntoIterator<Item = &'a Record>,
+        desired: impl IntoIterator<Item = &'a Record>,
+        rules: &EquivalenceRules,
+    ) -> Self {
+        Self::between_sets_with(record_sets(current), record_sets(desired), rules)
+    }
+
+    /// Computes the changes required to go from `current` to `desired`,
+    /// given records already grouped into record sets.
+    pub(crate) fn between_sets(
+        current: BTreeMap<(FullyQualifiedDomainName, Class, Type), RecordSet>,
+        desired: BTreeMap<(FullyQualifiedDomainName, Class, Type), RecordSet>,
+    ) -> Self {
+        Self::between_sets_with(current, desired, &EquivalenceRules::default())
+    }
+
+    fn between_sets_with(
+        mut current: BTreeMap<(FullyQualifiedDomainName, Class, Type), RecordSet>,
+        desired: BTreeMap<(FullyQualifiedDomainName, Class, Type), RecordSet>,
+        rules: &EquivalenceRules,
+    ) -> Self {
+        let mut changes = Vec::new();
+
+        for (key, desired) in desired {
+            match current.remove(&key) {
+                None => changes.push(Change::Create(desired)),
+                Some(current) if !rules.equivalent_sets(&current, &desired) => {
+                    changes.push(Change::Upsert(desired))
+                }
+                Some(_) => {}
+            }
+        }
+
+        changes.extend(current.into_values().map(Change::Delete));
+        changes.sort_by(|a, b| {
+            let (a, b) = (a.record_set(), b.record_set());
+            (&a.fqdn, a.class, a.r#type).cmp(&(&b.fqdn, b.class, b.r#type))
+        });
+
+        ChangeBatch { changes }
+    }
+
+    /// Returns true if the batch contains no changes.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Number of changes in the batch.
+    pub fn len(&self) -> usize {
+        self.changes.len()
+    }
+
+    /// Iterates over the changes in the batch.
+    pub fn iter(&self) -> impl Iterator<Item = &Change> + '_ {
+        self.changes.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        diff::{
+            ignore_trailing_dots, ignore_txt_splitting, Change, ChangeBatch, Diff, EquivalenceRules,
+        },
+        record::record,
+        FullyQualifiedDomainName, RecordData, RecordIdent, Type,
+    };
+
+    fn ident(fqdn: &str, r#type: Type, rdata: &str) -> RecordIdent {
+        RecordIdent {
+            fqdn: FullyQualifiedDomainName::try_from(fqdn).unwrap(),
+            r#type,
+            rdata: RecordData::parse(r#type, rdata).unwrap(),
+        }
+    }
+
+    #[test]
+    fn unified_rendering() {
+        let current = [
+            ident("www.example.org.", Type::A, "192.168.0.1"),
+            ident("example.org.", Type::MX, "10 mail.example.org."),
+        ];
+
+        let desired = [
+            ident("www.example.org.", Type::A, "192.168.0.2"),
+            ident("example.org.", Type::MX, "10 mail.example.org."),
+            ident("api.example.org.", Type::CNAME, "www.example.org."),
+        ];
+
+        let diff = Diff::between(&current, &desired);
+
+        assert_eq!(diff.changed().len(), 1);
+        assert_eq!(
+            diff.unified().to_string(),
+            "@@ api.example.org. CNAME @@\n\
+             +api.example.org. CNAME www.example.org.\n\
+             @@ www.example.org. A @@\n\
+             -www.example.org. A 192.168.0.1\n\
+             +www.example.org. A 192.168.0.2\n"
+        );
+    }
+
+    #[test]
+    fn table_rendering() {
+        let current = [ident("www.example.org.", Type::A, "192.168.0.1")];
+        let desired = [ident("www.example.org.", Type::A, "192.168.0.2")];
+
+        assert_eq!(
+            Diff::between(&current, &desired).table().to_string(),
+            "ACTION   NAME              TYPE  RDATA\n\
+             removed  www.example.org.  A     192.168.0.1\n\
+             added    www.example.org.  A     192.168.0.2\n"
+        );
+    }
+
+    #[test]
+    fn change_batch() {
+        let current = [
+            record("www.example.org. 300 IN A 192.168.0.1"),
+            record("example.org. 300 IN MX 10 mail.example.org."),
+            record("old.example.org. 300 IN A 192.168.0.3"),
+            record("ttl.example.org. 300 IN A 192.168.0.4"),
+        ];
+
+        let desired = [
+            record("www.example.org. 300 IN A 192.168.0.1"),
+            record("www.example.org. 300 IN A 192.168.0.2"),
+            record("example.org. 300 IN MX 10 mail.example.org."),
+            record("api.example.org. 300 IN CNAME www.example.org."),
+            record("ttl.example.org. 60 IN A 192.168.0.4"),
+        ];
+
+        let batch = ChangeBatch::between(&current, &desired);
+
+        assert_eq!(
+            batch
+                .iter()
+                .map(|change| {
+                    let set = change.record_set();
+                    let kind = match change {
+                        Change::Create(_) => "create",
+                        Change::Upsert(_) => "upsert",
+                        Change::Delete(_) => "delete",
+                    };
+                    format!(
+                        "{kind} {} {} {} {}",
+                        set.fqdn,
+                        set.r#type,
+                        set.ttl,
+                        set.rdata.len()
+                    )
+                })
+                .collect::<Vec<_>>(),
+            [
+                "create api.example.org. CNAME 300 1",
+                "delete old.example.org. A 300 1",
+                "upsert ttl.example.org. A 60 1",
+                "upsert www.example.org. A 300 2",
+            ]
+        );
+
+        let Change::Upsert(set) = &batch.changes[3] else {
+            panic!("expected upsert");
+        };
+        assert_eq!(set.records().collect::<Vec<_>>(), desired[..2]);
+
+        assert!(ChangeBatch::between(&current, &current).is_empty());
+    }
+
+    #[test]
+    fn equivalence_rules() {
+        let rules = EquivalenceRules::new()
+            .with_default(ignore_trailing_dots)
+            .with(Type::TXT, ignore_txt_splitting)
+            .with(Type::from_code(65280), |rdata| match rdata {
+                RecordData::Other(hex) => RecordData::Other(hex.to_ascii_uppercase()),
+                rdata => rdata.clone(),
+            });
+
+        assert!(rules.equivalent(
+            Type::from_code(65280),
+            &RecordData::Other("\\# 1 ab".to_string()),
+            &RecordData::Other("\\# 1 AB".to_string())
+        ));
+
+        let current = [
+            ident("api.example.org.", Type::CNAME, "www.example.org"),
+            ident("example.org.", Type::MX, "10 mail.example.org"),
+        ];
+        let desired = [
+            ident("api.example.org.", Type::CNAME, "www.example.org."),
+            ident("example.org.", Type::MX, "10 mail.example.org."),
+        ];
+
+        assert_eq!(Diff::between(&current, &desired).changed().len(), 2);
+        assert!(Diff::between_with(&current, &desired, &rules).is_empty());
+
+        let current = [record("example.org. 300 IN TXT \"v=spf1 \" \"-all\"")];
+        let desired = [record("example.org. 300 IN TXT \"v=spf1 -all\"")];
+
+        assert_eq!(ChangeBatch::between(&current, &desired).len(), 1);
+        assert!(ChangeBatch::between_with(&current, &desired, &rules).is_empty());
+
+        // Differences in TTL are never ignored.
+        let desired = [record("example.org. 60 IN TXT \"v=spf1 -all\"")];
+        assert_eq!(
+            ChangeBatch::between_with(&current, &desired, &rules).len(),
+            1
+        );
+
+        // Rules may capture state, such as an origin only known at runtime.
+        let origin = FullyQualifiedDomainName::try_from("example.org.").unwrap();
+        let rules =
+            EquivalenceRules::new().with(Type::CNAME, move |rdata| rdata.with_origin(&origin));
+        assert!(rules.equivalent(
+            Type::CNAME,
+            &RecordData::parse(Type::CNAME, "www").unwrap(),
+            &RecordData::parse(Type::CNAME, "www.example.org.").unwrap()
+        ));
+    }
+}
@@ -1,18 +1,27 @@
-use std::fmt::Display;
+use std::{cmp::Ordering, fmt::Display, str::FromStr};
 
+#[cfg(feature = "schemars")]
 use schemars::JsonSchema;
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 use crate::{
     fqdn::FullyQualifiedDomainNameError,
-    segment::{DomainSegment, DomainSegmentError},
+    pqdn::PartiallyQualifiedDomainNameError,
+    segment::{
+        validate_name_length, DomainSegment, DomainSegmentError, LabelError, NameLengthError,
+    },
     FullyQualifiedDomainName, PartiallyQualifiedDomainName,
 };
 
 /// Either a [`FullyQualifiedDomainName`] or a [`PartiallyQualifiedDomainName`].
-#[derive(Clone, Debug, Serialize, Deserialize, Hash, PartialEq, Eq, PartialOrd, Ord)]
-#[serde(untagged)]
+///
+/// Domain names are ordered by their segments first, regardless of whether
+/// they are fully or partially qualified. Only if the segments are equal is
+/// the partially qualified name ordered before the fully qualified one.
+#[derive(Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(untagged))]
 pub enum DomainName {
     /// Domain name is fully qualified.
     Full(FullyQualifiedDomainName),
@@ -53,6 +62,16 @@ impl DomainName {
         }
     }
 
+    /// Asserts the invariants of the contained domain name.
+    ///
+    /// See [`FullyQualifiedDomainName::debug_validate`].
+    pub fn debug_validate(&self) {
+        match self {
+            DomainName::Full(full) => full.debug_validate(),
+            DomainName::Partial(partial) => partial.debug_validate(),
+        }
+    }
+
     /// Returns the contained [`DomainSegment`]s as a[`FullyQualifiedDomainName`]
     pub fn into_fully_qualified(self) -> FullyQualifiedDomainName {
         match self {
@@ -85,6 +104,30 @@ impl DomainName {
         }
     }
 
+    /// Returns the domain name as fully qualified, appending the origin
+    /// if it is only partially qualified.
+    ///
+    /// See [`DomainName::try_with_origin`] for a validating alternative.
+    pub fn with_origin(&self, origin: &FullyQualifiedDomainName) -> FullyQualifiedDomainName {
+        match self {
+            DomainName::Full(full) => full.clone(),
+            DomainName::Partial(partial) => partial.with_origin(origin),
+        }
+    }
+
+    /// Returns the domain name as fully qualified, appending the origin
+    /// if it is only partially qualified, or an error if the resulting
+    /// domain name would be too long.
+    pub fn try_with_origin(
+        &self,
+        origin: &FullyQualifiedDomainName,
+    ) -> Result<FullyQualifiedDomainName, DomainNameError> {
+        let fqdn = self.with_origin(origin);
+        validate_name_length(fqdn.iter().map(DomainSegment::len))?;
+
+        Ok(fqdn)
+    }
+
     /// Iterates over all [`DomainSegment`]s that make up the domain name.
     pub fn iter(&self) -> core::slice::Iter<'_, DomainSegment> {
         match self {
@@ -93,10 +136,23 @@ impl DomainName {
         }
     }
 
-    /// Returns the length of the domain.
+    /// Splits off the leftmost label without cloning. The remaining labels
+    /// are qualified the same way as `self`.
+    pub fn split_first(&self) -> Option<(&DomainSegment, &[DomainSegment])> {
+        self.as_ref().split_first()
+    }
+
+    /// Splits off the rightmost label without cloning. The remaining
+    /// labels are never anchored at the root, see
+    /// [`FullyQualifiedDomainName::split_last`].
+    pub fn split_last(&self) -> Option<(&DomainSegment, &[DomainSegment])> {
+        self.as_ref().split_last()
+    }
+
+    /// Returns the length of the domain in octets, with escapes decoded.
     ///
-    /// Note that fully qualified domain names will include the trailing dot
-    /// in this measurement.
+    /// Note that both fully and partially qualified domain names include
+    /// a trailing dot in this measurement, see [`FullyQualifiedDomainName::len`].
     #[allow(clippy::len_without_is_empty)]
     pub fn len(&self) -> usize {
         match self {
@@ -104,18 +160,61 @@ impl DomainName {
             DomainName::Partial(partial) => partial.len(),
         }
     }
+
+    /// Prepends the labels of `prefix`, such as `www` or `*.dev`, which
+    /// are parsed and validated along with the resulting name.
+    ///
+    /// Offsets within errors refer to the resulting name.
+    pub fn try_prepend(&self, prefix: &str) -> Result<DomainName, DomainNameError> {
+        match self {
+            DomainName::Partial(partial) if partial.0.is_empty() => DomainName::try_from(prefix),
+            name => DomainName::try_from(format!("{prefix}.{name}")),
+        }
+    }
 }
 
 /// Produced when attempting to construct a [`DomainName`] from
 /// an invalid string.
 #[derive(Error, Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub enum DomainNameError {
-    /// Invalid Domain Segment.
-    #[error("segment error: {0}")]
-    SegmentError(DomainSegmentError),
+    /// The label starting at byte `offset` within the input is invalid.
+    #[error("invalid label {label:?} at offset {offset}: {error}")]
+    SegmentError {
+        offset: usize,
+        label: String,
+        error: DomainSegmentError,
+    },
     /// Wildcards must only appear in the very first segment of a domain.
     #[error("non-leading wildcard")]
     NonLeadingWildcard,
+    /// The name contains a wildcard, but was parsed with [`WildcardPolicy::Reject`](crate::WildcardPolicy::Reject).
+    #[error("wildcard names are not permitted")]
+    WildcardNotPermitted,
+    /// The domain name is longer than the permitted 255 octets in wire format.
+    #[error("domain too long {0} > 255")]
+    TooLong(usize),
+    /// The domain name consists of more than the permitted 127 labels.
+    #[error("domain has too many labels {0} > 127")]
+    TooManyLabels(usize),
+}
+
+impl From<LabelError> for DomainNameError {
+    fn from(value: LabelError) -> Self {
+        DomainNameError::SegmentError {
+            offset: value.offset,
+            label: value.label,
+            error: value.error,
+        }
+    }
+}
+
+impl From<NameLengthError> for DomainNameError {
+    fn from(value: NameLengthError) -> Self {
+        match value {
+            NameLengthError::TooLong(length) => DomainNameError::TooLong(length),
+            NameLengthError::TooManyLabels(count) => DomainNameError::TooManyLabels(count),
+        }
+    }
 }
 
 impl Default for DomainName {
@@ -150,19 +249,68 @@ impl TryFrom<&str> for DomainName {
     fn try_from(value: &str) -> Result<Self, Self::Error> {
         match FullyQualifiedDomainName::try_from(value) {
             Ok(fqdn) => Ok(DomainName::Full(fqdn)),
-            Err(FullyQualifiedDomainNameError::DomainIsPartiallyQualified) => Ok(
-                DomainName::Partial(PartiallyQualifiedDomainName::try_from(value).unwrap()),
-            ),
-            Err(FullyQualifiedDomainNameError::SegmentError(err)) => {
-                Err(DomainNameError::SegmentError(err))
+            Err(FullyQualifiedDomainNameError::DomainIsPartiallyQualified) => {
+                match PartiallyQualifiedDomainName::try_from(value) {
+                    Ok(pqdn) => Ok(DomainName::Partial(pqdn)),
+                    Err(PartiallyQualifiedDomainNameError::DomainIsFullyQualified) => {
+                        unreachable!("domain is both fully and partially qualified")
+                    }
+                    Err(PartiallyQualifiedDomainNameError::SegmentError {
+                        offset,
+                        label,
+                        error,
+                    }) => Err(DomainNameError::SegmentError {
+                        offset,
+                        label,
+                        error,
+                    }),
+                    Err(PartiallyQualifiedDomainNameError::NonLeadingWildcard) => {
+                        Err(DomainNameError::NonLeadingWildcard)
+                    }
+                    Err(PartiallyQualifiedDomainNameError::WildcardNotPermitted) => {
+                        Err(DomainNameError::WildcardNotPermitted)
+                    }
+                    Err(PartiallyQualifiedDomainNameError::TooLong(length)) => {
+                        Err(DomainNameError::TooLong(length))
+                    }
+                    Err(PartiallyQualifiedDomainNameError::TooManyLabels(count)) => {
+                        Err(DomainNameError::TooManyLabels(count))
+                    }
+                }
             }
+            Err(FullyQualifiedDomainNameError::SegmentError {
+                offset,
+                label,
+                error,
+            }) => Err(DomainNameError::SegmentError {
+                offset,
+                label,
+                error,
+            }),
             Err(FullyQualifiedDomainNameError::NonLeadingWildcard) => {
                 Err(DomainNameError::NonLeadingWildcard)
             }
+            Err(FullyQualifiedDomainNameError::WildcardNotPermitted) => {
+                Err(DomainNameError::WildcardNotPermitted)
+            }
+            Err(FullyQualifiedDomainNameError::TooLong(length)) => {
+                Err(DomainNameError::TooLong(length))
+            }
+            Err(FullyQualifiedDomainNameError::TooManyLabels(count)) => {
+                Err(DomainNameError::TooManyLabels(count))
+            }
         }
     }
 }
 
+impl FromStr for DomainName {
+    type Err = DomainNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
 impl Display for DomainName {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -181,6 +329,16 @@ impl AsRef<[DomainSegment]> for DomainName {
     }
 }
 
+/// Shows the name as either `Fqdn("www.example.org.")` or `Pqdn("www")`.
+impl std::fmt::Debug for DomainName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DomainName::Full(full) => std::fmt::Debug::fmt(full, f),
+            DomainName::Partial(partial) => std::fmt::Debug::fmt(partial, f),
+        }
+    }
+}
+
 impl PartialEq<PartiallyQualifiedDomainName> for DomainName {
     fn eq(&self, other: &PartiallyQualifiedDomainName) -> bool {
         match self {
@@ -199,6 +357,41 @@ impl PartialEq<FullyQualifiedDomainName> for DomainName {
     }
 }
 
+impl Ord for DomainName {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_ref()
+            .cmp(other.as_ref())
+            .then_with(|| self.is_fully_qualified().cmp(&other.is_fully_qualified()))
+    }
+}
+
+impl PartialOrd for DomainName {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialOrd<PartiallyQualifiedDomainName> for DomainName {
+    fn partial_cmp(&self, other: &PartiallyQualifiedDomainName) -> Option<Ordering> {
+        Some(
+            self.as_ref()
+                .cmp(other.as_ref())
+                .then_with(|| self.is_fully_qualified().cmp(&false)),
+        )
+    }
+}
+
+impl PartialOrd<FullyQualifiedDomainName> for DomainName {
+    fn partial_cmp(&self, other: &FullyQualifiedDomainName) -> Option<Ordering> {
+        Some(
+            self.as_ref()
+                .cmp(other.as_ref())
+                .then_with(|| self.is_fully_qualified().cmp(&true)),
+        )
+    }
+}
+
+#[cfg(feature = "schemars")]
 impl JsonSchema for DomainName {
     fn schema_name() -> String {
         <String as JsonSchema>::schema_name()
@@ -209,11 +402,32 @@ impl JsonSchema for DomainName {
     }
 }
 
+#[cfg(feature = "valuable")]
+impl valuable::Valuable for DomainName {
+    fn as_value(&self) -> valuable::Value<'_> {
+        match self {
+            DomainName::Full(full) => full.as_value(),
+            DomainName::Partial(partial) => partial.as_value(),
+        }
+    }
+
+    fn visit(&self, visit: &mut dyn valuable::Visit) {
+        match self {
+            DomainName::Full(full) => full.visit(visit),
+            DomainName::Partial(partial) => partial.visit(visit),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{DomainName, FullyQualifiedDomainName, PartiallyQualifiedDomainName};
+    use crate::{
+        error::{DomainNameError, DomainSegmentError},
+        DomainName, FullyQualifiedDomainName, PartiallyQualifiedDomainName,
+    };
 
     #[test]
+    #[cfg(feature = "serde")]
     fn deser() {
         let fqdn = DomainName::from(FullyQualifiedDomainName::try_from("example.org.").unwrap());
         let pqdn = DomainName::from(PartiallyQualifiedDomainName::try_from("example.org").unwrap());
@@ -231,4 +445,55 @@ mod tests {
             pqdn
         );
     }
+
+    #[test]
+    fn mixed_ordering() {
+        let mut names: Vec<DomainName> = ["b.org.", "a.org", "a.org.", "c.org", "b.org"]
+            .into_iter()
+            .map(|name| DomainName::try_from(name).unwrap())
+            .collect();
+
+        names.sort();
+
+        assert_eq!(
+            names.iter().map(ToString::to_string).collect::<Vec<_>>(),
+            ["a.org", "a.org.", "b.org", "b.org.", "c.org"]
+        );
+
+        let fqdn = FullyQualifiedDomainName::try_from("b.org.").unwrap();
+        let pqdn = PartiallyQualifiedDomainName::try_from("b.org").unwrap();
+
+        assert!(DomainName::from(pqdn.clone()) < fqdn);
+        assert!(DomainName::from(fqdn.clone()) > pqdn);
+        assert!(DomainName::try_from("a.org.").unwrap() < pqdn);
+        assert!(DomainName::try_from("c.org").unwrap() > fqdn);
+    }
+
+    #[test]
+    fn invalid_partial_names() {
+        assert_eq!(
+            DomainName::try_from("a..b"),
+            Err(DomainNameError::SegmentError {
+                offset: 2,
+                label: String::new(),
+                error: DomainSegmentError::EmptyString
+            })
+        );
+        assert_eq!(
+            DomainName::try_from("a.".repeat(127) + "a"),
+            Err(DomainNameError::TooManyLabels(128))
+        );
+    }
+
+    #[test]
+    fn debug_format() {
+        assert_eq!(
+            format!("{:?}", DomainName::try_from("www.example.org.").unwrap()),
+            "Fqdn(\"www.example.org.\")"
+        );
+        assert_eq!(
+            format!("{:?}", DomainName::try_from("www").unwrap()),
+            "Pqdn(\"www\")"
+        );
+    }
 }
@@ -0,0 +1,119 @@
+use thiserror::Error;
+
+use crate::{DomainName, FullyQualifiedDomainName, Record, RecordData};
+
+/// Produced when DNAME substitution cannot be applied to a name.
+#[derive(Error, Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum DnameError {
+    /// The record used for substitution is not a DNAME record.
+    #[error("record is not a DNAME record")]
+    NotDname,
+    /// The DNAME target is not fully qualified, and so cannot be substituted.
+    #[error("DNAME target {0} is not fully qualified")]
+    RelativeTarget(DomainName),
+    /// The name is not a proper subdomain of the DNAME owner, so
+    /// substitution does not apply.
+    #[error("{name} is not below DNAME owner {owner}")]
+    NotBelowOwner {
+        name: FullyQualifiedDomainName,
+        owner: FullyQualifiedDomainName,
+    },
+    /// The substituted name exceeds the maximum of 255 octets in wire format.
+    /// Servers answer such queries with YXDOMAIN.
+    #[error("substituted name is too long {0} > 255")]
+    TooLong(usize),
+}
+
+impl FullyQualifiedDomainName {
+    /// Applies DNAME substitution as described in RFC 6672, replacing the
+    /// `owner` suffix of the name with `target`.
+    ///
+    /// Substitution only applies to proper subdomains of the owner, the
+    /// owner itself is not redirected by a DNAME record.
+    pub fn substitute_dname(
+        &self,
+        owner: &FullyQualifiedDomainName,
+        target: &FullyQualifiedDomainName,
+    ) -> Result<FullyQualifiedDomainName, DnameError> {
+        if !self.is_subdomain_of(owner) {
+            return Err(DnameError::NotBelowOwner {
+                name: self.clone(),
+                owner: owner.clone(),
+            });
+        }
+
+        let prefix = &self.as_ref()[..self.as_ref().len() - owner.as_ref().len()];
+        let substituted: FullyQualifiedDomainName = prefix.iter().chain(target.iter()).collect();
+
+        // Wire format adds a length octet per label and the root label,
+        // which is one octet more than the presentation format with its dots.
+        let wire_length = substituted.len() + 1;
+        if wire_length > 255 {
+            return Err(DnameError::TooLong(wire_length));
+        }
+
+        Ok(substituted)
+    }
+}
+
+impl Record {
+    /// Applies DNAME substitution to `name`, using this record as the DNAME.
+    ///
+    /// See [`FullyQualifiedDomainName::substitute_dname`].
+    pub fn substitute_dname(
+        &self,
+        name: &FullyQualifiedDomainName,
+    ) -> Result<FullyQualifiedDomainName, DnameError> {
+        let RecordData::DNAME(target) = &self.rdata else {
+            return Err(DnameError::NotDname);
+        };
+
+        let DomainName::Full(target) = target else {
+            return Err(DnameError::RelativeTarget(target.clone()));
+        };
+
+        name.substitute_dname(&self.fqdn, target)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::DnameError, fqdn, Class, FullyQualifiedDomainName, Record, RecordData, Type,
+    };
+
+    #[test]
+    fn substitution() {
+        let dname = Record {
+            fqdn: fqdn!("example.com."),
+            class: Class::IN,
+            r#type: Type::DNAME,
+            ttl: 300,
+            rdata: RecordData::parse(Type::DNAME, "example.net.").unwrap(),
+        };
+
+        assert_eq!(
+            dname.substitute_dname(&fqdn!("a.b.example.com.")),
+            Ok(fqdn!("a.b.example.net."))
+        );
+
+        assert_eq!(
+            dname.substitute_dname(&fqdn!("example.com.")),
+            Err(DnameError::NotBelowOwner {
+                name: fqdn!("example.com."),
+                owner: fqdn!("example.com.")
+            })
+        );
+
+        let label = "a".repeat(63);
+        let long =
+            FullyQualifiedDomainName::try_from(format!("{label}.{label}.{label}.example.com."))
+                .unwrap();
+        let target = FullyQualifiedDomainName::try_from(format!("{label}.example.net.")).unwrap();
+
+        assert_eq!(
+            long.substitute_dname(&fqdn!("example.com."), &target),
+            Err(DnameError::TooLong(269))
+        );
+    }
+}
@@ -0,0 +1,286 @@
+use std::{
+    fmt::{Display, Write},
+    str::FromStr,
+};
+
+use thiserror::Error;
+
+use crate::{
+    error::{FullyQualifiedDomainNameError, ServiceNameError},
+    segment::split_labels,
+    FullyQualifiedDomainName, ServiceName,
+};
+
+/// Maximum length of the instance label in octets.
+const MAX_INSTANCE_LENGTH: usize = 63;
+
+/// Produced when constructing or parsing an [`InstanceName`] fails.
+#[derive(Error, Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum InstanceNameError {
+    /// The instance label is empty.
+    #[error("instance is an empty string")]
+    EmptyInstance,
+    /// The instance label is longer than the permitted 63 octets.
+    #[error("instance too long {0} > 63")]
+    InstanceTooLong(usize),
+    /// Escape sequence in the instance label is incomplete, or exceeds 255.
+    #[error("invalid escape sequence {0:?}")]
+    InvalidEscape(String),
+    /// The unescaped instance label is not valid UTF-8.
+    #[error("instance is not valid UTF-8")]
+    InvalidUtf8,
+    /// The labels following the instance are not a valid service name.
+    #[error("{0}")]
+    InvalidService(#[from] ServiceNameError),
+    /// The domain is invalid, or the complete name is not a valid domain
+    /// name, because it is too long, or the instance contains a wildcard.
+    #[error("invalid domain: {0}")]
+    InvalidDomain(#[from] FullyQualifiedDomainNameError),
+}
+
+/// Name of a service instance in DNS-Based Service Discovery (RFC 6763),
+/// such as `My Printer._ipp._tcp.local.`
+///
+/// Unlike other labels, the instance label is user-facing, and may contain
+/// any UTF-8 text, including spaces and dots. Its case is preserved.
+///
+/// When rendered as a domain name, such characters are escaped:
+///
+/// ```text
+/// My\032Printer._ipp._tcp.local.
+/// ```
+///
+/// Use [`InstanceName::fqdn`] as the owner of the SRV and TXT records of
+/// the instance, or as the target of the PTR record used for browsing.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct InstanceName {
+    instance: String,
+    service: ServiceName,
+    domain: FullyQualifiedDomainName,
+}
+
+impl InstanceName {
+    /// Constructs the name of an instance of `service` within `domain`.
+    pub fn new(
+        instance: impl Into<String>,
+        service: ServiceName,
+        domain: FullyQualifiedDomainName,
+    ) -> Result<Self, InstanceNameError> {
+        let instance = instance.into();
+
+        if instance.is_empty() {
+            return Err(InstanceNameError::EmptyInstance);
+        }
+
+        if instance.len() > MAX_INSTANCE_LENGTH {
+            return Err(InstanceNameError::InstanceTooLong(instance.len()));
+        }
+
+        service.with_domain(&domain)?;
+
+        let name = InstanceName {
+            instance,
+            service,
+            domain,
+        };
+        FullyQualifiedDomainName::try_from(name.to_string())?;
+
+        Ok(name)
+    }
+
+    /// User-facing name of the instance, without escapes.
+    pub fn instance(&self) -> &str {
+        &self.instance
+    }
+
+    /// Service of which this is an instance.
+    pub fn service(&self) -> &ServiceName {
+        &self.service
+    }
+
+    /// Domain in which the service is registered.
+    pub fn domain(&self) -> &FullyQualifiedDomainName {
+        &self.domain
+    }
+
+    /// Name of the service type the instance belongs to, such as
+    /// `_ipp._tcp.local.`, which holds the PTR records used for browsing.
+    pub fn service_name(&self) -> FullyQualifiedDomainName {
+        self.service
+            .with_domain(&self.domain)
+            .expect("service name was validated")
+    }
+
+    /// Complete name of the instance, such as `my\032printer._ipp._tcp.local.`
+    ///
+    /// Like all domain names, the name is lowercased, so the case of the
+    /// instance is only preserved by [`InstanceName::instance`] and when
+    /// displaying the instance name itself.
+    pub fn fqdn(&self) -> FullyQualifiedDomainName {
+        FullyQualifiedDomainName::try_from(self.to_string()).expect("instance name was validated")
+    }
+}
+
+/// Decodes the `\X` and `\DDD` escapes of a label into raw octets.
+fn unescape(label: &str) -> Result<Vec<u8>, InstanceNameError> {
+    let mut bytes = Vec::with_capacity(label.len());
+    let mut chars = label.char_indices();
+
+    while let Some((start, c)) = chars.next() {
+        if c != '\\' {
+            bytes.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes());
+            continue;
+        }
+
+        let invalid = |end: usize| InstanceNameError::InvalidEscape(label[start..end].to_string());
+
+        match chars.next() {
+            Some((_, digit)) if digit.is_ascii_digit() => {
+                let end = start + 4;
+                let byte = label
+                    .get(start + 1..end)
+                    .filter(|digits| digits.bytes().all(|byte| byte.is_ascii_digit()))
+                    .and_then(|digits| digits.parse::<u8>().ok())
+                    .ok_or_else(|| invalid(label.len().min(end)))?;
+
+                chars.nth(1);
+                bytes.push(byte);
+            }
+            Some((_, escaped)) => {
+                bytes.extend_from_slice(escaped.encode_utf8(&mut [0; 4]).as_bytes())
+            }
+            None => return Err(invalid(label.len())),
+        }
+    }
+
+    Ok(bytes)
+}
+
+impl TryFrom<&str> for InstanceName {
+    type Error = InstanceNameError;
+
+    /// Parses an instance name in presentation format, where the instance
+    /// label may use escapes, including `\DDD` escapes of UTF-8 octets.
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let instance = split_labels(value).next().unwrap_or_default();
+        let rest = value
+            .get(instance.len() + 1..)
+            .ok_or(ServiceNameError::MissingService)?;
+
+        let instance =
+            String::from_utf8(unescape(instance)?).map_err(|_| InstanceNameError::InvalidUtf8)?;
+
+        let (service, domain) = ServiceName::split(&FullyQualifiedDomainName::try_from(rest)?)?;
+
+        InstanceName::new(instance, service, domain)
+    }
+}
+
+impl TryFrom<String> for InstanceName {
+    type Error = InstanceNameError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+
+impl FromStr for InstanceName {
+    type Err = InstanceNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl Display for InstanceName {
+    /// Renders the name in presentation format, escaping non-printable
+    /// and non-ASCII octets as `\DDD`, and other characters not permitted
+    /// in domain names as `\X`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for byte in self.instance.bytes() {
+            match byte {
+                byte if byte.is_ascii_alphanumeric() || byte == b'-' || byte == b'_' => {
+                    f.write_char(char::from(byte))?
+                }
+                byte if byte.is_ascii_graphic() => write!(f, "\\{}", char::from(byte))?,
+                byte => write!(f, "\\{byte:03}")?,
+            }
+        }
+
+        write!(f, ".{}", self.service_name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::{InstanceNameError, ServiceNameError},
+        FullyQualifiedDomainName, InstanceName, Protocol, ServiceName,
+    };
+
+    #[test]
+    fn escaping() {
+        let name = InstanceName::new(
+            "My Printer v2.0",
+            ServiceName::new("ipp", Protocol::Tcp),
+            FullyQualifiedDomainName::try_from("local.").unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            name.to_string(),
+            "My\\032Printer\\032v2\\.0._ipp._tcp.local."
+        );
+        assert_eq!(name.service_name().to_string(), "_ipp._tcp.local.");
+        assert_eq!(
+            name.fqdn().to_string(),
+            "my\\032printer\\032v2\\.0._ipp._tcp.local."
+        );
+        assert_eq!(name.fqdn().parent(), Some(name.service_name()));
+        assert_eq!(InstanceName::try_from(name.to_string()), Ok(name));
+
+        let printer = InstanceName::try_from("Printer\\ #2._ipp._tcp.local.").unwrap();
+        assert_eq!(printer.instance(), "Printer #2");
+        assert_eq!(printer.to_string(), "Printer\\032\\#2._ipp._tcp.local.");
+        assert_eq!(
+            printer.fqdn(),
+            FullyQualifiedDomainName::try_from(printer.to_string()).unwrap()
+        );
+
+        let utf8 = InstanceName::try_from("Caf\\195\\169._http._tcp.local.").unwrap();
+        assert_eq!(utf8.instance(), "Café");
+        assert_eq!(utf8.to_string(), "Caf\\195\\169._http._tcp.local.");
+        assert_eq!(
+            utf8.fqdn().iter().next().unwrap().as_raw(),
+            "café".as_bytes()
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            InstanceName::try_from("\\255._http._tcp.local."),
+            Err(InstanceNameError::InvalidUtf8)
+        );
+        assert_eq!(
+            InstanceName::try_from("Printer._tcp.local."),
+            Err(InstanceNameError::InvalidService(
+                ServiceNameError::MissingProtocol
+            ))
+        );
+        assert_eq!(
+            InstanceName::try_from("Printer"),
+            Err(InstanceNameError::InvalidService(
+                ServiceNameError::MissingService
+            ))
+        );
+        assert_eq!(
+            InstanceName::try_from(format!("{}._http._tcp.local.", "a".repeat(64))),
+            Err(InstanceNameError::InstanceTooLong(64))
+        );
+        assert!(matches!(
+            InstanceName::try_from("a*b._http._tcp.local."),
+            Err(InstanceNameError::InvalidDomain(_))
+        ));
+    }
+}
@@ -0,0 +1,136 @@
+use std::borrow::Cow;
+
+use thiserror::Error;
+
+use crate::{
+    error::{DomainSegmentError, FullyQualifiedDomainNameError},
+    segment::validate_name_length,
+    DomainSegment, FullyQualifiedDomainName,
+};
+
+/// Produced when an email address cannot be converted into an SOA RNAME.
+#[derive(Error, Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum EmailError {
+    /// The address does not contain an `@` separating local part and domain.
+    #[error("missing @ in email address")]
+    MissingAt,
+    /// The local part is not a valid domain segment.
+    #[error("invalid local part: {0}")]
+    InvalidLocalPart(DomainSegmentError),
+    /// The domain of the address is invalid.
+    #[error("invalid domain: {0}")]
+    InvalidDomain(FullyQualifiedDomainNameError),
+}
+
+impl FullyQualifiedDomainName {
+    /// Converts an email address into the mailbox format used by the RNAME
+    /// field of SOA records (RFC 1035, section 8), such that
+    /// `hostmaster@example.org` becomes `hostmaster.example.org.`
+    ///
+    /// Dots in the local part are escaped, such that `john.doe@example.org`
+    /// becomes `john\.doe.example.org.`, and a trailing dot on the domain
+    /// of the address is accepted.
+    pub fn from_rfc822_email(email: &str) -> Result<Self, EmailError> {
+        let (local, domain) = email.rsplit_once('@').ok_or(EmailError::MissingAt)?;
+
+        let local = match DomainSegment::try_from(local.replace('.', "\\.")) {
+            Ok(segment) if segment.is_wildcard() => Err(EmailError::InvalidLocalPart(
+                DomainSegmentError::InvalidCharacter {
+                    character: '*',
+                    position: 0,
+                },
+            )),
+            Ok(segment) => Ok(segment),
+            Err(err) => Err(EmailError::InvalidLocalPart(err)),
+        }?;
+
+        let domain = if domain.ends_with('.') {
+            FullyQualifiedDomainName::try_from(domain)
+        } else {
+            FullyQualifiedDomainName::try_from(format!("{domain}."))
+        }
+        .map_err(EmailError::InvalidDomain)?;
+
+        let mut labels = vec![local];
+        labels.extend(domain.iter().cloned());
+        let rname = FullyQualifiedDomainName(labels);
+
+        validate_name_length(rname.iter().map(DomainSegment::len))
+            .map_err(|err| EmailError::InvalidDomain(err.into()))?;
+
+        Ok(rname)
+    }
+
+    /// Converts an SOA RNAME back into an email address, such that
+    /// `hostmaster.example.org.` becomes `hostmaster@example.org`
+    ///
+    /// Returns [`None`] for names with fewer than two labels,
+    /// which have no domain part.
+    pub fn to_rfc822_email(&self) -> Option<String> {
+        let (local, domain) = self.0.split_first()?;
+
+        if domain.is_empty() {
+            return None;
+        }
+
+        // The local part may contain dots, which are escaped in the name.
+        let local = std::str::from_utf8(local.as_raw()).ok()?;
+        let domain: Vec<Cow<str>> = domain.iter().map(DomainSegment::as_presentation).collect();
+
+        Some(format!("{local}@{}", domain.join(".")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::{DomainSegmentError, EmailError},
+        FullyQualifiedDomainName,
+    };
+
+    #[test]
+    fn round_trip() {
+        let rname = FullyQualifiedDomainName::from_rfc822_email("Hostmaster@Example.org").unwrap();
+
+        assert_eq!(rname.to_string(), "hostmaster.example.org.");
+        assert_eq!(
+            rname.to_rfc822_email().as_deref(),
+            Some("hostmaster@example.org")
+        );
+        assert_eq!(
+            FullyQualifiedDomainName::from_rfc822_email("hostmaster@example.org."),
+            Ok(rname)
+        );
+
+        let dotted = FullyQualifiedDomainName::from_rfc822_email("john.doe@example.org").unwrap();
+        assert_eq!(dotted.to_string(), "john\\.doe.example.org.");
+        assert_eq!(
+            dotted.to_rfc822_email().as_deref(),
+            Some("john.doe@example.org")
+        );
+
+        let tld = FullyQualifiedDomainName::try_from("org.").unwrap();
+        assert_eq!(tld.to_rfc822_email(), None);
+    }
+
+    #[test]
+    fn invalid_addresses() {
+        assert_eq!(
+            FullyQualifiedDomainName::from_rfc822_email("example.org"),
+            Err(EmailError::MissingAt)
+        );
+        assert_eq!(
+            FullyQualifiedDomainName::from_rfc822_email("john+dns@example.org"),
+            Err(EmailError::InvalidLocalPart(
+                DomainSegmentError::InvalidCharacter {
+                    character: '+',
+                    position: 4
+                }
+            ))
+        );
+        assert!(matches!(
+            FullyQualifiedDomainName::from_rfc822_email("hostmaster@example..org"),
+            Err(EmailError::InvalidDomain(_))
+        ));
+    }
+}
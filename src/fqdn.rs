@@ -1,17 +1,29 @@
 use std::{
     fmt::{Debug, Display, Write},
     ops::Sub,
+    str::FromStr,
 };
 
-use schemars::JsonSchema;
+#[cfg(feature = "schemars")]
+use schemars::{
+    schema::{InstanceType, Schema, SchemaObject, StringValidation},
+    JsonSchema,
+};
+#[cfg(feature = "serde")]
 use serde::{de::Error, Deserialize, Serialize};
 use thiserror::Error;
 
 use crate::{
-    segment::{DomainSegment, DomainSegmentError},
+    segment::{
+        debug_validate_segments, parse_labels, strip_root, validate_name_length, DomainSegment,
+        DomainSegmentError, LabelError, NameLengthError, MAX_LABELS, MAX_NAME_LENGTH,
+    },
     PartiallyQualifiedDomainName,
 };
 
+#[cfg(feature = "schemars")]
+use crate::segment::LABEL_REGEX;
+
 /// Produced when attempting to construct a [`FullyQualifiedDomainName`]
 /// from an invalid string.
 #[derive(Error, Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
@@ -20,13 +32,46 @@ pub enum FullyQualifiedDomainNameError {
     /// a trailing dot.
     #[error("domain is partially qualified")]
     DomainIsPartiallyQualified,
-    /// One or more of the segments of the domain specified in the string
-    /// are invalid.
-    #[error("{0}")]
-    SegmentError(#[from] DomainSegmentError),
+    /// The label starting at byte `offset` within the input is invalid.
+    #[error("invalid label {label:?} at offset {offset}: {error}")]
+    SegmentError {
+        offset: usize,
+        label: String,
+        error: DomainSegmentError,
+    },
     /// Wildcard segments must only appear at the beginning of a record.
     #[error("non-leading wildcard segment")]
     NonLeadingWildcard,
+    /// The name contains a wildcard, but was parsed with [`WildcardPolicy::Reject`](crate::WildcardPolicy::Reject).
+    #[error("wildcard names are not permitted")]
+    WildcardNotPermitted,
+    /// The domain name is longer than the permitted 255 octets in wire format.
+    #[error("domain too long {0} > 255")]
+    TooLong(usize),
+    /// The domain name consists of more than the permitted 127 labels.
+    #[error("domain has too many labels {0} > 127")]
+    TooManyLabels(usize),
+}
+
+impl From<LabelError> for FullyQualifiedDomainNameError {
+    fn from(value: LabelError) -> Self {
+        FullyQualifiedDomainNameError::SegmentError {
+            offset: value.offset,
+            label: value.label,
+            error: value.error,
+        }
+    }
+}
+
+impl From<NameLengthError> for FullyQualifiedDomainNameError {
+    fn from(value: NameLengthError) -> Self {
+        match value {
+            NameLengthError::TooLong(length) => FullyQualifiedDomainNameError::TooLong(length),
+            NameLengthError::TooManyLabels(count) => {
+                FullyQualifiedDomainNameError::TooManyLabels(count)
+            }
+        }
+    }
 }
 
 /// Fully qualified domain name (FQDN).
@@ -37,25 +82,187 @@ pub enum FullyQualifiedDomainNameError {
 /// domain name, and therefore denotes the exact location of the
 /// domain within the domain name system.
 ///
+/// Since [`DomainSegment`]s are stored inline, a name only requires a
+/// single allocation, regardless of its number of labels.
+///
 /// See also [`PartiallyQualifiedDomainName`](crate::PartiallyQualifiedDomainName).
-#[derive(Default, Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Default, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub struct FullyQualifiedDomainName(pub(crate) Vec<DomainSegment>);
 
 impl FullyQualifiedDomainName {
+    /// Maximum length of a domain name in octets, in wire format.
+    pub const MAX_LENGTH: usize = MAX_NAME_LENGTH;
+
+    /// Maximum number of labels in a domain name.
+    pub const MAX_LABELS: usize = MAX_LABELS;
+
+    /// Constructs a name from its segments, validating that wildcards
+    /// only appear as the leading segment, and that the name does not
+    /// exceed [`Self::MAX_LENGTH`] or [`Self::MAX_LABELS`].
+    ///
+    /// Unlike collecting segments into a name, which performs no
+    /// validation, this is safe to use with segments of unknown origin.
+    pub fn try_from_segments(
+        segments: impl IntoIterator<Item = DomainSegment>,
+    ) -> Result<Self, FullyQualifiedDomainNameError> {
+        let segments: Vec<DomainSegment> = segments.into_iter().collect();
+
+        if segments.iter().skip(1).any(DomainSegment::is_wildcard) {
+            return Err(FullyQualifiedDomainNameError::NonLeadingWildcard);
+        }
+
+        validate_name_length(segments.iter().map(DomainSegment::len))?;
+
+        Ok(FullyQualifiedDomainName(segments))
+    }
+
     /// Iterates over all [`DomainSegment`]s that make up the domain name.
     pub fn iter(&self) -> core::slice::Iter<'_, DomainSegment> {
         self.0.iter()
     }
 
+    /// Splits `www.example.org.` into `www` and the labels of its parent
+    /// `example.org.`, without cloning, or returns [`None`] for the root,
+    /// which has no labels.
+    pub fn split_first(&self) -> Option<(&DomainSegment, &[DomainSegment])> {
+        self.0.split_first()
+    }
+
+    /// Splits `www.example.org.` into its top-level label `org` and the
+    /// remaining labels `www.example`, without cloning. The remaining
+    /// labels are no longer anchored at the root, and only make up a
+    /// partially qualified name.
+    pub fn split_last(&self) -> Option<(&DomainSegment, &[DomainSegment])> {
+        self.0.split_last()
+    }
+
     /// Returns true if `parent` matches the tail end of `self`.
     pub fn is_subdomain_of(&self, parent: &FullyQualifiedDomainName) -> bool {
-        self.0.ends_with(parent.as_ref()) && self != parent
+        self.0.ends_with(&parent.0) && self != parent
+    }
+
+    /// Name with the leftmost label removed, or [`None`] for the root.
+    pub fn parent(&self) -> Option<FullyQualifiedDomainName> {
+        let (_, parent) = self.0.split_first()?;
+
+        Some(FullyQualifiedDomainName(parent.to_vec()))
+    }
+
+    /// Iterates over the name itself followed by each of its parents,
+    /// ending with the root, like [`Path::ancestors`](std::path::Path::ancestors).
+    ///
+    /// ```text
+    /// www.example.org. => www.example.org., example.org., org., .
+    /// ```
+    pub fn ancestors(&self) -> impl Iterator<Item = FullyQualifiedDomainName> + '_ {
+        (0..=self.0.len()).map(|start| FullyQualifiedDomainName(self.0[start..].to_vec()))
+    }
+
+    /// Splits the name after its first `n` labels, or returns [`None`]
+    /// if it has fewer than `n` labels.
+    ///
+    /// ```text
+    /// www.example.org. at 1 => www, example.org.
+    /// ```
+    pub fn split_at(
+        &self,
+        n: usize,
+    ) -> Option<(PartiallyQualifiedDomainName, FullyQualifiedDomainName)> {
+        let (prefix, suffix) = self.0.split_at_checked(n)?;
+
+        Some((
+            PartiallyQualifiedDomainName(prefix.to_vec()),
+            FullyQualifiedDomainName(suffix.to_vec()),
+        ))
+    }
+
+    /// Labels preceding `suffix`, or [`None`] if the name does not end with it.
+    ///
+    /// Equivalent to subtracting `suffix` from the name.
+    ///
+    /// ```text
+    /// www.example.org. without example.org. => www
+    /// ```
+    pub fn strip_suffix(
+        &self,
+        suffix: &FullyQualifiedDomainName,
+    ) -> Option<PartiallyQualifiedDomainName> {
+        self.0
+            .strip_suffix(suffix.0.as_slice())
+            .map(|prefix| PartiallyQualifiedDomainName(prefix.to_vec()))
+    }
+
+    /// Labels following `prefix`, or [`None`] if the name does not start with it.
+    ///
+    /// ```text
+    /// www.example.org. without www => example.org.
+    /// ```
+    pub fn strip_prefix(
+        &self,
+        prefix: &PartiallyQualifiedDomainName,
+    ) -> Option<FullyQualifiedDomainName> {
+        self.0
+            .strip_prefix(prefix.0.as_slice())
+            .map(|suffix| FullyQualifiedDomainName(suffix.to_vec()))
     }
 
-    /// Length of the fully qualified domain name as a string, *including* the trailing dot.
+    /// Length of the fully qualified domain name in octets, *including* the
+    /// trailing dot, with escapes decoded. This is the length of its
+    /// presentation format only if no label requires escaping, and one
+    /// less than its length in wire format.
     #[allow(clippy::len_without_is_empty)]
     pub fn len(&self) -> usize {
-        self.0.iter().map(|segment| segment.len()).sum::<usize>() + self.0.len()
+        self.0.iter().map(DomainSegment::len).sum::<usize>() + self.0.len()
+    }
+
+    /// Iterates over the sequence of names queried when resolving this
+    /// name with QNAME minimization (RFC 9156), starting with the top-level
+    /// domain and adding one label at a time until the full name is reached.
+    pub fn minimized_queries(&self) -> impl Iterator<Item = FullyQualifiedDomainName> + '_ {
+        (0..self.0.len())
+            .rev()
+            .map(|start| FullyQualifiedDomainName(self.0[start..].to_vec()))
+    }
+
+    /// Iterates over the origins of the zones which could be responsible
+    /// for this name, from the name itself up to and including the root,
+    /// in the order they are probed when locating the closest enclosing zone.
+    ///
+    /// A leading wildcard is skipped, since it can never be a zone origin.
+    ///
+    /// ```text
+    /// www.example.org. => www.example.org., example.org., org., .
+    /// ```
+    pub fn iterate_zone_candidates(&self) -> impl Iterator<Item = FullyQualifiedDomainName> + '_ {
+        let first = usize::from(self.0.first().is_some_and(DomainSegment::is_wildcard));
+
+        self.ancestors().skip(first)
+    }
+
+    /// Asserts the invariants upheld by parsing, for use by fuzzers and
+    /// property tests after constructing names through other means.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any segment is invalid, or a wildcard segment
+    /// appears anywhere but at the beginning of the name.
+    pub fn debug_validate(&self) {
+        debug_validate_segments(&self.0);
+    }
+
+    /// Renders the name relative to `origin` if it is a subdomain of it,
+    /// as `@` if it is the origin itself, and fully qualified otherwise.
+    ///
+    /// ```text
+    /// www.example.org. relative to example.org. => www
+    /// example.org.     relative to example.org. => @
+    /// www.example.com. relative to example.org. => www.example.com.
+    /// ```
+    pub fn relative_display<'a>(
+        &'a self,
+        origin: &'a FullyQualifiedDomainName,
+    ) -> RelativeDisplay<'a> {
+        RelativeDisplay { fqdn: self, origin }
     }
 
     /// Coerce the domain name into a partially qualified one.
@@ -67,8 +274,21 @@ impl FullyQualifiedDomainName {
     pub fn to_partially_qualified(&self) -> PartiallyQualifiedDomainName {
         PartiallyQualifiedDomainName(self.0.clone())
     }
+
+    /// Prepends the labels of `prefix`, such as `www` or `*.dev`, which
+    /// are parsed and validated along with the resulting name.
+    ///
+    /// Offsets within errors refer to the resulting name.
+    pub fn try_prepend(
+        &self,
+        prefix: &str,
+    ) -> Result<FullyQualifiedDomainName, FullyQualifiedDomainNameError> {
+        FullyQualifiedDomainName::try_from(format!("{prefix}.{self}"))
+    }
 }
 
+/// Collects segments into a name without validating it, see
+/// [`FullyQualifiedDomainName::try_from_segments`].
 impl FromIterator<DomainSegment> for FullyQualifiedDomainName {
     fn from_iter<T: IntoIterator<Item = DomainSegment>>(iter: T) -> Self {
         FullyQualifiedDomainName(iter.into_iter().collect())
@@ -93,36 +313,90 @@ impl TryFrom<&str> for FullyQualifiedDomainName {
     type Error = FullyQualifiedDomainNameError;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        if !value.ends_with('.') {
-            Err(FullyQualifiedDomainNameError::DomainIsPartiallyQualified)
-        } else {
-            let segments: Vec<DomainSegment> = Result::from_iter(
-                value
-                    .trim_end_matches('.')
-                    .split('.')
-                    .map(DomainSegment::try_from),
-            )?;
-
-            if segments.iter().skip(1).any(DomainSegment::is_wildcard) {
-                return Err(FullyQualifiedDomainNameError::NonLeadingWildcard);
-            }
+        let Some(mut labels) = strip_root(value) else {
+            return Err(FullyQualifiedDomainNameError::DomainIsPartiallyQualified);
+        };
+
+        while let Some(stripped) = strip_root(labels) {
+            labels = stripped;
+        }
 
-            Ok(FullyQualifiedDomainName(segments))
+        // The root consists of no labels at all, written as `.`
+        if labels.is_empty() {
+            return Ok(FullyQualifiedDomainName::default());
         }
+
+        let segments = parse_labels(labels)?;
+
+        if segments.iter().skip(1).any(DomainSegment::is_wildcard) {
+            return Err(FullyQualifiedDomainNameError::NonLeadingWildcard);
+        }
+
+        validate_name_length(segments.iter().map(DomainSegment::len))?;
+
+        Ok(FullyQualifiedDomainName(segments))
+    }
+}
+
+impl FromStr for FullyQualifiedDomainName {
+    type Err = FullyQualifiedDomainNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
     }
 }
 
 impl Display for FullyQualifiedDomainName {
+    /// Renders the name with a trailing dot, or as `.` for the root.
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.0.is_empty() {
+            return f.write_char('.');
+        }
+
         for segment in &self.0 {
-            write!(f, "{}", segment)?;
-            f.write_char('.')?;
+            write!(f, "{segment}.")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Renders a name relative to an origin, produced by
+/// [`FullyQualifiedDomainName::relative_display`].
+pub struct RelativeDisplay<'a> {
+    fqdn: &'a FullyQualifiedDomainName,
+    origin: &'a FullyQualifiedDomainName,
+}
+
+impl Display for RelativeDisplay<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.fqdn == self.origin {
+            return f.write_char('@');
+        }
+
+        if !self.fqdn.is_subdomain_of(self.origin) {
+            return Display::fmt(self.fqdn, f);
+        }
+
+        let relative = &self.fqdn.0[..self.fqdn.0.len() - self.origin.0.len()];
+        for (index, segment) in relative.iter().enumerate() {
+            if index > 0 {
+                f.write_char('.')?;
+            }
+
+            write!(f, "{segment}")?;
         }
 
         Ok(())
     }
 }
 
+impl Debug for FullyQualifiedDomainName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Fqdn").field(&self.to_string()).finish()
+    }
+}
+
 impl AsRef<[DomainSegment]> for FullyQualifiedDomainName {
     fn as_ref(&self) -> &[DomainSegment] {
         self.0.as_ref()
@@ -141,16 +415,29 @@ impl PartialEq<str> for FullyQualifiedDomainName {
     }
 }
 
+#[cfg(feature = "schemars")]
 impl JsonSchema for FullyQualifiedDomainName {
     fn schema_name() -> String {
-        <String as schemars::JsonSchema>::schema_name()
+        String::from("FullyQualifiedDomainName")
     }
 
-    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
-        <String as schemars::JsonSchema>::json_schema(gen)
+    fn json_schema(_: &mut schemars::gen::SchemaGenerator) -> Schema {
+        // The length limit is that of names without escapes, which are
+        // otherwise longer as written than in wire format.
+        SchemaObject {
+            instance_type: Some(InstanceType::String.into()),
+            string: Some(Box::new(StringValidation {
+                max_length: Some((Self::MAX_LENGTH - 1) as u32),
+                pattern: Some(format!(r"^((\*|{LABEL_REGEX})\.({LABEL_REGEX}\.)*|\.)$")),
+                ..Default::default()
+            })),
+            ..Default::default()
+        }
+        .into()
     }
 }
 
+#[cfg(feature = "serde")]
 impl<'de> Deserialize<'de> for FullyQualifiedDomainName {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -162,6 +449,7 @@ impl<'de> Deserialize<'de> for FullyQualifiedDomainName {
     }
 }
 
+#[cfg(feature = "serde")]
 impl Serialize for FullyQualifiedDomainName {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -175,19 +463,7 @@ impl<'a> Sub for &'a FullyQualifiedDomainName {
     type Output = Result<PartiallyQualifiedDomainName, &'a FullyQualifiedDomainName>;
 
     fn sub(self, rhs: Self) -> Self::Output {
-        let mut own_segments = self.0.clone().into_iter().rev();
-        let parent_segments = rhs.0.iter().rev();
-
-        for parent_domain in parent_segments {
-            if !own_segments
-                .next()
-                .is_some_and(|segment| &segment == parent_domain)
-            {
-                return Err(self);
-            }
-        }
-
-        Ok(PartiallyQualifiedDomainName::from_iter(own_segments.rev()))
+        self.strip_suffix(rhs).ok_or(self)
     }
 }
 
@@ -202,11 +478,32 @@ impl Sub for FullyQualifiedDomainName {
     }
 }
 
+#[cfg(feature = "valuable")]
+impl valuable::Valuable for FullyQualifiedDomainName {
+    fn as_value(&self) -> valuable::Value<'_> {
+        valuable::Value::Listable(self)
+    }
+
+    fn visit(&self, visit: &mut dyn valuable::Visit) {
+        DomainSegment::visit_slice(&self.0, visit)
+    }
+}
+
+#[cfg(feature = "valuable")]
+impl valuable::Listable for FullyQualifiedDomainName {
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.0.len(), Some(self.0.len()))
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::{
-        fqdn::FullyQualifiedDomainNameError, segment::DomainSegment, FullyQualifiedDomainName,
-        PartiallyQualifiedDomainName,
+        fqdn,
+        fqdn::FullyQualifiedDomainNameError,
+        pqdn,
+        segment::{DomainSegment, DomainSegmentError},
+        FullyQualifiedDomainName, PartiallyQualifiedDomainName,
     };
 
     #[test]
@@ -220,6 +517,50 @@ mod test {
         );
     }
 
+    #[test]
+    fn length_limits() {
+        // 4 labels of 63 characters, plus length prefixes and the root.
+        let label = "a".repeat(63);
+        let longest = format!("{label}.{label}.{label}.{}.", "a".repeat(61));
+        assert!(FullyQualifiedDomainName::try_from(longest.as_str()).is_ok());
+
+        assert_eq!(
+            FullyQualifiedDomainName::try_from(format!("a.{longest}")),
+            Err(FullyQualifiedDomainNameError::TooLong(257))
+        );
+        assert_eq!(
+            FullyQualifiedDomainName::try_from("a.".repeat(128)),
+            Err(FullyQualifiedDomainNameError::TooManyLabels(128))
+        );
+    }
+
+    #[test]
+    fn from_segments() {
+        let label = DomainSegment::try_from("a".repeat(63).as_str()).unwrap();
+        let labels = vec![label.clone(); 1100];
+
+        assert_eq!(
+            FullyQualifiedDomainName::try_from_segments(labels.clone()),
+            Err(FullyQualifiedDomainNameError::TooManyLabels(1100))
+        );
+        assert_eq!(
+            FullyQualifiedDomainName::try_from_segments(vec![label; 4]),
+            Err(FullyQualifiedDomainNameError::TooLong(257))
+        );
+        assert_eq!(
+            FullyQualifiedDomainName::try_from_segments([
+                DomainSegment::try_from("www").unwrap(),
+                DomainSegment::try_from("*").unwrap(),
+            ]),
+            Err(FullyQualifiedDomainNameError::NonLeadingWildcard)
+        );
+
+        // Collecting does not validate, but must not fail either.
+        let unchecked = FullyQualifiedDomainName::from_iter(labels);
+        assert_eq!(unchecked.iter().len(), 1100);
+        assert_eq!(unchecked.len(), 1100 * 64);
+    }
+
     #[test]
     fn fqdn_from_pqdn_fails() {
         assert_eq!(
@@ -228,6 +569,143 @@ mod test {
         );
     }
 
+    #[test]
+    fn split() {
+        let fqdn = fqdn!("www.example.org.");
+
+        let (first, parent) = fqdn.split_first().unwrap();
+        assert_eq!(first.as_presentation(), "www");
+        assert_eq!(parent, fqdn!("example.org.").as_ref());
+
+        let (last, rest) = fqdn.split_last().unwrap();
+        assert_eq!(last.as_presentation(), "org");
+        assert_eq!(rest, pqdn!("www.example").as_ref());
+
+        // Both halves point into the name itself, rather than copies.
+        let segments: &[DomainSegment] = fqdn.as_ref();
+        assert!(std::ptr::eq(first, &segments[0]));
+        assert!(std::ptr::eq(parent, &segments[1..]));
+        assert!(std::ptr::eq(last, &segments[2]));
+        assert!(std::ptr::eq(rest, &segments[..2]));
+
+        assert_eq!(FullyQualifiedDomainName::default().split_first(), None);
+    }
+
+    #[test]
+    fn error_location() {
+        let error = FullyQualifiedDomainName::try_from("www.my_host!.example.org.").unwrap_err();
+
+        assert_eq!(
+            error,
+            FullyQualifiedDomainNameError::SegmentError {
+                offset: 4,
+                label: String::from("my_host!"),
+                error: DomainSegmentError::InvalidCharacter {
+                    character: '!',
+                    position: 7
+                }
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "invalid label \"my_host!\" at offset 4: invalid character '!' at position 7"
+        );
+    }
+
+    #[test]
+    fn from_str() {
+        assert_eq!(
+            "example.org.".parse::<FullyQualifiedDomainName>(),
+            FullyQualifiedDomainName::try_from("example.org.")
+        );
+
+        assert_eq!(
+            "example.org".parse::<FullyQualifiedDomainName>(),
+            Err(FullyQualifiedDomainNameError::DomainIsPartiallyQualified)
+        );
+    }
+
+    #[test]
+    fn minimized_queries() {
+        let queries: Vec<String> = FullyQualifiedDomainName::try_from("www.example.org.")
+            .unwrap()
+            .minimized_queries()
+            .map(|fqdn| fqdn.to_string())
+            .collect();
+
+        assert_eq!(queries, ["org.", "example.org.", "www.example.org."]);
+
+        assert_eq!(
+            FullyQualifiedDomainName::default()
+                .minimized_queries()
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn ancestors() {
+        let www = fqdn!("www.example.org.");
+
+        assert_eq!(www.parent(), Some(fqdn!("example.org.")));
+        assert_eq!(
+            fqdn!("org.").parent(),
+            Some(FullyQualifiedDomainName::default())
+        );
+        assert_eq!(FullyQualifiedDomainName::default().parent(), None);
+
+        assert_eq!(
+            www.ancestors().collect::<Vec<_>>(),
+            [
+                www.clone(),
+                fqdn!("example.org."),
+                fqdn!("org."),
+                FullyQualifiedDomainName::default()
+            ]
+        );
+        assert_eq!(FullyQualifiedDomainName::default().ancestors().count(), 1);
+    }
+
+    #[test]
+    fn zone_candidates() {
+        let candidates = |fqdn: FullyQualifiedDomainName| -> Vec<FullyQualifiedDomainName> {
+            fqdn.iterate_zone_candidates().collect()
+        };
+
+        assert_eq!(
+            candidates(fqdn!("www.example.org.")),
+            [
+                fqdn!("www.example.org."),
+                fqdn!("example.org."),
+                fqdn!("org."),
+                FullyQualifiedDomainName::default()
+            ]
+        );
+        assert_eq!(
+            candidates(fqdn!("*.example.org.")),
+            [
+                fqdn!("example.org."),
+                fqdn!("org."),
+                FullyQualifiedDomainName::default()
+            ]
+        );
+        assert_eq!(
+            candidates(FullyQualifiedDomainName::default()),
+            [FullyQualifiedDomainName::default()]
+        );
+    }
+
+    #[test]
+    fn debug_format() {
+        assert_eq!(
+            format!(
+                "{:?}",
+                FullyQualifiedDomainName::try_from("www.example.org.").unwrap()
+            ),
+            "Fqdn(\"www.example.org.\")"
+        );
+    }
+
     #[test]
     fn subtraction() {
         assert_eq!(
@@ -248,4 +726,59 @@ mod test {
             Err(FullyQualifiedDomainName::try_from("www.example.org.").unwrap())
         );
     }
+
+    #[test]
+    fn splitting() {
+        let www = fqdn!("www.example.org.");
+
+        assert_eq!(www.split_at(1), Some((pqdn!("www"), fqdn!("example.org."))));
+        assert_eq!(
+            www.split_at(3),
+            Some((
+                pqdn!("www.example.org"),
+                FullyQualifiedDomainName::default()
+            ))
+        );
+        assert_eq!(www.split_at(4), None);
+
+        assert_eq!(www.strip_suffix(&fqdn!("example.org.")), Some(pqdn!("www")));
+        assert_eq!(
+            www.strip_suffix(&www),
+            Some(PartiallyQualifiedDomainName::default())
+        );
+        assert_eq!(www.strip_suffix(&fqdn!("ample.org.")), None);
+
+        assert_eq!(www.strip_prefix(&pqdn!("www.example")), Some(fqdn!("org.")));
+        assert_eq!(www.strip_prefix(&pqdn!("example")), None);
+    }
+
+    #[test]
+    fn relative_display() {
+        let origin = FullyQualifiedDomainName::try_from("example.org.").unwrap();
+
+        for (name, expected) in [
+            ("www.dev.example.org.", "www.dev"),
+            ("example.org.", "@"),
+            ("www.example.com.", "www.example.com."),
+            ("org.", "org."),
+        ] {
+            let fqdn = FullyQualifiedDomainName::try_from(name).unwrap();
+            assert_eq!(fqdn.relative_display(&origin).to_string(), expected);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "schemars")]
+    fn json_schema() {
+        let schema = schemars::schema_for!(FullyQualifiedDomainName).schema;
+        let string = schema.string.unwrap();
+
+        assert_eq!(string.max_length, Some(254));
+        assert_eq!(
+            string.pattern.as_deref(),
+            Some(
+                r"^((\*|([a-zA-Z0-9_-]|\\[0-9]{3}|\\[^0-9]){1,63})\.(([a-zA-Z0-9_-]|\\[0-9]{3}|\\[^0-9]){1,63}\.)*|\.)$"
+            )
+        );
+    }
 }
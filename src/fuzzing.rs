@@ -0,0 +1,185 @@
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::{
+    segment::MAX_NAME_LENGTH, Class, DomainSegment, FullyQualifiedDomainName,
+    PartiallyQualifiedDomainName, Pattern, Type,
+};
+
+/// Maximum length of a label in octets.
+const MAX_LABEL_LENGTH: usize = 63;
+
+/// Characters from which labels are generated.
+const CHARACTERS: &[u8] = b"abcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// Single `*` label.
+fn wildcard() -> DomainSegment {
+    DomainSegment::try_from("*").expect("wildcard is valid")
+}
+
+/// Generates a valid label of at most `max` characters, optionally
+/// containing a single `*` among its other characters.
+fn label(u: &mut Unstructured<'_>, max: usize, wildcard: bool) -> Result<String> {
+    let length = u.int_in_range(1..=max)?;
+    let mut bytes = (0..length)
+        .map(|_| u.choose(CHARACTERS).copied())
+        .collect::<Result<Vec<u8>>>()?;
+
+    if wildcard && length < MAX_LABEL_LENGTH && u.ratio(1, 4)? {
+        bytes.insert(u.int_in_range(0..=length)?, b'*');
+    }
+
+    // Hyphens are not permitted at either end, nor at both the 3rd and
+    // 4th position, which is reserved for Punycode.
+    let last = bytes.len() - 1;
+    for index in [0, last] {
+        if bytes[index] == b'-' {
+            bytes[index] = b'a';
+        }
+    }
+
+    if bytes.get(2..4) == Some(b"--") {
+        bytes[3] = b'a';
+    }
+
+    Ok(String::from_utf8(bytes).expect("label is ASCII"))
+}
+
+/// Generates the labels of a name, which fit within `budget` octets
+/// in wire format, including the length octet of every label.
+fn labels(u: &mut Unstructured<'_>, mut budget: usize) -> Result<Vec<DomainSegment>> {
+    let mut segments = Vec::new();
+
+    if u.ratio(1, 8)? {
+        segments.push(wildcard());
+        budget -= 2;
+    }
+
+    while budget >= 2 && u.arbitrary()? {
+        let label = label(u, (budget - 1).min(MAX_LABEL_LENGTH), false)?;
+        budget -= label.len() + 1;
+
+        segments.push(DomainSegment::try_from(label).expect("label is valid"));
+    }
+
+    Ok(segments)
+}
+
+impl<'a> Arbitrary<'a> for DomainSegment {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        if u.ratio(1, 16)? {
+            return Ok(wildcard());
+        }
+
+        Ok(DomainSegment::try_from(label(u, MAX_LABEL_LENGTH, false)?).expect("label is valid"))
+    }
+}
+
+impl<'a> Arbitrary<'a> for FullyQualifiedDomainName {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        // The root label takes up the last octet.
+        Ok(FullyQualifiedDomainName(labels(u, MAX_NAME_LENGTH - 1)?))
+    }
+}
+
+impl<'a> Arbitrary<'a> for PartiallyQualifiedDomainName {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let mut segments = labels(u, MAX_NAME_LENGTH - 1)?;
+
+        // Empty names cannot be parsed, so at least one label is generated.
+        if segments.is_empty() {
+            let label = label(u, MAX_LABEL_LENGTH, false)?;
+            segments.push(DomainSegment::try_from(label).expect("label is valid"));
+        }
+
+        Ok(PartiallyQualifiedDomainName(segments))
+    }
+}
+
+impl<'a> Arbitrary<'a> for Pattern {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let mut segments = Vec::new();
+        let mut budget = MAX_NAME_LENGTH - 1;
+
+        while segments.is_empty() || (budget >= 2 && u.arbitrary()?) {
+            let segment = label(u, (budget - 1).min(MAX_LABEL_LENGTH), true)?;
+
+            // Wildcards may match nothing, but every segment matches at
+            // least one character.
+            let literal = segment.bytes().filter(|byte| *byte != b'*').count();
+            budget -= literal.max(1) + 1;
+
+            segments.push(segment);
+        }
+
+        Ok(Pattern::try_from(segments.join(".")).expect("pattern is valid"))
+    }
+}
+
+impl<'a> Arbitrary<'a> for Type {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Type::from_code(u.arbitrary()?))
+    }
+}
+
+impl<'a> Arbitrary<'a> for Class {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Class::from_code(u.arbitrary()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arbitrary::{Arbitrary, Unstructured};
+
+    use crate::{
+        Class, DomainSegment, FullyQualifiedDomainName, PartiallyQualifiedDomainName, Pattern, Type,
+    };
+
+    #[test]
+    fn generates_valid_values() {
+        let mut state = 0x2545_f491_4f6c_dd1d_u64;
+
+        for length in 0..512 {
+            let data: Vec<u8> = (0..length * 4)
+                .map(|_| {
+                    state ^= state << 13;
+                    state ^= state >> 7;
+                    state ^= state << 17;
+                    state as u8
+                })
+                .collect();
+
+            let mut u = Unstructured::new(&data);
+
+            DomainSegment::arbitrary(&mut u).unwrap().debug_validate();
+
+            let fqdn = FullyQualifiedDomainName::arbitrary(&mut u).unwrap();
+            fqdn.debug_validate();
+
+            // The root is rendered as an empty string, which does not parse.
+            if fqdn != FullyQualifiedDomainName::default() {
+                assert_eq!(
+                    FullyQualifiedDomainName::try_from(fqdn.to_string()),
+                    Ok(fqdn)
+                );
+            }
+
+            let pqdn = PartiallyQualifiedDomainName::arbitrary(&mut u).unwrap();
+            pqdn.debug_validate();
+            assert_eq!(
+                PartiallyQualifiedDomainName::try_from(pqdn.to_string()),
+                Ok(pqdn)
+            );
+
+            let pattern = Pattern::arbitrary(&mut u).unwrap();
+            pattern.debug_validate();
+            assert_eq!(Pattern::try_from(pattern.to_string()), Ok(pattern));
+
+            let r#type = Type::arbitrary(&mut u).unwrap();
+            assert_eq!(Type::from_code(r#type.code()), r#type);
+
+            let class = Class::arbitrary(&mut u).unwrap();
+            assert_eq!(Class::from_code(class.code()), class);
+        }
+    }
+}
@@ -0,0 +1,146 @@
+use std::mem::size_of;
+
+use crate::{
+    DomainName, DomainSegment, FullyQualifiedDomainName, PartiallyQualifiedDomainName, Record,
+    RecordData, RecordIdent, Zone,
+};
+
+/// Approximate number of bytes allocated on the heap by a value, excluding
+/// the size of the value itself, such that operators can expose memory
+/// usage metrics for the zones they hold:
+///
+/// ```text
+/// let bytes = size_of::<Zone>() + zone.approx_heap_size();
+/// ```
+///
+/// The overhead of the allocator and of collection internals, such as the
+/// nodes of a [`BTreeMap`](std::collections::BTreeMap), is not included.
+pub trait HeapSize {
+    fn approx_heap_size(&self) -> usize;
+}
+
+impl HeapSize for DomainSegment {
+    /// Segments are stored inline, so only segments which require escapes
+    /// allocate, for their presentation format.
+    fn approx_heap_size(&self) -> usize {
+        self.escaped_len()
+    }
+}
+
+impl HeapSize for FullyQualifiedDomainName {
+    fn approx_heap_size(&self) -> usize {
+        vec_heap_size(&self.0)
+    }
+}
+
+impl HeapSize for PartiallyQualifiedDomainName {
+    fn approx_heap_size(&self) -> usize {
+        vec_heap_size(&self.0)
+    }
+}
+
+impl HeapSize for DomainName {
+    fn approx_heap_size(&self) -> usize {
+        match self {
+            DomainName::Full(full) => full.approx_heap_size(),
+            DomainName::Partial(partial) => partial.approx_heap_size(),
+        }
+    }
+}
+
+impl HeapSize for RecordData {
+    fn approx_heap_size(&self) -> usize {
+        match self {
+            RecordData::A(_) | RecordData::AAAA(_) => 0,
+            RecordData::CAA { tag, value, .. } => tag.capacity() + value.capacity(),
+            RecordData::AFSDB { hostname: name, .. }
+            | RecordData::CNAME(name)
+            | RecordData::DNAME(name)
+            | RecordData::KX {
+                exchanger: name, ..
+            }
+            | RecordData::MX { exchange: name, .. }
+            | RecordData::NS(name)
+            | RecordData::PTR(name)
+            | RecordData::SRV { target: name, .. } => name.approx_heap_size(),
+            RecordData::DLV { digest, .. } | RecordData::TA { digest, .. } => digest.capacity(),
+            RecordData::HIP {
+                hit,
+                public_key,
+                rendezvous_servers,
+                ..
+            } => hit.capacity() + public_key.capacity() + vec_heap_size(rendezvous_servers),
+            RecordData::SOA { mname, rname, .. } => {
+                mname.approx_heap_size() + rname.approx_heap_size()
+            }
+            RecordData::TXT(strings) => {
+                strings.capacity() * size_of::<Vec<u8>>()
+                    + strings.iter().map(Vec::capacity).sum::<usize>()
+            }
+            RecordData::Other(rdata) => rdata.capacity(),
+        }
+    }
+}
+
+impl HeapSize for RecordIdent {
+    fn approx_heap_size(&self) -> usize {
+        self.fqdn.approx_heap_size() + self.rdata.approx_heap_size()
+    }
+}
+
+impl HeapSize for Record {
+    fn approx_heap_size(&self) -> usize {
+        self.fqdn.approx_heap_size() + self.rdata.approx_heap_size()
+    }
+}
+
+impl HeapSize for Zone {
+    /// Includes the records, as well as the identities they are stored under.
+    fn approx_heap_size(&self) -> usize {
+        let entries = self.len() * (size_of::<RecordIdent>() + size_of::<Record>());
+
+        let owned: usize = self
+            .idents()
+            .map(HeapSize::approx_heap_size)
+            .chain(self.iter().map(HeapSize::approx_heap_size))
+            .sum();
+
+        self.origin().approx_heap_size() + entries + owned
+    }
+}
+
+fn vec_heap_size<T: HeapSize>(vec: &Vec<T>) -> usize {
+    vec.capacity() * size_of::<T>() + vec.iter().map(HeapSize::approx_heap_size).sum::<usize>()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::mem::size_of;
+
+    use crate::{fqdn, DomainSegment, HeapSize, Record, RecordIdent, Zone};
+
+    #[test]
+    fn heap_sizes() {
+        let fqdn = fqdn!("www.example.org.");
+        assert!(fqdn.approx_heap_size() >= 3 * size_of::<DomainSegment>());
+
+        let escaped = DomainSegment::try_from("a\\032b").unwrap();
+        assert_eq!(escaped.approx_heap_size(), "a\\032b".len());
+
+        let record: Record = "www.example.org. 300 IN TXT \"hello\"".parse().unwrap();
+        assert!(record.approx_heap_size() > fqdn.approx_heap_size());
+
+        let mut zone = Zone::new(fqdn!("example.org."));
+        let empty = zone.approx_heap_size();
+        zone.insert(record).unwrap();
+
+        let stored = zone.iter().next().unwrap();
+        assert_eq!(
+            zone.approx_heap_size() - empty,
+            size_of::<RecordIdent>()
+                + size_of::<Record>()
+                + stored.ident().approx_heap_size()
+                + stored.approx_heap_size()
+        );
+    }
+}
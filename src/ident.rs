@@ -1,6 +1,13 @@
-use std::hash::Hash;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    hash::Hash,
+};
 
-use crate::{FullyQualifiedDomainName, Type};
+use thiserror::Error;
+
+use crate::{
+    diff::Diff, rdata::RecordDataError, Class, FullyQualifiedDomainName, Record, RecordData, Type,
+};
 
 /// A uniquely identified Record identity.
 ///
@@ -9,9 +16,229 @@ use crate::{FullyQualifiedDomainName, Type};
 /// across these parameters.
 ///
 /// Can be used to store records in HashMaps/HashSets
+#[cfg_attr(feature = "valuable", derive(valuable::Valuable))]
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct RecordIdent {
     pub fqdn: FullyQualifiedDomainName,
     pub r#type: Type,
-    pub rdata: String,
+    pub rdata: RecordData,
+}
+
+impl RecordIdent {
+    /// Constructs a [`RecordIdentBuilder`], which parses the record data
+    /// according to the record type before constructing the ident.
+    pub fn builder() -> RecordIdentBuilder {
+        RecordIdentBuilder::default()
+    }
+
+    /// Constructs a [`Record`] with the given class and TTL.
+    pub fn into_record(self, class: Class, ttl: u32) -> Record {
+        Record {
+            fqdn: self.fqdn,
+            class,
+            r#type: self.r#type,
+            ttl,
+            rdata: self.rdata,
+        }
+    }
+}
+
+/// Grouping and set operations over collections of [`RecordIdent`]s,
+/// such as the desired and observed records of a controller.
+///
+/// Since the results are themselves collections of idents, operations can
+/// be chained, for example grouping the missing records by owner:
+///
+/// ```text
+/// desired.set_difference(observed).group_by_owner()
+/// ```
+pub trait RecordIdents: IntoIterator<Item = RecordIdent> + Sized {
+    /// Groups the idents by their fully qualified domain name.
+    fn group_by_owner(self) -> BTreeMap<FullyQualifiedDomainName, BTreeSet<RecordIdent>> {
+        let mut groups: BTreeMap<_, BTreeSet<_>> = BTreeMap::new();
+
+        for ident in self {
+            groups.entry(ident.fqdn.clone()).or_default().insert(ident);
+        }
+
+        groups
+    }
+
+    /// Groups the idents by their record type.
+    fn group_by_type(self) -> BTreeMap<Type, BTreeSet<RecordIdent>> {
+        let mut groups: BTreeMap<_, BTreeSet<_>> = BTreeMap::new();
+
+        for ident in self {
+            groups.entry(ident.r#type).or_default().insert(ident);
+        }
+
+        groups
+    }
+
+    /// Returns the idents which are not part of `other`.
+    fn set_difference(self, other: impl IntoIterator<Item = RecordIdent>) -> BTreeSet<RecordIdent> {
+        let mut idents: BTreeSet<_> = self.into_iter().collect();
+
+        for ident in other {
+            idents.remove(&ident);
+        }
+
+        idents
+    }
+
+    /// Computes the changes required to go from these idents to `desired`.
+    ///
+    /// See [`Diff::between`].
+    fn diff(self, desired: impl IntoIterator<Item = RecordIdent>) -> Diff {
+        let current: Vec<_> = self.into_iter().collect();
+        let desired: Vec<_> = desired.into_iter().collect();
+
+        Diff::between(&current, &desired)
+    }
+}
+
+impl<T: IntoIterator<Item = RecordIdent>> RecordIdents for T {}
+
+/// Produced when a [`RecordIdentBuilder`] fails to construct a [`RecordIdent`].
+#[derive(Error, Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum RecordIdentError {
+    /// No fully qualified domain name was provided.
+    #[error("missing fqdn")]
+    MissingFqdn,
+    /// No record type was provided.
+    #[error("missing record type")]
+    MissingType,
+    /// The record data is not valid for the record type.
+    #[error("invalid record data: {0}")]
+    InvalidRecordData(#[from] RecordDataError),
+}
+
+/// Builder for validated [`RecordIdent`]s.
+#[derive(Default, Debug, Clone)]
+pub struct RecordIdentBuilder {
+    fqdn: Option<FullyQualifiedDomainName>,
+    r#type: Option<Type>,
+    rdata: String,
+}
+
+impl RecordIdentBuilder {
+    /// Sets the fully qualified domain name of the record.
+    pub fn fqdn(mut self, fqdn: FullyQualifiedDomainName) -> Self {
+        self.fqdn = Some(fqdn);
+        self
+    }
+
+    /// Sets the type of the record.
+    pub fn r#type(mut self, r#type: Type) -> Self {
+        self.r#type = Some(r#type);
+        self
+    }
+
+    /// Sets the record data, in presentation format.
+    pub fn rdata(mut self, rdata: impl Into<String>) -> Self {
+        self.rdata = rdata.into();
+        self
+    }
+
+    /// Parses the record data according to the record type, and constructs
+    /// the [`RecordIdent`].
+    pub fn build(self) -> Result<RecordIdent, RecordIdentError> {
+        let fqdn = self.fqdn.ok_or(RecordIdentError::MissingFqdn)?;
+        let r#type = self.r#type.ok_or(RecordIdentError::MissingType)?;
+        let rdata = RecordData::parse(r#type, &self.rdata)?;
+
+        Ok(RecordIdent {
+            fqdn,
+            r#type,
+            rdata,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::{RecordDataError, RecordIdentError},
+        FullyQualifiedDomainName, RecordData, RecordIdent, RecordIdents, Type,
+    };
+
+    fn ident(fqdn: &str, r#type: Type, rdata: &str) -> RecordIdent {
+        RecordIdent {
+            fqdn: FullyQualifiedDomainName::try_from(fqdn).unwrap(),
+            r#type,
+            rdata: RecordData::parse(r#type, rdata).unwrap(),
+        }
+    }
+
+    #[test]
+    fn grouping_and_difference() {
+        let desired = vec![
+            ident("www.example.org.", Type::A, "192.168.0.1"),
+            ident("www.example.org.", Type::AAAA, "2001:db8::1"),
+            ident("api.example.org.", Type::A, "192.168.0.2"),
+        ];
+        let observed = vec![ident("www.example.org.", Type::A, "192.168.0.1")];
+
+        let by_type = desired.clone().group_by_type();
+        assert_eq!(by_type[&Type::A].len(), 2);
+        assert_eq!(by_type[&Type::AAAA].len(), 1);
+
+        let missing = desired
+            .clone()
+            .set_difference(observed.clone())
+            .group_by_owner();
+        assert_eq!(
+            missing
+                .iter()
+                .map(|(fqdn, idents)| (fqdn.to_string(), idents.len()))
+                .collect::<Vec<_>>(),
+            [
+                (String::from("api.example.org."), 1),
+                (String::from("www.example.org."), 1)
+            ]
+        );
+
+        let diff = observed.diff(desired);
+        assert_eq!((diff.added.len(), diff.removed.len()), (2, 0));
+    }
+
+    #[test]
+    fn builder() {
+        let ident = RecordIdent::builder()
+            .fqdn(FullyQualifiedDomainName::try_from("www.example.org.").unwrap())
+            .r#type(Type::AAAA)
+            .rdata("2001:0db8::0001")
+            .build()
+            .unwrap();
+
+        assert_eq!(ident.rdata.to_string(), "2001:db8::1");
+    }
+
+    #[test]
+    fn builder_requires_type() {
+        assert_eq!(
+            RecordIdent::builder()
+                .fqdn(FullyQualifiedDomainName::try_from("www.example.org.").unwrap())
+                .rdata("10.0.0.1")
+                .build(),
+            Err(RecordIdentError::MissingType)
+        );
+    }
+
+    #[test]
+    fn builder_rejects_invalid_rdata() {
+        assert_eq!(
+            RecordIdent::builder()
+                .fqdn(FullyQualifiedDomainName::try_from("www.example.org.").unwrap())
+                .r#type(Type::A)
+                .rdata("www.example.org.")
+                .build(),
+            Err(RecordIdentError::InvalidRecordData(
+                RecordDataError::InvalidField {
+                    field: "ipv4 address",
+                    value: String::from("www.example.org.")
+                }
+            ))
+        );
+    }
 }
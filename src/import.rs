@@ -0,0 +1,211 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::{
+    error::{DomainNameError, RecordDataError, ZoneError},
+    DomainName, FullyQualifiedDomainName, Record, RecordData, Type, Zone,
+};
+
+/// Produced when importing a [`Zone`] from provider record sets fails.
+#[derive(Error, Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ImportError {
+    /// The name of the record set is not a valid domain name.
+    #[error("invalid name {name:?}: {error}")]
+    InvalidName {
+        name: String,
+        error: DomainNameError,
+    },
+    /// One of the values of the record set is not valid for its type.
+    #[error("invalid {} value {value:?} for {name}: {error}", .r#type)]
+    InvalidValue {
+        name: FullyQualifiedDomainName,
+        r#type: Type,
+        value: String,
+        error: RecordDataError,
+    },
+    /// The records could not be inserted into the zone, because they
+    /// are not within it.
+    #[error("{0}")]
+    InvalidRecord(#[from] ZoneError),
+}
+
+/// Record set as listed by the APIs of hosted DNS providers, with all
+/// values of the set in a single entry:
+///
+/// ```yaml
+/// name: www
+/// type: A
+/// ttl: 300
+/// values: [192.0.2.1, 192.0.2.2]
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ProviderRecordSet {
+    /// Name of the record set, see [`Zone::import`] for how it is qualified.
+    pub name: String,
+    pub r#type: Type,
+    pub ttl: u32,
+    /// Record data of each record in the set, in presentation format.
+    pub values: Vec<String>,
+}
+
+impl Zone {
+    /// Builds a zone from the record sets listed by a hosted DNS provider.
+    ///
+    /// Names of record sets may be given as either:
+    ///
+    /// * `@` or an empty string, denoting the origin.
+    /// * Fully qualified, such as `www.example.org.`
+    /// * Absolute without a trailing dot, such as `www.example.org`,
+    ///   which is how many providers list them.
+    /// * Relative to the origin, such as `www`.
+    ///
+    /// Partially qualified names within the record data, such as the
+    /// targets of CNAME records, are qualified the same way, and `@`
+    /// within them refers to the origin itself.
+    pub fn import(
+        origin: FullyQualifiedDomainName,
+        record_sets: impl IntoIterator<Item = ProviderRecordSet>,
+    ) -> Result<Zone, ImportError> {
+        let mut zone = Zone::new(origin);
+
+        for record_set in record_sets {
+            let fqdn = qualify(&record_set.name, zone.origin()).map_err(|error| {
+                ImportError::InvalidName {
+                    name: record_set.name.clone(),
+                    error,
+                }
+            })?;
+
+            for value in record_set.values {
+                let rdata =
+                    match RecordData::parse_in(record_set.r#type, &value, Some(zone.origin())) {
+                        Ok(rdata) => rdata.map_names(|name| {
+                            DomainName::Full(
+                                within(name, zone.origin())
+                                    .unwrap_or_else(|| name.with_origin(zone.origin())),
+                            )
+                        }),
+                        Err(error) => {
+                            return Err(ImportError::InvalidValue {
+                                name: fqdn,
+                                r#type: record_set.r#type,
+                                value,
+                                error,
+                            })
+                        }
+                    };
+
+                zone.insert(Record {
+                    fqdn: fqdn.clone(),
+                    class: zone.class(),
+                    r#type: record_set.r#type,
+                    ttl: record_set.ttl,
+                    rdata,
+                })?;
+            }
+        }
+
+        Ok(zone)
+    }
+}
+
+/// Qualifies the name of a record set within the zone of `origin`.
+fn qualify(
+    name: &str,
+    origin: &FullyQualifiedDomainName,
+) -> Result<FullyQualifiedDomainName, DomainNameError> {
+    if name.is_empty() || name == "@" {
+        return Ok(origin.clone());
+    }
+
+    let name = DomainName::try_from(name)?;
+
+    match within(&name, origin) {
+        Some(absolute) => Ok(absolute),
+        None => name.try_with_origin(origin),
+    }
+}
+
+/// Returns the name as fully qualified if it is the origin or one of its
+/// subdomains, since providers commonly list absolute names without the
+/// trailing dot.
+fn within(
+    name: &DomainName,
+    origin: &FullyQualifiedDomainName,
+) -> Option<FullyQualifiedDomainName> {
+    let absolute = name.to_fully_qualified();
+
+    (absolute == *origin || absolute.is_subdomain_of(origin)).then_some(absolute)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::{ImportError, ZoneError},
+        fqdn, ProviderRecordSet, Type, Zone,
+    };
+
+    fn record_set(name: &str, r#type: Type, values: &[&str]) -> ProviderRecordSet {
+        ProviderRecordSet {
+            name: name.to_string(),
+            r#type,
+            ttl: 300,
+            values: values.iter().map(|value| value.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn import() {
+        let zone = Zone::import(
+            fqdn!("example.org."),
+            [
+                record_set("@", Type::MX, &["10 mail"]),
+                record_set("@", Type::NS, &["@"]),
+                record_set("www", Type::A, &["192.0.2.1", "192.0.2.2"]),
+                record_set("api.example.org", Type::CNAME, &["www"]),
+                record_set("cdn", Type::CNAME, &["www.example.org"]),
+                record_set("ext", Type::CNAME, &["www.example.com"]),
+                record_set("mail.example.org.", Type::TXT, &["\"v=spf1 -all\""]),
+            ],
+        )
+        .unwrap();
+
+        let records: Vec<String> = zone.iter().map(|record| record.to_string()).collect();
+
+        assert_eq!(
+            records,
+            [
+                "api.example.org. 300 IN CNAME www.example.org.",
+                "cdn.example.org. 300 IN CNAME www.example.org.",
+                "example.org. 300 IN MX 10 mail.example.org.",
+                "example.org. 300 IN NS example.org.",
+                "ext.example.org. 300 IN CNAME www.example.com.example.org.",
+                "mail.example.org. 300 IN TXT \"v=spf1 -all\"",
+                "www.example.org. 300 IN A 192.0.2.1",
+                "www.example.org. 300 IN A 192.0.2.2",
+            ]
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let import = |record_set| Zone::import(fqdn!("example.org."), [record_set]);
+
+        assert!(matches!(
+            import(record_set("www..example", Type::A, &["192.0.2.1"])),
+            Err(ImportError::InvalidName { .. })
+        ));
+        assert!(matches!(
+            import(record_set("www", Type::A, &["2001:db8::1"])),
+            Err(ImportError::InvalidValue { .. })
+        ));
+        assert_eq!(
+            import(record_set("www.example.com.", Type::A, &["192.0.2.1"])),
+            Err(ImportError::InvalidRecord(ZoneError::OutOfZone(fqdn!(
+                "www.example.com."
+            ))))
+        );
+    }
+}
@@ -0,0 +1,169 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{FullyQualifiedDomainName, Record, RecordIdent, Type};
+
+/// Collection of records, indexed by owner name and by type.
+///
+/// Inserting and removing a record updates the indices in constant time,
+/// so the index can be kept alongside a live set of records instead of
+/// being rebuilt whenever the records change.
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct RecordIndex {
+    records: HashMap<RecordIdent, Record>,
+    owners: HashMap<FullyQualifiedDomainName, HashSet<RecordIdent>>,
+    types: HashMap<Type, HashSet<RecordIdent>>,
+}
+
+impl RecordIndex {
+    /// Constructs an empty index.
+    pub fn new() -> Self {
+        RecordIndex::default()
+    }
+
+    /// Inserts a record, returning the record with the same identity
+    /// which it replaced, if any.
+    pub fn insert(&mut self, record: Record) -> Option<Record> {
+        let ident = record.ident();
+
+        self.owners
+            .entry(ident.fqdn.clone())
+            .or_default()
+            .insert(ident.clone());
+
+        self.types
+            .entry(ident.r#type)
+            .or_default()
+            .insert(ident.clone());
+
+        self.records.insert(ident, record)
+    }
+
+    /// Removes the record with the given identity.
+    pub fn remove(&mut self, ident: &RecordIdent) -> Option<Record> {
+        let record = self.records.remove(ident)?;
+
+        if let Some(idents) = self.owners.get_mut(&ident.fqdn) {
+            idents.remove(ident);
+            if idents.is_empty() {
+                self.owners.remove(&ident.fqdn);
+            }
+        }
+
+        if let Some(idents) = self.types.get_mut(&ident.r#type) {
+            idents.remove(ident);
+            if idents.is_empty() {
+                self.types.remove(&ident.r#type);
+            }
+        }
+
+        Some(record)
+    }
+
+    /// Returns the record with the given identity, if present.
+    pub fn get(&self, ident: &RecordIdent) -> Option<&Record> {
+        self.records.get(ident)
+    }
+
+    /// Iterates over all records owned by the given name, in no particular order.
+    pub fn by_owner<'a>(
+        &'a self,
+        fqdn: &FullyQualifiedDomainName,
+    ) -> impl Iterator<Item = &'a Record> + 'a {
+        self.owners
+            .get(fqdn)
+            .into_iter()
+            .flatten()
+            .filter_map(|ident| self.records.get(ident))
+    }
+
+    /// Iterates over all records of the given type, in no particular order.
+    pub fn by_type(&self, r#type: Type) -> impl Iterator<Item = &Record> + '_ {
+        self.types
+            .get(&r#type)
+            .into_iter()
+            .flatten()
+            .filter_map(|ident| self.records.get(ident))
+    }
+
+    /// Iterates over the records of the record set with the given
+    /// owner name and type, in no particular order.
+    pub fn record_set<'a>(
+        &'a self,
+        fqdn: &FullyQualifiedDomainName,
+        r#type: Type,
+    ) -> impl Iterator<Item = &'a Record> + 'a {
+        self.by_owner(fqdn)
+            .filter(move |record| record.r#type == r#type)
+    }
+
+    /// Iterates over all owner names with at least one record.
+    pub fn owners(&self) -> impl Iterator<Item = &FullyQualifiedDomainName> + '_ {
+        self.owners.keys()
+    }
+
+    /// Iterates over all records, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = &Record> + '_ {
+        self.records.values()
+    }
+
+    /// Number of records in the index.
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    /// Returns true if the index contains no records.
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+}
+
+impl Extend<Record> for RecordIndex {
+    fn extend<T: IntoIterator<Item = Record>>(&mut self, iter: T) {
+        for record in iter {
+            self.insert(record);
+        }
+    }
+}
+
+impl FromIterator<Record> for RecordIndex {
+    fn from_iter<T: IntoIterator<Item = Record>>(iter: T) -> Self {
+        let mut index = RecordIndex::new();
+        index.extend(iter);
+        index
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{record::record, FullyQualifiedDomainName, RecordIndex, Type};
+
+    #[test]
+    fn incremental_updates() {
+        let www = FullyQualifiedDomainName::try_from("www.example.org.").unwrap();
+
+        let mut index: RecordIndex = [
+            record("www.example.org. 300 IN A 192.168.0.1"),
+            record("www.example.org. 300 IN A 192.168.0.2"),
+            record("www.example.org. 300 IN AAAA ::1"),
+            record("api.example.org. 300 IN A 192.168.0.3"),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(index.by_owner(&www).count(), 3);
+        assert_eq!(index.by_type(Type::A).count(), 3);
+        assert_eq!(index.record_set(&www, Type::A).count(), 2);
+
+        let removed = index
+            .remove(&record("www.example.org. 300 IN AAAA ::1").ident())
+            .unwrap();
+        assert_eq!(removed.r#type, Type::AAAA);
+
+        assert_eq!(index.by_owner(&www).count(), 2);
+        assert_eq!(index.by_type(Type::AAAA).count(), 0);
+
+        index.remove(&record("api.example.org. 300 IN A 192.168.0.3").ident());
+        assert_eq!(index.owners().collect::<Vec<_>>(), [&www]);
+        assert_eq!(index.len(), 2);
+    }
+}
@@ -0,0 +1,92 @@
+use std::{
+    collections::HashSet,
+    sync::{Arc, Mutex, OnceLock, PoisonError},
+};
+
+use crate::FullyQualifiedDomainName;
+
+/// Deduplicating store of shared [`FullyQualifiedDomainName`]s.
+///
+/// Interning a name returns an [`Arc`] pointing to a single shared copy
+/// of it, so holding and cloning many references to the same names costs
+/// no further heap allocations.
+///
+/// Names stay interned until [`DomainNameInterner::purge`] is called,
+/// even if no references to them remain outside the interner.
+#[derive(Default, Debug)]
+pub struct DomainNameInterner {
+    names: Mutex<HashSet<Arc<FullyQualifiedDomainName>>>,
+}
+
+impl DomainNameInterner {
+    /// Constructs an empty interner.
+    pub fn new() -> Self {
+        DomainNameInterner::default()
+    }
+
+    /// Process-wide interner, shared by all callers.
+    pub fn global() -> &'static DomainNameInterner {
+        static GLOBAL: OnceLock<DomainNameInterner> = OnceLock::new();
+
+        GLOBAL.get_or_init(DomainNameInterner::new)
+    }
+
+    /// Returns the shared copy of `name`, interning it if it is not already.
+    pub fn intern(&self, name: FullyQualifiedDomainName) -> Arc<FullyQualifiedDomainName> {
+        let mut names = self.names.lock().unwrap_or_else(PoisonError::into_inner);
+
+        if let Some(interned) = names.get(&name) {
+            return Arc::clone(interned);
+        }
+
+        let interned = Arc::new(name);
+        names.insert(Arc::clone(&interned));
+        interned
+    }
+
+    /// Removes all names which are no longer referenced outside the interner.
+    pub fn purge(&self) {
+        self.names
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .retain(|name| Arc::strong_count(name) > 1);
+    }
+
+    /// Number of distinct names currently interned.
+    pub fn len(&self) -> usize {
+        self.names
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .len()
+    }
+
+    /// Returns true if no names are interned.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::{DomainNameInterner, FullyQualifiedDomainName};
+
+    #[test]
+    fn shared_storage() {
+        let interner = DomainNameInterner::new();
+
+        let first = interner.intern(FullyQualifiedDomainName::try_from("example.org.").unwrap());
+        let second = interner.intern(FullyQualifiedDomainName::try_from("example.org.").unwrap());
+        let other = interner.intern(FullyQualifiedDomainName::try_from("example.com.").unwrap());
+
+        assert!(Arc::ptr_eq(&first, &second));
+        assert!(!Arc::ptr_eq(&first, &other));
+        assert_eq!(interner.len(), 2);
+
+        drop(other);
+        interner.purge();
+
+        assert_eq!(interner.len(), 1);
+    }
+}
@@ -1,24 +1,145 @@
+mod annotated;
+mod borrowed;
+mod canonical;
+#[cfg(feature = "rand")]
+mod casing;
 mod class;
+mod compiled;
+mod delegation;
+mod diagnostic;
+pub mod diff;
 mod dn;
+mod dname;
+mod dnssd;
+mod email;
 mod fqdn;
+#[cfg(feature = "arbitrary")]
+mod fuzzing;
+#[cfg(feature = "heapsize")]
+mod heapsize;
 mod ident;
+mod import;
+mod index;
+#[cfg(feature = "intern")]
+mod intern;
+#[cfg(feature = "serde")]
+pub mod list;
+#[doc(hidden)]
+pub mod literal;
+mod mnemonic;
+mod options;
 mod pattern;
+mod patternset;
+mod policy;
 mod pqdn;
+#[cfg(feature = "python")]
+pub mod python;
+mod rdata;
+mod record;
+mod registrable;
+mod reverse;
+mod rrset;
 mod segment;
+mod service;
+mod similarity;
+mod snapshot;
+mod soa;
+mod special;
+mod stagger;
+mod suggestion;
+mod synthesize;
+mod template;
+#[cfg(feature = "testing")]
+pub mod testing;
+mod timestamp;
+mod trie;
+mod ttl;
 mod r#type;
+mod typepattern;
+#[cfg(feature = "idna")]
+mod unicode;
+mod views;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+mod wildcard;
+mod wire;
+mod zone;
+mod zoneconf;
+pub mod zonefile;
 
+pub use annotated::{Annotated, Provenance};
+pub use borrowed::{DomainNameRef, FqdnRef};
+pub use canonical::{CanonicalOrd, Canonicalize};
 pub use class::Class;
+pub use compiled::CompiledPattern;
+pub use delegation::DelegationRule;
+pub use diagnostic::{Diagnostic, Severity};
 pub use dn::DomainName;
-pub use fqdn::FullyQualifiedDomainName;
-pub use ident::RecordIdent;
-pub use pattern::{Pattern, PatternSegment};
+pub use dnssd::InstanceName;
+pub use fqdn::{FullyQualifiedDomainName, RelativeDisplay};
+#[cfg(feature = "heapsize")]
+pub use heapsize::HeapSize;
+pub use ident::{RecordIdent, RecordIdentBuilder, RecordIdents};
+pub use import::ProviderRecordSet;
+pub use index::RecordIndex;
+#[cfg(feature = "intern")]
+pub use intern::DomainNameInterner;
+pub use mnemonic::MnemonicRegistry;
+pub use options::{Normalizer, ParseOptions, Validation};
+pub use pattern::{GlobToken, OriginBoundPattern, Pattern, PatternSegment, PatternSyntax};
+pub use patternset::PatternSet;
+pub use policy::{TypePolicy, TypeRegistry, ZonePolicy};
 pub use pqdn::PartiallyQualifiedDomainName;
-pub use r#type::Type;
+pub use r#type::{Category, Type, UnknownType};
+pub use rdata::RecordData;
+pub use record::Record;
+pub use rrset::RRSets;
 pub use segment::DomainSegment;
+pub use service::{Protocol, ServiceName};
+pub use snapshot::{Serial, ZoneDelta, ZoneHistory, ZoneSnapshot};
+pub use soa::Soa;
+pub use special::SpecialUseDomain;
+pub use stagger::TtlStagger;
+pub use suggestion::Suggest;
+pub use template::ZoneTemplate;
+pub use timestamp::Timestamp;
+pub use trie::DomainTrie;
+pub use ttl::Ttl;
+pub use typepattern::TypePattern;
+pub use views::ZoneViews;
+pub use wildcard::WildcardPolicy;
+pub use wire::{NameDecoder, NameEncoder};
+pub use zone::{MergeStrategy, Zone};
+pub use zoneconf::{ServerFlavor, ZoneRole, ZoneStatement, ZoneStatementDisplay};
 
 pub mod error {
+    pub use crate::class::ClassError;
+    pub use crate::dn::DomainNameError;
+    pub use crate::dname::DnameError;
+    pub use crate::dnssd::InstanceNameError;
+    pub use crate::email::EmailError;
     pub use crate::fqdn::FullyQualifiedDomainNameError;
-    pub use crate::pattern::PatternSegmentError;
+    pub use crate::ident::RecordIdentError;
+    pub use crate::import::ImportError;
+    pub use crate::mnemonic::MnemonicError;
+    pub use crate::pattern::{PatternError, PatternSegmentError};
+    pub use crate::policy::PolicyViolation;
     pub use crate::pqdn::PartiallyQualifiedDomainNameError;
+    pub use crate::r#type::TypeError;
+    pub use crate::rdata::RecordDataError;
+    pub use crate::record::RecordError;
+    pub use crate::rrset::RRSetError;
     pub use crate::segment::DomainSegmentError;
+    pub use crate::service::ServiceNameError;
+    pub use crate::snapshot::HistoryError;
+    pub use crate::template::TemplateError;
+    pub use crate::timestamp::TimestampError;
+    pub use crate::ttl::TtlError;
+    #[cfg(feature = "idna")]
+    pub use crate::unicode::IdnaError;
+    pub use crate::views::ViewError;
+    pub use crate::wire::WireError;
+    pub use crate::zone::{MergeError, ZoneError};
+    pub use crate::zoneconf::ZoneStatementError;
+    pub use crate::zonefile::ZoneFileError;
 }
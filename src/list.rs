@@ -0,0 +1,133 @@
+//! Serde helper for deserializing lists of string-based types, such as
+//! [`Pattern`](crate::Pattern)s, reporting which item failed to parse.
+//!
+//! Deserializing a plain `Vec<Pattern>` only reports why an item is invalid,
+//! which makes it hard to locate in long lists. This helper includes the
+//! index and raw value of the offending item in the error instead:
+//!
+//! ```text
+//! #[derive(Deserialize)]
+//! struct Spec {
+//!     #[serde(deserialize_with = "kubizone_common::list::deserialize")]
+//!     patterns: Vec<Pattern>,
+//! }
+//!
+//! item 1 ("www..example.org."): segment 1: pattern is an empty string
+//! ```
+//!
+//! Lists of types deserialized from maps, such as
+//! [`DelegationRule`](crate::DelegationRule)s or [`Record`](crate::Record)s,
+//! have no single raw value to report, so [`deserialize_items`] only
+//! includes the index of the offending item.
+
+use std::{fmt::Display, marker::PhantomData};
+
+use serde::{
+    de::{Error, SeqAccess, Visitor},
+    Deserialize, Deserializer,
+};
+
+/// Deserializes a list of strings, parsing each item using [`TryFrom<&str>`].
+pub fn deserialize<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: for<'a> TryFrom<&'a str>,
+    for<'a> <T as TryFrom<&'a str>>::Error: Display,
+{
+    Vec::<String>::deserialize(deserializer)?
+        .iter()
+        .enumerate()
+        .map(|(index, value)| {
+            T::try_from(value.as_str())
+                .map_err(|err| D::Error::custom(format!("item {index} ({value:?}): {err}")))
+        })
+        .collect()
+}
+
+/// Deserializes a list of any deserializable items, such as
+/// [`DelegationRule`](crate::DelegationRule)s, prefixing errors with the
+/// index of the item which failed.
+pub fn deserialize_items<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    struct ItemsVisitor<T>(PhantomData<T>);
+
+    impl<'de, T: Deserialize<'de>> Visitor<'de> for ItemsVisitor<T> {
+        type Value = Vec<T>;
+
+        fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+            formatter.write_str("a list")
+        }
+
+        fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: SeqAccess<'de>,
+        {
+            let mut items = Vec::with_capacity(seq.size_hint().unwrap_or_default().min(1024));
+
+            loop {
+                match seq.next_element() {
+                    Ok(Some(item)) => items.push(item),
+                    Ok(None) => return Ok(items),
+                    Err(err) => {
+                        return Err(A::Error::custom(format!("item {}: {err}", items.len())))
+                    }
+                }
+            }
+        }
+    }
+
+    deserializer.deserialize_seq(ItemsVisitor(PhantomData))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+
+    use crate::{DelegationRule, Pattern};
+
+    #[derive(Debug, Deserialize)]
+    struct Spec {
+        #[serde(deserialize_with = "crate::list::deserialize")]
+        patterns: Vec<Pattern>,
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct Rules {
+        #[serde(deserialize_with = "crate::list::deserialize_items")]
+        rules: Vec<DelegationRule>,
+    }
+
+    #[test]
+    fn index_in_error() {
+        let spec: Spec =
+            serde_yaml::from_str("patterns: [example.org., '*.example.org.']").unwrap();
+        assert_eq!(spec.patterns.len(), 2);
+
+        let err = serde_yaml::from_str::<Spec>("patterns: [example.org., 'www..example.org.']")
+            .unwrap_err()
+            .to_string();
+
+        assert!(err.starts_with("item 1 (\"www..example.org.\"): "), "{err}");
+    }
+
+    #[test]
+    fn index_of_structured_item() {
+        let rules: Rules = serde_yaml::from_str(
+            "rules: [{pattern: example.org.}, {pattern: '*.example.org.', types: [A]}]",
+        )
+        .unwrap();
+        assert_eq!(rules.rules.len(), 2);
+
+        let err = serde_yaml::from_str::<Rules>(
+            "rules: [{pattern: example.org.}, {pattern: example.org., types: [A, BOGUS]}]",
+        )
+        .unwrap_err()
+        .to_string();
+
+        assert!(err.contains("item 1: "), "{err}");
+        assert!(err.contains("item 1 (\"BOGUS\")"), "{err}");
+    }
+}
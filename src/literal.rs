@@ -0,0 +1,352 @@
+//! Compile-time validation of literals, used by the [`fqdn!`](crate::fqdn),
+//! [`pqdn!`](crate::pqdn) and [`pattern!`](crate::pattern) macros.
+//!
+//! The checks mirror those applied when parsing at runtime, except that
+//! escapes are not supported, so that every literal accepted here is
+//! guaranteed to parse.
+
+/// Maximum length of a label in octets.
+const MAX_LABEL_LENGTH: usize = 63;
+
+/// Maximum length of a domain name in octets, in wire format.
+const MAX_NAME_LENGTH: usize = 255;
+
+/// Maximum number of labels in a domain name, excluding the root.
+const MAX_LABELS: usize = 127;
+
+/// Maximum length of a domain name which a pattern can match.
+const MAX_PATTERN_LENGTH: usize = 253;
+
+/// Validates a fully qualified domain name literal, such as `example.org.`
+pub const fn fqdn(name: &str) -> Result<(), &'static str> {
+    let bytes = name.as_bytes();
+
+    if bytes.is_empty() || bytes[bytes.len() - 1] != b'.' {
+        return Err("fully qualified domain names must end with a dot");
+    }
+
+    // The root, written as `.`
+    if trim_dots(bytes) == 0 {
+        return Ok(());
+    }
+
+    labels(bytes, trim_dots(bytes))
+}
+
+/// Validates a partially qualified domain name literal, such as `www.example`.
+pub const fn pqdn(name: &str) -> Result<(), &'static str> {
+    let bytes = name.as_bytes();
+
+    if !bytes.is_empty() && bytes[bytes.len() - 1] == b'.' {
+        return Err("partially qualified domain names must not end with a dot");
+    }
+
+    labels(bytes, bytes.len())
+}
+
+/// Validates a pattern literal using the wildcard syntax, such as `*.example.org.`
+pub const fn pattern(pattern: &str) -> Result<(), &'static str> {
+    let bytes = pattern.as_bytes();
+    let mut end = trim_dots(bytes);
+
+    // Trailing origin.
+    if end >= 1 && bytes[end - 1] == b'@' {
+        if end == 1 {
+            return Ok(());
+        }
+
+        if bytes[end - 2] == b'.' {
+            end -= 2;
+        }
+    }
+
+    let (mut start, mut index, mut segments, mut min_match_len) = (0, 0, 0, 0);
+
+    loop {
+        let stop = next_dot(bytes, start, end);
+
+        if let Err(message) = label(bytes, start, stop) {
+            return Err(message);
+        }
+
+        let wildcards = count(bytes, start, stop, b'*');
+        let standalone = wildcards == stop - start;
+
+        if index > 0 && standalone && wildcards == 1 {
+            return Err("standalone wildcards are only permitted as the first segment");
+        }
+
+        // Multi-level wildcards may match nothing at all.
+        let multi_level = standalone && wildcards == 2;
+
+        if wildcards > 1 && !multi_level {
+            return Err("pattern segments can only have one wildcard");
+        }
+
+        if !multi_level {
+            let literal = stop - start - wildcards;
+            min_match_len += if literal == 0 { 1 } else { literal };
+            segments += 1;
+        }
+
+        index += 1;
+
+        if stop == end {
+            break;
+        }
+
+        start = stop + 1;
+    }
+
+    if (min_match_len + segments).saturating_sub(1) > MAX_PATTERN_LENGTH {
+        return Err("pattern is too long to match any domain name");
+    }
+
+    Ok(())
+}
+
+/// Panics with the message of the error, failing compilation when
+/// evaluated in a constant.
+pub const fn assert_valid(result: Result<(), &'static str>) {
+    if let Err(message) = result {
+        panic!("{}", message);
+    }
+}
+
+/// Validates the labels of a name ending at `end`, excluding trailing dots.
+const fn labels(bytes: &[u8], end: usize) -> Result<(), &'static str> {
+    let (mut start, mut count, mut length) = (0, 0, 1);
+
+    loop {
+        let stop = next_dot(bytes, start, end);
+
+        if let Err(message) = label(bytes, start, stop) {
+            return Err(message);
+        }
+
+        if self::count(bytes, start, stop, b'*') > 0 {
+            if stop - start != 1 {
+                return Err("wildcard segments must have length 1");
+            }
+
+            if count > 0 {
+                return Err("wildcards are only permitted as the leading segment");
+            }
+        }
+
+        count += 1;
+        length += stop - start + 1;
+
+        if stop >= end {
+            break;
+        }
+
+        start = stop + 1;
+    }
+
+    if count > MAX_LABELS {
+        return Err("domain name has too many labels");
+    }
+
+    if length > MAX_NAME_LENGTH {
+        return Err("domain name is too long");
+    }
+
+    Ok(())
+}
+
+/// Validates the characters, length and hyphens of a single label.
+const fn label(bytes: &[u8], start: usize, end: usize) -> Result<(), &'static str> {
+    let length = end - start;
+
+    if length == 0 {
+        return Err("segments cannot be empty");
+    }
+
+    if length > MAX_LABEL_LENGTH {
+        return Err("segment is longer than 63 characters");
+    }
+
+    let mut index = start;
+
+    while index < end {
+        match bytes[index].to_ascii_lowercase() {
+            b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'*' => {}
+            b'\\' => return Err("escapes are not supported in literals"),
+            _ => return Err("segment contains an invalid character"),
+        }
+
+        index += 1;
+    }
+
+    if bytes[start] == b'-' || bytes[end - 1] == b'-' {
+        return Err("segments cannot start or end with a hyphen");
+    }
+
+    if length >= 4
+        && bytes[start + 2] == b'-'
+        && bytes[start + 3] == b'-'
+        && !(bytes[start].eq_ignore_ascii_case(&b'x')
+            && bytes[start + 1].eq_ignore_ascii_case(&b'n'))
+    {
+        return Err("only punycode segments may have hyphens at the 3rd and 4th position");
+    }
+
+    Ok(())
+}
+
+/// Length of the literal without its trailing dots.
+const fn trim_dots(bytes: &[u8]) -> usize {
+    let mut end = bytes.len();
+
+    while end > 0 && bytes[end - 1] == b'.' {
+        end -= 1;
+    }
+
+    end
+}
+
+/// Position of the next dot from `start`, or `end` if there is none.
+const fn next_dot(bytes: &[u8], start: usize, end: usize) -> usize {
+    let mut index = start;
+
+    while index < end && bytes[index] != b'.' {
+        index += 1;
+    }
+
+    index
+}
+
+/// Number of occurrences of `needle` between `start` and `end`.
+const fn count(bytes: &[u8], start: usize, end: usize, needle: u8) -> usize {
+    let (mut index, mut count) = (start, 0);
+
+    while index < end {
+        if bytes[index] == needle {
+            count += 1;
+        }
+
+        index += 1;
+    }
+
+    count
+}
+
+/// Constructs a [`FullyQualifiedDomainName`](crate::FullyQualifiedDomainName)
+/// from a literal, which is validated at compile time.
+///
+/// ```text
+/// let origin = fqdn!("example.org.");
+/// ```
+#[macro_export]
+macro_rules! fqdn {
+    ($name:literal) => {{
+        const _: () = $crate::literal::assert_valid($crate::literal::fqdn($name));
+        $crate::FullyQualifiedDomainName::try_from($name).expect("literal was validated")
+    }};
+}
+
+/// Constructs a [`PartiallyQualifiedDomainName`](crate::PartiallyQualifiedDomainName)
+/// from a literal, which is validated at compile time.
+///
+/// ```text
+/// let host = pqdn!("www");
+/// ```
+#[macro_export]
+macro_rules! pqdn {
+    ($name:literal) => {{
+        const _: () = $crate::literal::assert_valid($crate::literal::pqdn($name));
+        $crate::PartiallyQualifiedDomainName::try_from($name).expect("literal was validated")
+    }};
+}
+
+/// Constructs a [`Pattern`](crate::Pattern) from a literal using the
+/// wildcard syntax, which is validated at compile time.
+///
+/// ```text
+/// let pattern = pattern!("*.example.org.");
+/// ```
+#[macro_export]
+macro_rules! pattern {
+    ($pattern:literal) => {{
+        const _: () = $crate::literal::assert_valid($crate::literal::pattern($pattern));
+        $crate::Pattern::try_from($pattern).expect("literal was validated")
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        literal, FullyQualifiedDomainName, PartiallyQualifiedDomainName, Pattern, PatternSyntax,
+    };
+
+    #[test]
+    fn macros() {
+        assert_eq!(
+            fqdn!("www.Example.org."),
+            FullyQualifiedDomainName::try_from("www.example.org.").unwrap()
+        );
+        assert_eq!(
+            pqdn!("*.xn--bcher-kva"),
+            PartiallyQualifiedDomainName::try_from("*.xn--bcher-kva").unwrap()
+        );
+        assert_eq!(
+            pattern!("www*.example.org."),
+            Pattern::try_from("www*.example.org.").unwrap()
+        );
+    }
+
+    #[test]
+    fn agrees_with_parsing() {
+        let long = format!("{}.", vec!["a".repeat(63); 4].join("."));
+
+        for name in [
+            "",
+            ".",
+            "..",
+            "org",
+            "example.org.",
+            "example..org.",
+            "*.example.org.",
+            "www.*.org.",
+            "**.example.org.",
+            "www.**.org.",
+            "**.www.**.**.org.",
+            "www.**",
+            "***.org.",
+            "*www.org.",
+            "-www.org.",
+            "www-.org.",
+            "ww--w.org.",
+            "xn--bcher-kva.org.",
+            "w w.org.",
+            "@",
+            "www.@",
+            "www.@.",
+            "@.org",
+            "www..@",
+            long.as_str(),
+            &long[64..],
+            &long[..long.len() - 1],
+        ] {
+            assert_eq!(
+                literal::fqdn(name).is_ok(),
+                FullyQualifiedDomainName::try_from(name).is_ok(),
+                "{name:?}"
+            );
+            assert_eq!(
+                literal::pqdn(name).is_ok(),
+                PartiallyQualifiedDomainName::try_from(name).is_ok(),
+                "{name:?}"
+            );
+            assert_eq!(
+                literal::pattern(name).is_ok(),
+                Pattern::parse(name, PatternSyntax::Wildcard).is_ok(),
+                "{name:?}"
+            );
+        }
+
+        assert!(literal::pattern("*w*.org").is_err());
+        assert!(literal::fqdn("w\\.w.org.").is_err());
+    }
+}
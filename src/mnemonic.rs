@@ -0,0 +1,232 @@
+use std::{
+    collections::BTreeMap,
+    sync::{OnceLock, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard},
+};
+
+use thiserror::Error;
+
+use crate::{Class, Type};
+
+/// Range of type and class codes reserved for private use (RFC 6895).
+const PRIVATE_USE: std::ops::RangeInclusive<u16> = 0xff00..=0xfffe;
+
+/// Produced when registering a mnemonic fails.
+#[derive(Error, Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum MnemonicError {
+    /// Only codes reserved for private use (`65280` to `65534`) may be registered.
+    #[error("code {0} is not reserved for private use")]
+    NotPrivateUse(u16),
+    /// Mnemonics must start with a letter, followed by letters, digits or hyphens.
+    #[error("invalid mnemonic {0:?}")]
+    InvalidMnemonic(String),
+    /// The mnemonic already identifies a type or class, including those of
+    /// the generic `TYPE123` and `CLASS123` syntax.
+    #[error("mnemonic {0:?} is already in use")]
+    AlreadyInUse(String),
+}
+
+/// Registry of mnemonics for private-use record types and classes.
+///
+/// Mnemonics registered with the [global](MnemonicRegistry::global)
+/// registry are used when displaying, parsing and (de)serializing
+/// [`Type::Unknown`] and [`Class::Unknown`], instead of the generic
+/// `TYPE65280` and `CLASS65280` syntax:
+///
+/// ```text
+/// MnemonicRegistry::global().register_type(65280, "ACME")?;
+///
+/// Type::from_code(65280).to_string() => "ACME"
+/// "acme".parse::<Type>()           => Ok(Type::from_code(65280))
+/// ```
+///
+/// Registered mnemonics are not reflected in JSON schemas.
+#[derive(Default, Debug)]
+pub struct MnemonicRegistry {
+    mnemonics: RwLock<Mnemonics>,
+}
+
+#[derive(Default, Debug)]
+struct Mnemonics {
+    types: BTreeMap<u16, String>,
+    classes: BTreeMap<u16, String>,
+}
+
+impl MnemonicRegistry {
+    /// Constructs an empty registry.
+    pub fn new() -> Self {
+        MnemonicRegistry::default()
+    }
+
+    /// Process-wide registry, consulted by [`Type`] and [`Class`].
+    pub fn global() -> &'static MnemonicRegistry {
+        static GLOBAL: OnceLock<MnemonicRegistry> = OnceLock::new();
+
+        GLOBAL.get_or_init(MnemonicRegistry::new)
+    }
+
+    /// Registers the mnemonic for the private-use record type `code`,
+    /// returning the mnemonic it replaced, if any.
+    pub fn register_type(
+        &self,
+        code: u16,
+        mnemonic: &str,
+    ) -> Result<Option<String>, MnemonicError> {
+        self.register(code, mnemonic, |mnemonics| &mut mnemonics.types)
+    }
+
+    /// Registers the mnemonic for the private-use class `code`,
+    /// returning the mnemonic it replaced, if any.
+    pub fn register_class(
+        &self,
+        code: u16,
+        mnemonic: &str,
+    ) -> Result<Option<String>, MnemonicError> {
+        self.register(code, mnemonic, |mnemonics| &mut mnemonics.classes)
+    }
+
+    fn register(
+        &self,
+        code: u16,
+        mnemonic: &str,
+        select: fn(&mut Mnemonics) -> &mut BTreeMap<u16, String>,
+    ) -> Result<Option<String>, MnemonicError> {
+        if !PRIVATE_USE.contains(&code) {
+            return Err(MnemonicError::NotPrivateUse(code));
+        }
+
+        if !mnemonic.starts_with(|c: char| c.is_ascii_alphabetic())
+            || !mnemonic
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-')
+        {
+            return Err(MnemonicError::InvalidMnemonic(mnemonic.to_string()));
+        }
+
+        let mnemonic = mnemonic.to_ascii_uppercase();
+
+        // Types and classes share a namespace, since zone files
+        // distinguish them only by their mnemonics.
+        if Type::from_mnemonic(&mnemonic).is_some() || Class::from_mnemonic(&mnemonic).is_some() {
+            return Err(MnemonicError::AlreadyInUse(mnemonic));
+        }
+
+        let mut mnemonics = self.write();
+
+        if select(&mut mnemonics).get(&code) == Some(&mnemonic) {
+            return Ok(Some(mnemonic));
+        }
+
+        if mnemonics
+            .types
+            .values()
+            .chain(mnemonics.classes.values())
+            .any(|name| *name == mnemonic)
+        {
+            return Err(MnemonicError::AlreadyInUse(mnemonic));
+        }
+
+        Ok(select(&mut mnemonics).insert(code, mnemonic))
+    }
+
+    /// Returns the mnemonic registered for the record type `code`.
+    pub fn type_mnemonic(&self, code: u16) -> Option<String> {
+        self.read().types.get(&code).cloned()
+    }
+
+    /// Returns the mnemonic registered for the class `code`.
+    pub fn class_mnemonic(&self, code: u16) -> Option<String> {
+        self.read().classes.get(&code).cloned()
+    }
+
+    /// Returns the record type code registered for the mnemonic, ignoring case.
+    pub fn type_code(&self, mnemonic: &str) -> Option<u16> {
+        find_code(&self.read().types, mnemonic)
+    }
+
+    /// Returns the class code registered for the mnemonic, ignoring case.
+    pub fn class_code(&self, mnemonic: &str) -> Option<u16> {
+        find_code(&self.read().classes, mnemonic)
+    }
+
+    fn read(&self) -> RwLockReadGuard<'_, Mnemonics> {
+        self.mnemonics
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn write(&self) -> RwLockWriteGuard<'_, Mnemonics> {
+        self.mnemonics
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+fn find_code(registry: &BTreeMap<u16, String>, mnemonic: &str) -> Option<u16> {
+    registry
+        .iter()
+        .find(|(_, registered)| registered.eq_ignore_ascii_case(mnemonic))
+        .map(|(code, _)| *code)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{error::MnemonicError, Class, MnemonicRegistry, Record, Type};
+
+    #[test]
+    fn registration() {
+        let registry = MnemonicRegistry::new();
+
+        assert_eq!(registry.register_type(65300, "acme"), Ok(None));
+        assert_eq!(registry.type_code("ACME"), Some(65300));
+        assert_eq!(registry.type_mnemonic(65300).as_deref(), Some("ACME"));
+        assert_eq!(
+            registry.register_type(65300, "ACME2"),
+            Ok(Some(String::from("ACME")))
+        );
+
+        assert_eq!(
+            registry.register_type(100, "ACME"),
+            Err(MnemonicError::NotPrivateUse(100))
+        );
+        assert_eq!(
+            registry.register_class(65300, "ACME2"),
+            Err(MnemonicError::AlreadyInUse(String::from("ACME2")))
+        );
+
+        for taken in ["TXT", "ch", "TYPE65301", "CLASS1"] {
+            assert_eq!(
+                registry.register_type(65301, taken),
+                Err(MnemonicError::AlreadyInUse(taken.to_ascii_uppercase()))
+            );
+        }
+
+        for invalid in ["", "1ACME", "AC ME"] {
+            assert_eq!(
+                registry.register_type(65301, invalid),
+                Err(MnemonicError::InvalidMnemonic(invalid.to_string()))
+            );
+        }
+    }
+
+    #[test]
+    fn global_registry() {
+        let global = MnemonicRegistry::global();
+        global.register_type(65310, "X-SITE").unwrap();
+        global.register_class(65310, "SITE").unwrap();
+
+        assert_eq!(Type::from_code(65310).to_string(), "X-SITE");
+        assert_eq!("x-site".parse(), Ok(Type::from_code(65310)));
+        assert_eq!(Class::Unknown(65310).to_string(), "SITE");
+        assert_eq!("site".parse(), Ok(Class::Unknown(65310)));
+
+        let record: Record = "www.example.org. 300 SITE X-SITE \\# 0".parse().unwrap();
+        assert_eq!(record.to_string(), "www.example.org. 300 SITE X-SITE \\# 0");
+
+        #[cfg(feature = "serde")]
+        assert_eq!(
+            serde_yaml::from_str::<Type>(&serde_yaml::to_string(&Type::from_code(65310)).unwrap())
+                .unwrap(),
+            Type::from_code(65310)
+        );
+    }
+}
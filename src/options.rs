@@ -0,0 +1,303 @@
+use std::{borrow::Cow, sync::Arc};
+
+use crate::{
+    error::{
+        DomainNameError, DomainSegmentError, FullyQualifiedDomainNameError,
+        PartiallyQualifiedDomainNameError,
+    },
+    segment::{label_offsets, written_position, LabelError},
+    DomainName, DomainSegment, FullyQualifiedDomainName, PartiallyQualifiedDomainName,
+    WildcardPolicy,
+};
+
+/// Rules applied to the labels of a name when parsing.
+///
+/// Different record types place different requirements on their owner
+/// names: hosts with address records must have valid hostnames, while
+/// service and verification records use labels such as `_acme-challenge`.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Validation {
+    /// Labels may contain letters, digits, hyphens and underscores
+    /// anywhere, as well as escaped characters.
+    #[default]
+    Relaxed,
+    /// Labels must be valid hostname labels (RFC 952, RFC 1123),
+    /// consisting of only letters, digits and hyphens.
+    Hostname,
+}
+
+impl Validation {
+    /// Checks the segments parsed from `value` against the rules.
+    fn check(&self, value: &str, segments: &[DomainSegment]) -> Result<(), LabelError> {
+        if *self == Validation::Relaxed {
+            return Ok(());
+        }
+
+        for ((offset, label), segment) in label_offsets(value).zip(segments) {
+            if segment.is_wildcard() {
+                continue;
+            }
+
+            if let Some(index) = segment
+                .as_raw()
+                .iter()
+                .position(|byte| !byte.is_ascii_alphanumeric() && *byte != b'-')
+            {
+                return Err(LabelError {
+                    offset,
+                    label: label.to_string(),
+                    error: DomainSegmentError::InvalidCharacter {
+                        character: char::from(segment.as_raw()[index]),
+                        position: written_position(label, index),
+                    },
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Callback rewriting names before they are parsed, see [`ParseOptions::normalize`].
+///
+/// Returns the name itself, borrowed, if it does not need rewriting.
+pub type Normalizer = Arc<dyn Fn(&str) -> Cow<'_, str> + Send + Sync>;
+
+/// Options for parsing names using `parse_with`, such as
+/// [`FullyQualifiedDomainName::parse_with`].
+///
+/// ```text
+/// let options = ParseOptions::default()
+///     .validation(Validation::Hostname)
+///     .wildcards(WildcardPolicy::Reject);
+///
+/// FullyQualifiedDomainName::parse_with("_acme-challenge.example.org.", &options)
+///     => Err(SegmentError { offset: 0, label: "_acme-challenge", .. })
+/// ```
+#[derive(Default, Clone)]
+pub struct ParseOptions {
+    validation: Validation,
+    wildcards: WildcardPolicy,
+    normalizer: Option<Normalizer>,
+}
+
+impl ParseOptions {
+    /// Sets the rules applied to the labels of the name.
+    pub fn validation(mut self, validation: Validation) -> Self {
+        self.validation = validation;
+        self
+    }
+
+    /// Sets whether names containing a wildcard are accepted.
+    pub fn wildcards(mut self, wildcards: WildcardPolicy) -> Self {
+        self.wildcards = wildcards;
+        self
+    }
+
+    /// Sets a callback which rewrites names before they are parsed, such
+    /// as to strip an internal suffix or map legacy hostnames, so that
+    /// naming conventions can be applied wherever the options are used:
+    ///
+    /// ```text
+    /// let options = ParseOptions::default()
+    ///     .normalize(|name| name.replace(".corp.internal.", ".example.org.").into());
+    ///
+    /// FullyQualifiedDomainName::parse_with("www.corp.internal.", &options)
+    ///     => Ok("www.example.org.")
+    /// ```
+    ///
+    /// The callback may capture state, such as a table of legacy hostnames.
+    /// Offsets within errors refer to the rewritten name.
+    pub fn normalize(
+        mut self,
+        normalizer: impl Fn(&str) -> Cow<'_, str> + Send + Sync + 'static,
+    ) -> Self {
+        self.normalizer = Some(Arc::new(normalizer));
+        self
+    }
+
+    /// Applies the normalizer to the name, if one is set.
+    fn normalized<'a>(&self, value: &'a str) -> Cow<'a, str> {
+        match &self.normalizer {
+            Some(normalizer) => normalizer(value),
+            None => Cow::Borrowed(value),
+        }
+    }
+}
+
+impl std::fmt::Debug for ParseOptions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ParseOptions")
+            .field("validation", &self.validation)
+            .field("wildcards", &self.wildcards)
+            .field("normalizer", &self.normalizer.as_ref().map(|_| ".."))
+            .finish()
+    }
+}
+
+/// Allows reusing options, which only clones the reference to the normalizer.
+impl From<&ParseOptions> for ParseOptions {
+    fn from(value: &ParseOptions) -> Self {
+        value.clone()
+    }
+}
+
+impl From<Validation> for ParseOptions {
+    fn from(value: Validation) -> Self {
+        ParseOptions::default().validation(value)
+    }
+}
+
+impl From<WildcardPolicy> for ParseOptions {
+    fn from(value: WildcardPolicy) -> Self {
+        ParseOptions::default().wildcards(value)
+    }
+}
+
+impl FullyQualifiedDomainName {
+    /// Parses the name, applying the given options.
+    pub fn parse_with(
+        value: &str,
+        options: impl Into<ParseOptions>,
+    ) -> Result<Self, FullyQualifiedDomainNameError> {
+        let options = options.into();
+        let value = options.normalized(value);
+        let fqdn = Self::try_from(value.as_ref())?;
+
+        options.validation.check(&value, &fqdn.0)?;
+
+        if !options.wildcards.permits(fqdn.contains_wildcard()) {
+            return Err(FullyQualifiedDomainNameError::WildcardNotPermitted);
+        }
+
+        Ok(fqdn)
+    }
+}
+
+impl PartiallyQualifiedDomainName {
+    /// Parses the name, applying the given options.
+    pub fn parse_with(
+        value: &str,
+        options: impl Into<ParseOptions>,
+    ) -> Result<Self, PartiallyQualifiedDomainNameError> {
+        let options = options.into();
+        let value = options.normalized(value);
+        let pqdn = Self::try_from(value.as_ref())?;
+
+        options.validation.check(&value, &pqdn.0)?;
+
+        if !options.wildcards.permits(pqdn.contains_wildcard()) {
+            return Err(PartiallyQualifiedDomainNameError::WildcardNotPermitted);
+        }
+
+        Ok(pqdn)
+    }
+}
+
+impl DomainName {
+    /// Parses the name, applying the given options.
+    pub fn parse_with(
+        value: &str,
+        options: impl Into<ParseOptions>,
+    ) -> Result<Self, DomainNameError> {
+        let options = options.into();
+        let value = options.normalized(value);
+        let name = Self::try_from(value.as_ref())?;
+
+        options.validation.check(&value, name.as_ref())?;
+
+        if !options.wildcards.permits(name.contains_wildcard()) {
+            return Err(DomainNameError::WildcardNotPermitted);
+        }
+
+        Ok(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use crate::{
+        error::{DomainNameError, DomainSegmentError, FullyQualifiedDomainNameError},
+        fqdn, DomainName, FullyQualifiedDomainName, ParseOptions, Validation, WildcardPolicy,
+    };
+
+    #[test]
+    fn validation_modes() {
+        for name in ["_acme-challenge.example.org.", "1password.example.org."] {
+            assert!(FullyQualifiedDomainName::parse_with(name, Validation::Relaxed).is_ok());
+        }
+
+        let strict = ParseOptions::default().validation(Validation::Hostname);
+
+        assert!(FullyQualifiedDomainName::parse_with("1password.example.org.", &strict).is_ok());
+        assert!(FullyQualifiedDomainName::parse_with("*.example.org.", &strict).is_ok());
+        assert_eq!(
+            FullyQualifiedDomainName::parse_with("_acme-challenge.example.org.", &strict),
+            Err(FullyQualifiedDomainNameError::SegmentError {
+                offset: 0,
+                label: String::from("_acme-challenge"),
+                error: DomainSegmentError::InvalidCharacter {
+                    character: '_',
+                    position: 0
+                }
+            })
+        );
+        assert_eq!(
+            DomainName::parse_with("www.web\\032server", &strict),
+            Err(DomainNameError::SegmentError {
+                offset: 4,
+                label: String::from("web\\032server"),
+                error: DomainSegmentError::InvalidCharacter {
+                    character: ' ',
+                    position: 3
+                }
+            })
+        );
+        assert_eq!(
+            FullyQualifiedDomainName::parse_with(
+                "*.example.org.",
+                strict.clone().wildcards(WildcardPolicy::Reject)
+            ),
+            Err(FullyQualifiedDomainNameError::WildcardNotPermitted)
+        );
+    }
+
+    #[test]
+    fn normalization() {
+        let options = ParseOptions::default()
+            .validation(Validation::Hostname)
+            .normalize(|name| name.replace(".corp.internal", ".example.org").into());
+
+        assert_eq!(
+            FullyQualifiedDomainName::parse_with("www.corp.internal.", &options),
+            Ok(fqdn!("www.example.org."))
+        );
+        assert_eq!(
+            DomainName::parse_with("www.corp.internal", &options),
+            DomainName::try_from("www.example.org")
+        );
+        assert!(FullyQualifiedDomainName::parse_with("_dmarc.corp.internal.", &options).is_err());
+        assert_eq!(
+            FullyQualifiedDomainName::parse_with("www.corp.internal.", Validation::Hostname),
+            Ok(fqdn!("www.corp.internal."))
+        );
+
+        // Callbacks may capture state, and leave names untouched.
+        let legacy = std::collections::BTreeMap::from([("intranet.", "intranet.example.org.")]);
+        let options = ParseOptions::default().normalize(move |name| match legacy.get(name) {
+            Some(name) => Cow::Borrowed(*name),
+            None => Cow::Borrowed(name),
+        });
+
+        assert_eq!(
+            FullyQualifiedDomainName::parse_with("intranet.", &options),
+            Ok(fqdn!("intranet.example.org."))
+        );
+        assert_eq!(
+            FullyQualifiedDomainName::parse_with("www.example.org.", options),
+            Ok(fqdn!("www.example.org."))
+        );
+    }
+}
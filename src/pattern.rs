@@ -1,18 +1,161 @@
-use std::fmt::{Display, Write};
-
-use schemars::JsonSchema;
+use std::{
+    fmt::{Debug, Display, Write},
+    str::FromStr,
+};
+
+#[cfg(feature = "schemars")]
+use schemars::{
+    schema::{InstanceType, Schema, SchemaObject, StringValidation},
+    JsonSchema,
+};
+#[cfg(feature = "serde")]
 use serde::{de::Error, Deserialize, Serialize};
 use thiserror::Error;
 
-use crate::{segment::DomainSegment, FullyQualifiedDomainName};
+use crate::{
+    error::DomainSegmentError,
+    segment::{strip_root, DomainSegment},
+    FullyQualifiedDomainName,
+};
 
+/// Produced when attempting to construct a [`Pattern`]
+/// from an invalid string.
 #[derive(Error, Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
-pub enum PatternError {}
+pub enum PatternError {
+    /// The segment at `index`, counting from zero, is invalid. It starts
+    /// at byte `offset` within the input.
+    #[error("invalid segment {segment:?} at offset {offset}: {error}")]
+    SegmentError {
+        index: usize,
+        offset: usize,
+        segment: String,
+        error: PatternSegmentError,
+    },
+    /// The origin (`@`) appears at the given index, but is only
+    /// permitted as the last segment.
+    #[error("origin @ must be the last segment, found at segment {0}")]
+    MisplacedOrigin(usize),
+    /// A standalone wildcard (`*`) appears at the given index, but
+    /// is only permitted as the first segment. Unlike `*`, the
+    /// multi-level wildcard (`**`) may appear anywhere.
+    #[error("standalone wildcard must be the first segment, found at segment {0}")]
+    NonLeadingWildcard(usize),
+    /// The shortest domain name the pattern could match is longer than
+    /// the permitted 253 characters.
+    #[error("pattern too long {0} > 253")]
+    TooLong(usize),
+    /// The segment at the given index contains wildcards or character
+    /// classes, so the pattern does not match exactly one name.
+    #[error("segment {0} is not a literal label")]
+    NotExact(usize),
+}
 
-#[derive(Default, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// Syntax used when parsing [`Pattern`]s and [`PatternSegment`]s.
+///
+/// Every pattern which is valid [`PatternSyntax::Wildcard`] has the
+/// same meaning when parsed as [`PatternSyntax::Glob`].
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PatternSyntax {
+    /// Segments may contain a single `*` wildcard, matching any
+    /// (possibly empty) sequence of characters.
+    #[default]
+    Wildcard,
+    /// Segments may contain any number of `*` wildcards, `?` matching
+    /// exactly one character, and character classes such as `[0-9]`
+    /// or `[a-f_]`, each matching exactly one of the listed characters.
+    Glob,
+    /// Segments may be regular expressions enclosed in slashes, such as
+    /// `/^dev-[0-9]+$/`, matched case-insensitively against a single
+    /// label. All other segments are parsed as [`PatternSyntax::Glob`].
+    ///
+    /// Regular expressions are not anchored implicitly.
+    #[cfg(feature = "regex")]
+    Regex,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Pattern(Vec<PatternSegment>);
 
 impl Pattern {
+    /// Parses a pattern using the given syntax.
+    ///
+    /// [`TryFrom`] and [`Deserialize`] use [`PatternSyntax::Glob`], and also
+    /// accept regular expression segments if the `regex` feature is
+    /// enabled, such that every pattern can be deserialized.
+    ///
+    /// A trailing `@` segment explicitly denotes the origin, so `www.@` is
+    /// equivalent to `www`, and `@` on its own is [`Pattern::origin`].
+    ///
+    /// A `**` segment matches any number of labels, including none, and
+    /// may appear anywhere in the pattern. `api.**.example.org` therefore
+    /// matches `api.example.org` and `api.eu.prod.example.org`, while a
+    /// leading `**` behaves like `*`, but also matches the name following
+    /// it, see [`Pattern::matches_apex`].
+    pub fn parse(value: &str, syntax: PatternSyntax) -> Result<Self, PatternError> {
+        Self::parse_segments(value, |label| PatternSegment::parse(label, syntax))
+    }
+
+    /// Parses a pattern, using `parse` for every segment other than the
+    /// origin and wildcards.
+    fn parse_segments(
+        value: &str,
+        parse: impl Fn(&str) -> Result<PatternSegment, PatternSegmentError>,
+    ) -> Result<Self, PatternError> {
+        let mut trimmed = value;
+        while let Some(stripped) = strip_root(trimmed) {
+            trimmed = stripped;
+        }
+
+        let mut labels: Vec<(usize, &str)> = trimmed
+            .split(segment_separator())
+            .scan(0, |offset, label| {
+                let start = *offset;
+                *offset += label.len() + 1;
+                Some((start, label))
+            })
+            .collect();
+
+        if labels.last().is_some_and(|(_, label)| *label == "@") {
+            labels.pop();
+        }
+
+        let segments = Result::from_iter(labels.into_iter().enumerate().map(
+            |(index, (offset, label))| match label {
+                "@" => Err(PatternError::MisplacedOrigin(index)),
+                "*" if index > 0 => Err(PatternError::NonLeadingWildcard(index)),
+                MULTI_LEVEL_WILDCARD => {
+                    Ok(PatternSegment::Label(String::from(MULTI_LEVEL_WILDCARD)))
+                }
+                label => parse(label).map_err(|error| PatternError::SegmentError {
+                    index,
+                    offset,
+                    segment: label.to_string(),
+                    error,
+                }),
+            },
+        ))?;
+
+        Pattern(segments).validate_length()
+    }
+
+    /// Maximum length of a domain name which a pattern can match,
+    /// excluding the trailing dot.
+    pub const MAX_LENGTH: usize = 253;
+
+    /// Returns a pattern matching exactly the given name, and nothing else.
+    ///
+    /// Wildcard names are the exception, since their leading `*` label
+    /// becomes a wildcard segment, as with [`Pattern::from_wildcard_fqdn`].
+    pub fn exact(fqdn: &FullyQualifiedDomainName) -> Self {
+        fqdn.iter().map(PatternSegment::from).collect()
+    }
+
+    /// Returns true if the pattern contains no wildcards or character
+    /// classes, such that it matches exactly one name.
+    pub fn is_exact(&self) -> bool {
+        self.0.iter().all(PatternSegment::is_literal)
+    }
+
     /// Returns a pattern that only matches the origin of the parent
     /// FQDN.
     pub fn origin() -> Self {
@@ -24,44 +167,256 @@ impl Pattern {
         self.0.iter()
     }
 
+    /// Sets whether a pattern with a leading standalone wildcard, such as
+    /// `*.example.org`, also matches the name following the wildcard,
+    /// `example.org` in this case.
+    ///
+    /// Such patterns are written with a leading `**` segment instead.
+    /// Patterns without a leading standalone wildcard are unaffected.
+    pub fn matches_apex(mut self, matches: bool) -> Self {
+        if let Some(first) = self
+            .0
+            .first_mut()
+            .filter(|first| first.is_standalone_wildcard())
+        {
+            let wildcard = if matches { MULTI_LEVEL_WILDCARD } else { "*" };
+            *first = PatternSegment::Label(String::from(wildcard));
+        }
+
+        self
+    }
+
     /// Returns a new pattern with the origin appended.
+    ///
+    /// The resulting pattern might not be able to match any legal domain
+    /// names, see [`Pattern::try_with_origin`] for a validating alternative.
     pub fn with_origin(&self, origin: &FullyQualifiedDomainName) -> Pattern {
         let mut cloned = self.clone();
         cloned.0.extend(origin.iter().map(PatternSegment::from));
         cloned
     }
 
+    /// Returns a new pattern with the origin appended, or an error if
+    /// the resulting pattern would be too long to match any legal domain.
+    pub fn try_with_origin(
+        &self,
+        origin: &FullyQualifiedDomainName,
+    ) -> Result<Pattern, PatternError> {
+        self.with_origin(origin).validate_length()
+    }
+
+    /// Asserts that every segment of the pattern could have been parsed.
+    ///
+    /// The overall length is deliberately not checked, since
+    /// [`Pattern::with_origin`] may produce overly long patterns.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any segment is invalid under [`PatternSyntax::Glob`],
+    /// which accepts a superset of [`PatternSyntax::Wildcard`], or
+    /// `PatternSyntax::Regex` if the `regex` feature is enabled.
+    pub fn debug_validate(&self) {
+        #[cfg(not(feature = "regex"))]
+        let syntax = PatternSyntax::Glob;
+        #[cfg(feature = "regex")]
+        let syntax = PatternSyntax::Regex;
+
+        for segment in &self.0 {
+            assert_eq!(
+                PatternSegment::parse(segment.as_ref(), syntax).as_ref(),
+                Ok(segment),
+                "pattern segment {segment:?} does not round-trip"
+            );
+        }
+    }
+
+    /// Length of the shortest domain name (excluding trailing dot) which
+    /// this pattern could possibly match.
+    pub fn min_match_len(&self) -> usize {
+        let (count, segments) = self
+            .0
+            .iter()
+            .filter(|segment| !segment.is_multi_level_wildcard())
+            .fold((0, 0), |(count, length), segment| {
+                (count + 1, length + segment.min_match_len().max(1))
+            });
+
+        (segments + count).saturating_sub(1)
+    }
+
+    fn validate_length(self) -> Result<Self, PatternError> {
+        if self.min_match_len() > Self::MAX_LENGTH {
+            return Err(PatternError::TooLong(self.min_match_len()));
+        }
+
+        Ok(self)
+    }
+
+    /// Binds the pattern to the given origin, producing an [`OriginBoundPattern`]
+    /// which can be matched against many domains without re-deriving state.
+    ///
+    /// The origin is appended to the pattern, exactly as with [`Pattern::with_origin`].
+    pub fn bind(&self, origin: &FullyQualifiedDomainName) -> OriginBoundPattern {
+        OriginBoundPattern::from(self.with_origin(origin))
+    }
+
+    /// Converts the pattern into an RFC 4592 wildcard owner name, with the
+    /// origin appended.
+    ///
+    /// Only patterns consisting of a standalone leading wildcard (`*`) followed
+    /// by literal segments, such as `*.dev`, can be expressed as wildcard owner
+    /// names. For all other patterns, [`None`] is returned.
+    pub fn to_wildcard_fqdn(
+        &self,
+        origin: &FullyQualifiedDomainName,
+    ) -> Option<FullyQualifiedDomainName> {
+        let pattern = self.with_origin(origin);
+        let (wildcard, rest) = pattern.0.split_first()?;
+
+        if wildcard.as_ref() != "*" {
+            return None;
+        }
+
+        std::iter::once(Ok(DomainSegment::new_unchecked("*")))
+            .chain(
+                rest.iter()
+                    .map(|segment| DomainSegment::try_from(segment.as_ref())),
+            )
+            .collect::<Result<FullyQualifiedDomainName, _>>()
+            .ok()
+    }
+
+    /// Converts an RFC 4592 wildcard owner name, such as `*.dev.example.org.`,
+    /// into the equivalent pattern.
+    ///
+    /// Returns [`None`] if the domain name is not a wildcard.
+    pub fn from_wildcard_fqdn(fqdn: &FullyQualifiedDomainName) -> Option<Pattern> {
+        if !fqdn.iter().next()?.is_wildcard() {
+            return None;
+        }
+
+        Some(fqdn.iter().map(PatternSegment::from).collect())
+    }
+
     /// Returns true if the papttern matches the given domain.
     pub fn matches(&self, domain: &FullyQualifiedDomainName) -> bool {
-        let domain_segments = domain.as_ref().iter().rev();
-        let pattern_segments = self.0[..].iter().rev();
+        labels_match(self.0.iter().rev(), domain.as_ref().iter().rev())
+    }
+}
 
-        if domain_segments.len() < pattern_segments.len() {
-            // Patterns longer than the domain segment cannot possibly match.
-            return false;
-        }
+/// A [`Pattern`] with its origin resolved, produced by [`Pattern::bind`].
+///
+/// Segments are stored in reverse order, so matching a domain requires
+/// neither allocation nor repeated traversal of the pattern.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct OriginBoundPattern {
+    reversed: Vec<PatternSegment>,
+}
 
-        if domain_segments.len() > pattern_segments.len()
-            // Domains longer than patterns can never match, unless the first
-            // segment of the pattern is a standalone wildcard (*)
-            && !self.0.first().is_some_and(|pattern| pattern.as_ref() == "*")
-        {
-            return false;
+impl OriginBoundPattern {
+    /// Returns true if the pattern matches the given domain.
+    ///
+    /// Semantically identical to [`Pattern::matches`].
+    pub fn matches(&self, domain: &FullyQualifiedDomainName) -> bool {
+        labels_match(self.reversed.iter(), domain.as_ref().iter().rev())
+    }
+
+    /// Returns the bound pattern, with its origin included.
+    pub fn to_pattern(&self) -> Pattern {
+        self.reversed.iter().rev().cloned().collect()
+    }
+}
+
+impl From<Pattern> for OriginBoundPattern {
+    fn from(value: Pattern) -> Self {
+        OriginBoundPattern {
+            reversed: value.0.into_iter().rev().collect(),
         }
+    }
+}
 
-        for (pattern, domain) in pattern_segments.zip(domain_segments) {
-            // If we have hit a pattern segment containing only a wildcard, the rest of the
-            // domain segments are automatically matched.
-            if pattern.as_ref() == "*" {
-                return true;
+/// Segment of a pattern as seen by [`labels_match`].
+pub(crate) trait SegmentMatcher {
+    /// Returns true if the segment is `**`.
+    fn is_multi_level_wildcard(&self) -> bool;
+
+    /// Returns true if the segment is `*` or `**`.
+    fn is_standalone_wildcard(&self) -> bool;
+
+    /// Returns true if the segment matches the single label.
+    fn matches_label(&self, label: &DomainSegment) -> bool;
+}
+
+impl SegmentMatcher for PatternSegment {
+    fn is_multi_level_wildcard(&self) -> bool {
+        PatternSegment::is_multi_level_wildcard(self)
+    }
+
+    fn is_standalone_wildcard(&self) -> bool {
+        PatternSegment::is_standalone_wildcard(self)
+    }
+
+    fn matches_label(&self, label: &DomainSegment) -> bool {
+        self.matches(label)
+    }
+}
+
+/// Matches domain labels against pattern segments, both starting from the
+/// rightmost label, backtracking to the most recent `**` on mismatch.
+pub(crate) fn labels_match<'a, 'b, S: SegmentMatcher + 'a>(
+    mut pattern: impl Iterator<Item = &'a S> + Clone,
+    mut domain: impl Iterator<Item = &'b DomainSegment> + Clone,
+) -> bool {
+    let mut backtrack = None;
+
+    while let Some(label) = domain.clone().next() {
+        let mut next = pattern.clone();
+
+        match next.next() {
+            Some(segment) if segment.is_multi_level_wildcard() => {
+                pattern = next;
+                backtrack = Some((pattern.clone(), domain.clone()));
+                continue;
             }
+            // The leading wildcard matches all remaining labels.
+            Some(segment) if segment.is_standalone_wildcard() => return true,
+            Some(segment) if segment.matches_label(label) => {
+                pattern = next;
+                domain.next();
+                continue;
+            }
+            _ => {}
+        }
 
-            if !pattern.matches(domain) {
-                return false;
+        match &mut backtrack {
+            Some((wildcard, consumed)) => {
+                consumed.next();
+                pattern = wildcard.clone();
+                domain = consumed.clone();
             }
+            None => return false,
+        }
+    }
+
+    pattern.all(|segment| segment.is_multi_level_wildcard())
+}
+
+impl Display for OriginBoundPattern {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for segment in self.reversed.iter().rev() {
+            write!(f, "{}", segment)?;
+            f.write_char('.')?;
         }
 
-        true
+        Ok(())
+    }
+}
+
+impl Debug for OriginBoundPattern {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("OriginBoundPattern")
+            .field(&self.to_string())
+            .finish()
     }
 }
 
@@ -71,30 +426,67 @@ impl FromIterator<PatternSegment> for Pattern {
     }
 }
 
+impl TryFrom<&Pattern> for FullyQualifiedDomainName {
+    type Error = PatternError;
+
+    /// Converts a pattern without wildcards or character classes into
+    /// the only name it matches.
+    fn try_from(value: &Pattern) -> Result<Self, Self::Error> {
+        if let Some(index) = value.0.iter().position(|segment| !segment.is_literal()) {
+            return Err(PatternError::NotExact(index));
+        }
+
+        Ok(value
+            .0
+            .iter()
+            .map(|segment| {
+                DomainSegment::try_from(segment.as_ref())
+                    .expect("literal segments are valid labels")
+            })
+            .collect())
+    }
+}
+
+impl TryFrom<Pattern> for FullyQualifiedDomainName {
+    type Error = PatternError;
+
+    fn try_from(value: Pattern) -> Result<Self, Self::Error> {
+        Self::try_from(&value)
+    }
+}
+
 impl TryFrom<&str> for Pattern {
-    type Error = PatternSegmentError;
+    type Error = PatternError;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        let segments = Result::from_iter(
-            value
-                .trim_end_matches('.')
-                .split('.')
-                .map(PatternSegment::try_from),
-        )?;
-        Ok(Pattern(segments))
+        Pattern::parse_segments(value, |label| PatternSegment::try_from(label))
     }
 }
 
 impl TryFrom<String> for Pattern {
-    type Error = PatternSegmentError;
+    type Error = PatternError;
 
     fn try_from(value: String) -> Result<Self, Self::Error> {
         Self::try_from(value.as_ref())
     }
 }
 
+impl FromStr for Pattern {
+    type Err = PatternError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
 impl Display for Pattern {
+    /// Renders the pattern with a trailing dot, or as `@` if it
+    /// only matches the origin.
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.0.is_empty() {
+            return f.write_char('@');
+        }
+
         for segment in &self.0 {
             write!(f, "{}", segment)?;
             f.write_char('.')?;
@@ -104,16 +496,39 @@ impl Display for Pattern {
     }
 }
 
+impl Debug for Pattern {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Pattern").field(&self.to_string()).finish()
+    }
+}
+
+#[cfg(feature = "schemars")]
 impl JsonSchema for Pattern {
     fn schema_name() -> String {
-        <String as schemars::JsonSchema>::schema_name()
+        String::from("Pattern")
     }
 
-    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
-        <String as schemars::JsonSchema>::json_schema(gen)
+    fn json_schema(_: &mut schemars::gen::SchemaGenerator) -> Schema {
+        let segment = r"([a-zA-Z0-9_*?\[\]-]|\\[0-9]{3}|\\[^0-9]){1,63}";
+
+        // Every segment may contain a wildcard matching nothing,
+        // in addition to the optional trailing dot.
+        let max_length = Self::MAX_LENGTH + FullyQualifiedDomainName::MAX_LABELS + 1;
+
+        SchemaObject {
+            instance_type: Some(InstanceType::String.into()),
+            string: Some(Box::new(StringValidation {
+                max_length: Some(max_length as u32),
+                pattern: Some(format!(r"^(@|{segment}(\.{segment})*(\.@)?\.?)$")),
+                ..Default::default()
+            })),
+            ..Default::default()
+        }
+        .into()
     }
 }
 
+#[cfg(feature = "serde")]
 impl<'de> Deserialize<'de> for Pattern {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -125,6 +540,7 @@ impl<'de> Deserialize<'de> for Pattern {
     }
 }
 
+#[cfg(feature = "serde")]
 impl Serialize for Pattern {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -137,28 +553,124 @@ impl Serialize for Pattern {
 /// Segment of a pattern.
 ///
 /// Used for matching against a single [`DomainSegment`].
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct PatternSegment(String);
+///
+/// Segments are validated when parsed, constructing the variants directly
+/// bypasses this validation, see [`Pattern::debug_validate`].
+#[derive(Clone)]
+pub enum PatternSegment {
+    /// Literal label, or a label containing a single wildcard, such as
+    /// `dev-*`, in lowercase.
+    Label(String),
+    /// Label containing `?`, character classes or multiple wildcards,
+    /// such as `dev-[0-9]*`, in lowercase, along with its glob tokens.
+    Glob {
+        source: String,
+        tokens: Vec<GlobToken>,
+    },
+    /// Regular expression enclosed in slashes, such as `/^dev-[0-9]+$/`,
+    /// along with the compiled expression.
+    #[cfg(feature = "regex")]
+    Regex { source: String, regex: regex::Regex },
+}
 
 impl PatternSegment {
+    /// Returns true if the segment is a standalone wildcard, either `*`
+    /// or the multi-level `**`, matching any number of labels.
+    pub(crate) fn is_standalone_wildcard(&self) -> bool {
+        self.as_ref() == "*" || self.is_multi_level_wildcard()
+    }
+
+    /// Constructs a glob segment from a validated label, tokenizing it.
+    pub(crate) fn glob(source: String) -> Self {
+        let tokens = tokenize(&source);
+
+        PatternSegment::Glob { source, tokens }
+    }
+
+    /// Returns true if the segment is `**`, matching any number of
+    /// labels including none.
+    pub(crate) fn is_multi_level_wildcard(&self) -> bool {
+        self.as_ref() == MULTI_LEVEL_WILDCARD
+    }
+
+    /// Returns true if the segment contains no wildcards, character
+    /// classes or regular expressions.
+    ///
+    /// Segments containing escapes are always literal, since the escaped
+    /// characters have no special meaning.
+    pub(crate) fn is_literal(&self) -> bool {
+        match self {
+            PatternSegment::Label(label) => label.contains('\\') || !label.contains('*'),
+            PatternSegment::Glob { .. } => false,
+            #[cfg(feature = "regex")]
+            PatternSegment::Regex { .. } => false,
+        }
+    }
+
+    /// Returns true if the segment is a regular expression, such as `/^dev-[0-9]+$/`
+    pub fn is_regex(&self) -> bool {
+        match self {
+            PatternSegment::Label(_) | PatternSegment::Glob { .. } => false,
+            #[cfg(feature = "regex")]
+            PatternSegment::Regex { .. } => true,
+        }
+    }
+
     /// Returns true if the pattern segment matches the provided domain segment.
+    ///
+    /// Segments are matched against the domain segment in presentation
+    /// format, such that `a\.b` only matches the single label `a\.b`.
     pub fn matches(&self, domain_segment: &DomainSegment) -> bool {
-        if self.0 == domain_segment.as_ref() {
+        let label: &str = domain_segment.as_ref();
+
+        let segment = match self {
+            PatternSegment::Label(segment) => segment,
+            PatternSegment::Glob { source, tokens } => {
+                return glob_matches(source.as_bytes(), tokens, label.as_bytes())
+            }
+            #[cfg(feature = "regex")]
+            PatternSegment::Regex { regex, .. } => return regex.is_match(label),
+        };
+
+        if segment == label || self.is_standalone_wildcard() {
             return true;
         }
 
-        if let Some((head, tail)) = self.0.split_once('*') {
-            return domain_segment.as_ref().starts_with(head)
-                && domain_segment.as_ref().ends_with(tail);
+        if self.is_literal() {
+            return false;
+        }
+
+        if let Some((head, tail)) = segment.split_once('*') {
+            return affix_matches(head, tail, label);
         }
 
         false
     }
 
+    /// Length of the shortest domain segment this pattern segment could match.
+    pub(crate) fn min_match_len(&self) -> usize {
+        if self.is_regex() {
+            return 0;
+        }
+
+        match self {
+            PatternSegment::Label(label) if label.contains('\\') => {
+                DomainSegment::try_from(label.as_str()).map_or(0, |segment| segment.len())
+            }
+            PatternSegment::Label(label) => label.bytes().filter(|c| *c != b'*').count(),
+            PatternSegment::Glob { tokens, .. } => tokens
+                .iter()
+                .filter(|token| **token != GlobToken::Any)
+                .count(),
+            #[cfg(feature = "regex")]
+            PatternSegment::Regex { .. } => 0,
+        }
+    }
+
     // Segments cannot be empty.
     #[allow(clippy::len_without_is_empty)]
     pub fn len(&self) -> usize {
-        self.0.len()
+        self.as_ref().len()
     }
 }
 
@@ -171,12 +683,14 @@ pub enum PatternSegmentError {
     ///
     /// * Not at the beginning of a segment.
     /// * Not at the end of a segment.
-    /// * Not at the 3rd and 4th position *simultaneously* (used for [Punycode encoding](https://en.wikipedia.org/wiki/Punycode))
+    /// * Not at the 3rd and 4th position *simultaneously*, unless the segment starts
+    ///   with `xn--` (used for [Punycode encoding](https://en.wikipedia.org/wiki/Punycode))
     #[error("illegal hyphen at position {0}")]
     IllegalHyphen(usize),
-    /// Segment contains invalid character.
-    #[error("invalid character {0}")]
-    InvalidCharacter(char),
+    /// Segment contains an invalid character, at the given byte
+    /// position within the segment.
+    #[error("invalid character {character:?} at position {position}")]
+    InvalidCharacter { character: char, position: usize },
     /// Domain segment is longer than the permitted 63 characters.
     #[error("pattern too long {0} > 63")]
     TooLong(usize),
@@ -186,16 +700,75 @@ pub enum PatternSegmentError {
     /// Pattern contains more than one wildcard (*) character.
     #[error("patterns can only have one wildcard")]
     MultipleWildcards,
+    /// Character class is empty, unterminated, contains an invalid
+    /// character, or a descending range.
+    #[error("invalid character class")]
+    InvalidCharacterClass,
+    /// Regular expression segment could not be compiled.
+    #[error("invalid regular expression: {0}")]
+    InvalidRegex(String),
+    /// Escape sequence is invalid, or the segment contains wildcards in
+    /// addition to escapes, which is not permitted.
+    #[error("invalid escape sequence {0:?}")]
+    InvalidEscape(String),
 }
 
+impl From<DomainSegmentError> for PatternSegmentError {
+    fn from(value: DomainSegmentError) -> Self {
+        match value {
+            DomainSegmentError::IllegalHyphen(position) => {
+                PatternSegmentError::IllegalHyphen(position)
+            }
+            DomainSegmentError::InvalidCharacter {
+                character,
+                position,
+            } => PatternSegmentError::InvalidCharacter {
+                character,
+                position,
+            },
+            DomainSegmentError::TooLong(length) => PatternSegmentError::TooLong(length),
+            DomainSegmentError::EmptyString => PatternSegmentError::EmptyString,
+            DomainSegmentError::NonStandaloneWildcard => {
+                PatternSegmentError::InvalidEscape(String::from("*"))
+            }
+            DomainSegmentError::InvalidEscape(escape) => PatternSegmentError::InvalidEscape(escape),
+        }
+    }
+}
+
+/// Segment matching any number of labels, including none.
+const MULTI_LEVEL_WILDCARD: &str = "**";
+
 const VALID_CHARACTERS: &str = "_-0123456789abcdefghijklmnopqrstuvwxyz*";
+const VALID_GLOB_CHARACTERS: &str = "_-0123456789abcdefghijklmnopqrstuvwxyz*?[]";
 
-impl TryFrom<&str> for PatternSegment {
-    type Error = PatternSegmentError;
+impl PatternSegment {
+    /// Parses a pattern segment using the given syntax.
+    pub fn parse(value: &str, syntax: PatternSyntax) -> Result<Self, PatternSegmentError> {
+        #[cfg(feature = "regex")]
+        if syntax == PatternSyntax::Regex && is_regex(value) {
+            return match compile_regex(value) {
+                Ok(regex) => Ok(PatternSegment::Regex {
+                    source: value.to_string(),
+                    regex,
+                }),
+                Err(error) => Err(PatternSegmentError::InvalidRegex(error.to_string())),
+            };
+        }
+
+        // Escaped segments are literal labels, stored in the canonical
+        // presentation format of the label.
+        if value.contains('\\') {
+            return Ok(PatternSegment::from(DomainSegment::try_from(value)?));
+        }
 
-    fn try_from(value: &str) -> Result<Self, Self::Error> {
         let value = value.to_ascii_lowercase();
 
+        let valid_characters = match syntax {
+            PatternSyntax::Wildcard => VALID_CHARACTERS,
+            _ => VALID_GLOB_CHARACTERS,
+        };
+
         if value.is_empty() {
             return Err(PatternSegmentError::EmptyString);
         }
@@ -204,8 +777,14 @@ impl TryFrom<&str> for PatternSegment {
             return Err(PatternSegmentError::TooLong(value.len()));
         }
 
-        if let Some(character) = value.chars().find(|c| !VALID_CHARACTERS.contains(*c)) {
-            return Err(PatternSegmentError::InvalidCharacter(character));
+        if let Some((position, character)) = value
+            .char_indices()
+            .find(|(_, c)| !valid_characters.contains(*c))
+        {
+            return Err(PatternSegmentError::InvalidCharacter {
+                character,
+                position,
+            });
         }
 
         if value.starts_with('-') {
@@ -216,27 +795,247 @@ impl TryFrom<&str> for PatternSegment {
             return Err(PatternSegmentError::IllegalHyphen(value.len()));
         }
 
-        if value.get(2..4) == Some("--") {
+        if value.get(2..4) == Some("--") && !value.starts_with("xn--") {
             return Err(PatternSegmentError::IllegalHyphen(3));
         }
 
-        if value.chars().filter(|c| *c == '*').count() > 1 {
+        if syntax == PatternSyntax::Wildcard && value.chars().filter(|c| *c == '*').count() > 1 {
             return Err(PatternSegmentError::MultipleWildcards);
         }
 
-        Ok(PatternSegment(value))
+        if syntax != PatternSyntax::Wildcard {
+            validate_classes(&value)?;
+        }
+
+        if value.contains(['?', '[']) || value.matches('*').count() > 1 {
+            return Ok(PatternSegment::glob(value));
+        }
+
+        Ok(PatternSegment::Label(value))
+    }
+}
+
+impl TryFrom<&str> for PatternSegment {
+    type Error = PatternSegmentError;
+
+    /// Parses the segment using [`PatternSyntax::Glob`], or as a regular
+    /// expression if it is enclosed in slashes and the `regex` feature is
+    /// enabled, such that every segment can be parsed from its [`Display`]
+    /// output.
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        #[cfg(feature = "regex")]
+        if is_regex(value) {
+            return PatternSegment::parse(value, PatternSyntax::Regex);
+        }
+
+        PatternSegment::parse(value, PatternSyntax::Glob)
+    }
+}
+
+/// Returns true if the label starts with `head` and ends with `tail`,
+/// without the two overlapping.
+pub(crate) fn affix_matches(head: &str, tail: &str, label: &str) -> bool {
+    label.len() >= head.len() + tail.len() && label.starts_with(head) && label.ends_with(tail)
+}
+
+/// Returns true if the segment is enclosed in slashes.
+#[cfg(feature = "regex")]
+fn is_regex(segment: &str) -> bool {
+    segment.len() >= 2 && segment.starts_with('/') && segment.ends_with('/')
+}
+
+/// Returns a predicate identifying the dots separating segments.
+///
+/// Dots within regular expressions do not separate segments regardless of
+/// syntax, such that the entire expression is reported as invalid when
+/// regular expressions are not permitted.
+///
+/// Escaped dots outside of regular expressions, such as in `a\.b`, do not
+/// separate segments either.
+fn segment_separator() -> impl FnMut(char) -> bool {
+    let (mut start, mut regex, mut escaped) = (true, false, false);
+
+    move |c| {
+        let separator = c == '.' && !regex && !escaped;
+        escaped = !escaped && !regex && c == '\\';
+
+        // Opening slash at the start of a segment, or the closing one.
+        if c == '/' && (start || regex) {
+            regex = start;
+        }
+
+        start = separator;
+        separator
+    }
+}
+
+/// Compiles the regular expression of a regex segment, which is matched
+/// case-insensitively.
+#[cfg(feature = "regex")]
+fn compile_regex(segment: &str) -> Result<regex::Regex, regex::Error> {
+    regex::RegexBuilder::new(&segment[1..segment.len() - 1])
+        .case_insensitive(true)
+        .build()
+}
+
+/// Single element of a glob pattern segment, see [`PatternSegment::Glob`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GlobToken {
+    /// Matches exactly this character.
+    Literal(u8),
+    /// Matches any single character (`?`).
+    Single,
+    /// Matches any sequence of characters (`*`).
+    Any,
+    /// Matches a single character from the class found between `start`
+    /// and `end` within the segment, excluding brackets.
+    Class { start: usize, end: usize },
+}
+
+/// Splits a pattern segment into glob tokens.
+///
+/// The segment is assumed to have been validated, so unterminated
+/// classes are treated as literals.
+pub(crate) fn tokenize(segment: &str) -> Vec<GlobToken> {
+    let bytes = segment.as_bytes();
+    let mut tokens = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+
+    while i < bytes.len() {
+        match bytes[i] {
+            b'*' => tokens.push(GlobToken::Any),
+            b'?' => tokens.push(GlobToken::Single),
+            b'[' => {
+                if let Some(end) = bytes[i..].iter().position(|c| *c == b']') {
+                    tokens.push(GlobToken::Class {
+                        start: i + 1,
+                        end: i + end,
+                    });
+                    i += end + 1;
+                    continue;
+                }
+
+                tokens.push(GlobToken::Literal(b'['));
+            }
+            c => tokens.push(GlobToken::Literal(c)),
+        }
+
+        i += 1;
+    }
+
+    tokens
+}
+
+/// Ensures all character classes in the segment are terminated, non-empty,
+/// contain only domain segment characters, and use ascending ranges.
+fn validate_classes(segment: &str) -> Result<(), PatternSegmentError> {
+    let mut rest = segment;
+
+    while let Some(start) = rest.find(['[', ']']) {
+        if rest.as_bytes()[start] == b']' {
+            return Err(PatternSegmentError::InvalidCharacterClass);
+        }
+
+        let Some(end) = rest[start..].find(']') else {
+            return Err(PatternSegmentError::InvalidCharacterClass);
+        };
+
+        let class = &rest.as_bytes()[start + 1..start + end];
+
+        if class.is_empty() || class.iter().any(|c| b"*?[".contains(c)) {
+            return Err(PatternSegmentError::InvalidCharacterClass);
+        }
+
+        if class
+            .windows(3)
+            .any(|range| range[1] == b'-' && range[0] > range[2])
+        {
+            return Err(PatternSegmentError::InvalidCharacterClass);
+        }
+
+        rest = &rest[start + end + 1..];
+    }
+
+    Ok(())
+}
+
+fn class_matches(class: &[u8], character: u8) -> bool {
+    let mut i = 0;
+
+    while i < class.len() {
+        if i + 2 < class.len() && class[i + 1] == b'-' {
+            if (class[i]..=class[i + 2]).contains(&character) {
+                return true;
+            }
+
+            i += 3;
+        } else {
+            if class[i] == character {
+                return true;
+            }
+
+            i += 1;
+        }
+    }
+
+    false
+}
+
+/// Matches the input against the tokens of `segment`, backtracking to
+/// the most recent `*` on mismatch.
+pub(crate) fn glob_matches(segment: &[u8], tokens: &[GlobToken], input: &[u8]) -> bool {
+    let (mut t, mut i) = (0, 0);
+    let mut backtrack = None;
+
+    while i < input.len() {
+        match tokens.get(t) {
+            Some(GlobToken::Any) => {
+                backtrack = Some((t, i));
+                t += 1;
+                continue;
+            }
+            Some(GlobToken::Single) => {
+                t += 1;
+                i += 1;
+                continue;
+            }
+            Some(GlobToken::Literal(c)) if *c == input[i] => {
+                t += 1;
+                i += 1;
+                continue;
+            }
+            Some(GlobToken::Class { start, end })
+                if class_matches(&segment[*start..*end], input[i]) =>
+            {
+                t += 1;
+                i += 1;
+                continue;
+            }
+            _ => {}
+        }
+
+        match backtrack {
+            Some((star, consumed)) => {
+                backtrack = Some((star, consumed + 1));
+                t = star + 1;
+                i = consumed + 1;
+            }
+            None => return false,
+        }
     }
+
+    tokens[t..].iter().all(|token| *token == GlobToken::Any)
 }
 
 impl From<DomainSegment> for PatternSegment {
     fn from(value: DomainSegment) -> Self {
-        PatternSegment(value.to_string())
+        PatternSegment::Label(value.to_string())
     }
 }
 
 impl From<&DomainSegment> for PatternSegment {
     fn from(value: &DomainSegment) -> Self {
-        PatternSegment(value.to_string())
+        PatternSegment::Label(value.to_string())
     }
 }
 
@@ -250,21 +1049,92 @@ impl TryFrom<String> for PatternSegment {
 
 impl Display for PatternSegment {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_str(&self.0)
+        f.write_str(self.as_ref())
+    }
+}
+
+impl Debug for PatternSegment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("PatternSegment")
+            .field(&self.as_ref())
+            .finish()
     }
 }
 
 impl AsRef<str> for PatternSegment {
     fn as_ref(&self) -> &str {
-        self.0.as_str()
+        match self {
+            PatternSegment::Label(label) => label,
+            PatternSegment::Glob { source, .. } => source,
+            #[cfg(feature = "regex")]
+            PatternSegment::Regex { source, .. } => source,
+        }
+    }
+}
+
+impl PartialEq for PatternSegment {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_ref() == other.as_ref()
+    }
+}
+
+impl Eq for PatternSegment {}
+
+impl PartialOrd for PatternSegment {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for PatternSegment {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.as_ref().cmp(other.as_ref())
+    }
+}
+
+impl std::hash::Hash for PatternSegment {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.as_ref().hash(state)
+    }
+}
+
+#[cfg(feature = "valuable")]
+impl valuable::Valuable for Pattern {
+    fn as_value(&self) -> valuable::Value<'_> {
+        valuable::Value::Listable(self)
+    }
+
+    fn visit(&self, visit: &mut dyn valuable::Visit) {
+        PatternSegment::visit_slice(&self.0, visit)
+    }
+}
+
+#[cfg(feature = "valuable")]
+impl valuable::Listable for Pattern {
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.0.len(), Some(self.0.len()))
+    }
+}
+
+#[cfg(feature = "valuable")]
+impl valuable::Valuable for PatternSegment {
+    fn as_value(&self) -> valuable::Value<'_> {
+        valuable::Value::String(self.as_ref())
+    }
+
+    fn visit(&self, visit: &mut dyn valuable::Visit) {
+        visit.visit_value(self.as_value())
     }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::{
-        error::PatternSegmentError, pattern::PatternSegment, segment::DomainSegment,
-        FullyQualifiedDomainName, Pattern,
+        error::{PatternError, PatternSegmentError},
+        pattern::PatternSegment,
+        pattern::PatternSyntax,
+        segment::DomainSegment,
+        FullyQualifiedDomainName, OriginBoundPattern, Pattern,
     };
 
     #[test]
@@ -305,9 +1175,13 @@ mod tests {
     #[test]
     fn multiple_wildcards() {
         assert_eq!(
-            PatternSegment::try_from("*amp*"),
+            PatternSegment::parse("*amp*", PatternSyntax::Wildcard),
             Err(PatternSegmentError::MultipleWildcards)
         );
+        assert!(matches!(
+            PatternSegment::try_from("*amp*"),
+            Ok(PatternSegment::Glob { .. })
+        ));
     }
 
     #[test]
@@ -319,7 +1193,7 @@ mod tests {
 
     #[test]
     fn longer_pattern_than_domain() {
-        assert!(!Pattern::try_from("*.*.example.org")
+        assert!(!Pattern::try_from("*.dev.example.org")
             .unwrap()
             .matches(&FullyQualifiedDomainName::try_from("www.example.org.").unwrap()));
     }
@@ -357,6 +1231,373 @@ mod tests {
         );
     }
 
+    #[test]
+    fn total_length() {
+        let label = "a".repeat(63);
+        let long = [label.as_str(); 4].join(".");
+
+        assert_eq!(
+            Pattern::try_from(long.as_str()),
+            Err(PatternError::TooLong(255))
+        );
+
+        // Wildcards must match at least one character.
+        let pattern =
+            Pattern::try_from(format!("*.{}", [label.as_str(); 3].join(".")).as_str()).unwrap();
+        assert_eq!(pattern.min_match_len(), 193);
+
+        assert_eq!(
+            pattern
+                .try_with_origin(&FullyQualifiedDomainName::try_from(format!("{label}.")).unwrap()),
+            Err(PatternError::TooLong(257))
+        );
+    }
+
+    #[test]
+    fn structural_errors() {
+        assert_eq!(
+            Pattern::try_from("www.ex@mple.org"),
+            Err(PatternError::SegmentError {
+                index: 1,
+                offset: 4,
+                segment: String::from("ex@mple"),
+                error: PatternSegmentError::InvalidCharacter {
+                    character: '@',
+                    position: 2
+                }
+            })
+        );
+        assert_eq!(
+            Pattern::try_from("www.*.org"),
+            Err(PatternError::NonLeadingWildcard(1))
+        );
+        assert_eq!(
+            Pattern::try_from("www.@.org"),
+            Err(PatternError::MisplacedOrigin(1))
+        );
+
+        assert_eq!(Pattern::try_from("www.@"), Pattern::try_from("www"));
+        assert_eq!(Pattern::try_from("@"), Ok(Pattern::origin()));
+        assert_eq!(Pattern::origin().to_string(), "@");
+
+        let apex = Pattern::try_from("@")
+            .unwrap()
+            .with_origin(&FullyQualifiedDomainName::try_from("example.org.").unwrap());
+        assert!(apex.matches(&FullyQualifiedDomainName::try_from("example.org.").unwrap()));
+        assert!(!apex.matches(&FullyQualifiedDomainName::try_from("www.example.org.").unwrap()));
+    }
+
+    #[test]
+    fn exact_conversion() {
+        let fqdn = FullyQualifiedDomainName::try_from("www.example.org.").unwrap();
+
+        let exact = Pattern::exact(&fqdn);
+        assert!(exact.is_exact());
+        assert!(exact.matches(&fqdn));
+        assert!(!exact.matches(&FullyQualifiedDomainName::try_from("api.example.org.").unwrap()));
+        assert_eq!(FullyQualifiedDomainName::try_from(exact), Ok(fqdn));
+
+        assert_eq!(
+            FullyQualifiedDomainName::try_from(Pattern::try_from("www.ex*.org").unwrap()),
+            Err(PatternError::NotExact(1))
+        );
+        assert_eq!(
+            FullyQualifiedDomainName::try_from(Pattern::origin()),
+            Ok(FullyQualifiedDomainName::default())
+        );
+    }
+
+    #[test]
+    fn escaped_labels() {
+        for name in [
+            "a\\.b.example.org.",
+            "a\\032b.example.org.",
+            "a\\\\b.example.org.",
+            "a\\255.example.org.",
+        ] {
+            let fqdn = FullyQualifiedDomainName::try_from(name).unwrap();
+            let exact = Pattern::exact(&fqdn);
+
+            assert!(exact.is_exact());
+            assert!(exact.matches(&fqdn), "{exact:?} does not match {fqdn:?}");
+            assert!(exact.compile().matches(&fqdn));
+            assert!(crate::PatternSet::new()
+                .include(exact.clone())
+                .matches(&fqdn));
+
+            assert_eq!(exact.to_string(), name);
+            assert_eq!(Pattern::try_from(exact.to_string()), Ok(exact.clone()));
+            assert_eq!(FullyQualifiedDomainName::try_from(&exact), Ok(fqdn));
+        }
+
+        let pattern = Pattern::try_from("*.a\\.b.example.org").unwrap();
+        assert!(
+            pattern.matches(&FullyQualifiedDomainName::try_from("www.a\\.b.example.org.").unwrap())
+        );
+        assert!(
+            !pattern.matches(&FullyQualifiedDomainName::try_from("www.a.b.example.org.").unwrap())
+        );
+
+        assert_eq!(
+            PatternSegment::try_from("a*\\.b"),
+            Err(PatternSegmentError::InvalidEscape(String::from("*")))
+        );
+    }
+
+    #[test]
+    fn apex_wildcard() {
+        let apex = FullyQualifiedDomainName::try_from("example.org.").unwrap();
+        let www = FullyQualifiedDomainName::try_from("www.example.org.").unwrap();
+
+        let wildcard = Pattern::try_from("*.example.org.").unwrap();
+        assert!(!wildcard.matches(&apex));
+        assert!(wildcard.matches(&www));
+
+        let inclusive = wildcard.clone().matches_apex(true);
+        assert_eq!(Pattern::try_from("**.example.org."), Ok(inclusive.clone()));
+        assert_eq!(inclusive.to_string(), "**.example.org.");
+        assert_eq!(inclusive.min_match_len(), 11);
+        assert_eq!(inclusive.clone().matches_apex(false), wildcard);
+
+        for pattern in [
+            inclusive.clone(),
+            Pattern::try_from("**").unwrap().with_origin(&apex),
+        ] {
+            assert!(pattern.matches(&apex));
+            assert!(pattern.matches(&www));
+            assert!(!pattern.matches(&FullyQualifiedDomainName::try_from("org.").unwrap()));
+
+            let bound = OriginBoundPattern::from(pattern);
+            assert!(bound.matches(&apex));
+            assert!(bound.matches(&www));
+            assert!(!bound.matches(&FullyQualifiedDomainName::try_from("org.").unwrap()));
+        }
+
+        assert_eq!(
+            Pattern::try_from("www").unwrap().matches_apex(true),
+            Pattern::try_from("www").unwrap()
+        );
+    }
+
+    #[test]
+    fn multi_level_wildcard() {
+        let pattern = Pattern::try_from("api.**.example.org").unwrap();
+        assert_eq!(pattern.to_string(), "api.**.example.org.");
+        assert_eq!(pattern.min_match_len(), 15);
+
+        for (domain, matches) in [
+            ("api.example.org.", true),
+            ("api.eu.example.org.", true),
+            ("api.eu.prod.example.org.", true),
+            ("www.api.eu.example.org.", false),
+            ("api.example.com.", false),
+            ("example.org.", false),
+            ("api.org.", false),
+        ] {
+            let domain = FullyQualifiedDomainName::try_from(domain).unwrap();
+
+            assert_eq!(pattern.matches(&domain), matches, "{domain}");
+            assert_eq!(
+                OriginBoundPattern::from(pattern.clone()).matches(&domain),
+                matches
+            );
+        }
+
+        let pattern = Pattern::try_from("*.svc.**.cluster.**.local").unwrap();
+        assert!(
+            pattern.matches(&FullyQualifiedDomainName::try_from("db.svc.cluster.local.").unwrap())
+        );
+        assert!(pattern.matches(
+            &FullyQualifiedDomainName::try_from("db.ns.svc.a.b.cluster.c.local.").unwrap()
+        ));
+        assert!(
+            !pattern.matches(&FullyQualifiedDomainName::try_from("svc.cluster.local.").unwrap())
+        );
+        assert!(!pattern.matches(
+            &FullyQualifiedDomainName::try_from("db.svc.cluster.local.example.").unwrap()
+        ));
+
+        assert_eq!(
+            Pattern::try_from("api.*.example.org"),
+            Err(PatternError::NonLeadingWildcard(1))
+        );
+    }
+
+    #[test]
+    fn bound_pattern() {
+        let origin = FullyQualifiedDomainName::try_from("example.org.").unwrap();
+
+        for pattern in ["*", "dev*", "www", "*.dev", "www.dev", "**", "www.**"] {
+            let pattern = Pattern::try_from(pattern).unwrap();
+            let bound = pattern.bind(&origin);
+
+            assert_eq!(bound.to_pattern(), pattern.with_origin(&origin));
+
+            for domain in [
+                "example.org.",
+                "www.example.org.",
+                "dev-1.example.org.",
+                "a.b.dev.example.org.",
+                "www.example.com.",
+            ] {
+                let domain = FullyQualifiedDomainName::try_from(domain).unwrap();
+
+                assert_eq!(
+                    bound.matches(&domain),
+                    pattern.with_origin(&origin).matches(&domain)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn glob_syntax() {
+        let segment = |value| PatternSegment::parse(value, PatternSyntax::Glob).unwrap();
+        let domain = |value| DomainSegment::try_from(value).unwrap();
+
+        assert!(segment("dev-[0-9]*").matches(&domain("dev-1")));
+        assert!(segment("dev-[0-9]*").matches(&domain("dev-42-feature")));
+        assert!(!segment("dev-[0-9]*").matches(&domain("dev-feature")));
+
+        assert!(segment("v?").matches(&domain("v2")));
+        assert!(!segment("v?").matches(&domain("v")));
+        assert!(!segment("v?").matches(&domain("v10")));
+
+        assert!(segment("*-[a-c_]-*").matches(&domain("pr-b-123")));
+        assert!(segment("*-[a-c_]-*").matches(&domain("pr-_-123")));
+        assert!(!segment("*-[a-c_]-*").matches(&domain("pr-d-123")));
+
+        // Existing wildcard semantics are unchanged.
+        assert!(segment("ex*le").matches(&domain("example")));
+        assert_eq!(segment("dev-[0-9]*").min_match_len(), 5);
+
+        assert_eq!(
+            PatternSegment::parse("dev-[0-9]", PatternSyntax::Wildcard),
+            Err(PatternSegmentError::InvalidCharacter {
+                character: '[',
+                position: 4
+            })
+        );
+
+        for invalid in ["dev-[0-9", "dev-]", "dev-[]", "dev-[9-0]", "dev-[*]"] {
+            assert_eq!(
+                PatternSegment::parse(invalid, PatternSyntax::Glob),
+                Err(PatternSegmentError::InvalidCharacterClass)
+            );
+        }
+
+        let pattern = Pattern::parse("dev-[0-9]*.example.org", PatternSyntax::Glob).unwrap();
+        assert!(pattern.matches(&FullyQualifiedDomainName::try_from("dev-7.example.org.").unwrap()));
+        assert!(
+            !pattern.matches(&FullyQualifiedDomainName::try_from("prod-7.example.org.").unwrap())
+        );
+
+        // Globs survive being written out and read back in.
+        assert_eq!(Pattern::try_from(pattern.to_string()), Ok(pattern.clone()));
+
+        #[cfg(feature = "serde")]
+        {
+            let yaml = serde_yaml::to_string(&pattern).unwrap();
+            let parsed: Pattern = serde_yaml::from_str(&yaml).unwrap();
+            assert_eq!(parsed, pattern);
+            assert!(matches!(
+                parsed.iter().next(),
+                Some(PatternSegment::Glob { .. })
+            ));
+            assert!(
+                parsed.matches(&FullyQualifiedDomainName::try_from("dev-7.example.org.").unwrap())
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "regex")]
+    fn regex_syntax() {
+        let pattern = Pattern::parse(r"/^dev-\d+$/.example.org", PatternSyntax::Regex).unwrap();
+        assert_eq!(pattern.to_string(), r"/^dev-\d+$/.example.org.");
+        assert_eq!(
+            format!("{:?}", pattern.iter().next().unwrap()),
+            r#"PatternSegment("/^dev-\\d+$/")"#
+        );
+        assert_eq!(pattern.min_match_len(), 13);
+
+        for (domain, matches) in [
+            ("dev-1.example.org.", true),
+            ("DEV-42.example.org.", true),
+            ("dev-feature.example.org.", false),
+            ("www.dev-1.example.org.", false),
+        ] {
+            let domain = FullyQualifiedDomainName::try_from(domain).unwrap();
+            assert_eq!(pattern.matches(&domain), matches, "{domain}");
+        }
+
+        // Dots within expressions do not separate segments.
+        let pattern = Pattern::parse("/^a.c$/.dev*.org", PatternSyntax::Regex).unwrap();
+        assert_eq!(pattern.iter().count(), 3);
+        assert!(pattern.matches(&FullyQualifiedDomainName::try_from("abc.dev-1.org.").unwrap()));
+
+        assert!(matches!(
+            PatternSegment::parse("/dev-(/", PatternSyntax::Regex),
+            Err(PatternSegmentError::InvalidRegex(_))
+        ));
+        assert_eq!(
+            Pattern::parse("/^dev.*$/.org", PatternSyntax::Glob),
+            Err(PatternError::SegmentError {
+                index: 0,
+                offset: 0,
+                segment: String::from("/^dev.*$/"),
+                error: PatternSegmentError::InvalidCharacter {
+                    character: '/',
+                    position: 0
+                }
+            })
+        );
+
+        // Regular expressions survive being written out and read back in.
+        let pattern = Pattern::parse(r"/^dev-\d+$/.example.org", PatternSyntax::Regex).unwrap();
+        assert_eq!(Pattern::try_from(pattern.to_string()), Ok(pattern.clone()));
+
+        #[cfg(feature = "serde")]
+        {
+            let yaml = serde_yaml::to_string(&pattern).unwrap();
+            let parsed: Pattern = serde_yaml::from_str(&yaml).unwrap();
+            assert!(
+                parsed.matches(&FullyQualifiedDomainName::try_from("dev-7.example.org.").unwrap())
+            );
+        }
+    }
+
+    #[test]
+    fn wildcard_fqdn() {
+        let origin = FullyQualifiedDomainName::try_from("example.org.").unwrap();
+        let wildcard = FullyQualifiedDomainName::try_from("*.dev.example.org.").unwrap();
+
+        assert_eq!(
+            Pattern::try_from("*.dev")
+                .unwrap()
+                .to_wildcard_fqdn(&origin),
+            Some(wildcard.clone())
+        );
+
+        for pattern in ["dev", "dev*", "*.dev*", "www.dev"] {
+            assert_eq!(
+                Pattern::try_from(pattern)
+                    .unwrap()
+                    .to_wildcard_fqdn(&origin),
+                None
+            );
+        }
+
+        let pattern = Pattern::from_wildcard_fqdn(&wildcard).unwrap();
+        assert_eq!(pattern, Pattern::try_from("*.dev.example.org.").unwrap());
+        assert_eq!(
+            pattern.to_wildcard_fqdn(&FullyQualifiedDomainName::default()),
+            Some(wildcard)
+        );
+
+        assert_eq!(Pattern::from_wildcard_fqdn(&origin), None);
+    }
+
     #[test]
     fn origin_insertion() {
         let pattern = Pattern::try_from("example").unwrap();
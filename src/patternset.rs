@@ -0,0 +1,497 @@
+use std::{collections::BTreeMap, fmt::Display, str::FromStr};
+
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{error::PatternError, FullyQualifiedDomainName, Pattern};
+
+/// Combination of [`Pattern`]s, matching domains which match any of the
+/// included patterns, but none of the excluded ones.
+///
+/// Besides the structured form, sets can be parsed from a comma-separated
+/// list of patterns, where exclusions are prefixed with `!`:
+///
+/// ```text
+/// *.example.org,!internal.example.org
+/// ```
+///
+/// Patterns are indexed by their trailing literal segments, so matching
+/// a domain only evaluates patterns which share its suffix.
+///
+/// When serialized, patterns are ordered from most to least specific,
+/// and then lexicographically, such that the output does not depend on
+/// the order of insertion. See [`PatternSet::preserve_order`], which
+/// only affects serialization, and is ignored when comparing sets.
+#[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub struct PatternSet {
+    /// Domains must match at least one of these patterns.
+    #[cfg_attr(feature = "serde", serde(default))]
+    include: Patterns,
+    /// Domains must not match any of these patterns.
+    #[cfg_attr(feature = "serde", serde(default))]
+    exclude: Patterns,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    preserve_order: bool,
+}
+
+impl PatternSet {
+    /// Constructs an empty set, which matches no domains.
+    pub fn new() -> Self {
+        PatternSet::default()
+    }
+
+    /// Adds a pattern which domains may match.
+    pub fn include(mut self, pattern: Pattern) -> Self {
+        self.include.insert(pattern);
+        self
+    }
+
+    /// Adds a pattern which domains must not match.
+    pub fn exclude(mut self, pattern: Pattern) -> Self {
+        self.exclude.insert(pattern);
+        self
+    }
+
+    /// Serializes patterns in order of insertion, rather than by
+    /// specificity, such that deserializing and serializing a set
+    /// reproduces the original order.
+    pub fn preserve_order(mut self, preserve: bool) -> Self {
+        self.preserve_order = preserve;
+        self
+    }
+
+    /// Patterns which domains may match, in order of insertion.
+    pub fn included(&self) -> &[Pattern] {
+        &self.include.patterns
+    }
+
+    /// Patterns which domains must not match, in order of insertion.
+    pub fn excluded(&self) -> &[Pattern] {
+        &self.exclude.patterns
+    }
+
+    /// Returns true if the domain matches any included pattern,
+    /// and none of the excluded ones.
+    pub fn matches(&self, domain: &FullyQualifiedDomainName) -> bool {
+        self.best_match(domain).is_some()
+    }
+
+    /// Returns the most specific included pattern matching the domain,
+    /// unless the domain matches any of the excluded patterns.
+    ///
+    /// Patterns with more literal segments are more specific, followed by
+    /// patterns with more literal characters, such that `www.example.org`
+    /// is preferred over `w*.example.org`, which in turn is preferred over
+    /// `*.example.org`. Ties are resolved in favor of the earliest pattern.
+    pub fn best_match(&self, domain: &FullyQualifiedDomainName) -> Option<&Pattern> {
+        if !self.exclude.matching(domain).is_empty() {
+            return None;
+        }
+
+        self.include
+            .matching(domain)
+            .into_iter()
+            .min_by_key(|(index, pattern)| (std::cmp::Reverse(specificity(pattern)), *index))
+            .map(|(_, pattern)| pattern)
+    }
+
+    /// Asserts that all patterns are valid, and that the index used for
+    /// matching is consistent with them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any pattern is invalid, see [`Pattern::debug_validate`].
+    pub fn debug_validate(&self) {
+        for patterns in [&self.include, &self.exclude] {
+            for pattern in &patterns.patterns {
+                pattern.debug_validate();
+            }
+
+            assert_eq!(
+                patterns,
+                &patterns.patterns.iter().cloned().collect::<Patterns>(),
+                "pattern index is out of sync"
+            );
+        }
+    }
+
+    /// Resolves all patterns of the set against the origin.
+    ///
+    /// See [`Pattern::with_origin`].
+    pub fn with_origin(&self, origin: &FullyQualifiedDomainName) -> PatternSet {
+        PatternSet {
+            include: self
+                .include
+                .patterns
+                .iter()
+                .map(|pattern| pattern.with_origin(origin))
+                .collect(),
+            exclude: self
+                .exclude
+                .patterns
+                .iter()
+                .map(|pattern| pattern.with_origin(origin))
+                .collect(),
+            preserve_order: self.preserve_order,
+        }
+    }
+
+    /// Patterns in the order they are serialized in.
+    #[cfg(feature = "serde")]
+    fn serialization_order<'a>(&self, patterns: &'a [Pattern]) -> Vec<&'a Pattern> {
+        let mut ordered: Vec<&Pattern> = patterns.iter().collect();
+
+        if !self.preserve_order {
+            ordered.sort_by_cached_key(|pattern| {
+                (std::cmp::Reverse(specificity(pattern)), pattern.to_string())
+            });
+        }
+
+        ordered
+    }
+}
+
+impl PartialEq for PatternSet {
+    fn eq(&self, other: &Self) -> bool {
+        self.include == other.include && self.exclude == other.exclude
+    }
+}
+
+impl Eq for PatternSet {}
+
+impl PartialOrd for PatternSet {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for PatternSet {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        (&self.include, &self.exclude).cmp(&(&other.include, &other.exclude))
+    }
+}
+
+impl std::hash::Hash for PatternSet {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.include.hash(state);
+        self.exclude.hash(state);
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for PatternSet {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        #[derive(Serialize)]
+        struct PatternSetRepr<'a> {
+            include: Vec<&'a Pattern>,
+            #[serde(skip_serializing_if = "Vec::is_empty")]
+            exclude: Vec<&'a Pattern>,
+        }
+
+        PatternSetRepr {
+            include: self.serialization_order(self.included()),
+            exclude: self.serialization_order(self.excluded()),
+        }
+        .serialize(serializer)
+    }
+}
+
+/// Number of literal segments, and number of literal characters.
+fn specificity(pattern: &Pattern) -> (usize, usize) {
+    let literal_segments = pattern
+        .iter()
+        .filter(|segment| segment.is_literal())
+        .count();
+
+    let literal_characters = pattern
+        .iter()
+        .filter(|segment| !segment.is_regex())
+        .map(|segment| {
+            let segment = segment.as_ref();
+            // Characters inside classes match variably, so only count
+            // characters outside of them.
+            let mut in_class = false;
+            segment
+                .chars()
+                .filter(|character| match character {
+                    '[' => {
+                        in_class = true;
+                        false
+                    }
+                    ']' => {
+                        in_class = false;
+                        false
+                    }
+                    '*' | '?' => false,
+                    _ => !in_class,
+                })
+                .count()
+        })
+        .sum();
+
+    (literal_segments, literal_characters)
+}
+
+/// Prefix tree over the reversed literal segments of patterns.
+///
+/// Each pattern is stored at the node reached by following its trailing
+/// literal segments, either as `terminal` if all of its segments are
+/// literal, or in `candidates` if the remaining segments are not.
+#[derive(Default, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct PatternTree {
+    terminal: Vec<usize>,
+    candidates: Vec<usize>,
+    children: BTreeMap<String, PatternTree>,
+}
+
+/// Patterns, along with their [`PatternTree`] index.
+#[derive(Default, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct Patterns {
+    patterns: Vec<Pattern>,
+    tree: PatternTree,
+}
+
+impl Patterns {
+    fn insert(&mut self, pattern: Pattern) {
+        let index = self.patterns.len();
+        let segments: Vec<_> = pattern.iter().collect();
+
+        let mut node = &mut self.tree;
+        let mut remaining = segments.len();
+        while let Some(segment) = remaining
+            .checked_sub(1)
+            .map(|last| segments[last])
+            .filter(|segment| segment.is_literal())
+        {
+            node = node.children.entry(segment.to_string()).or_default();
+            remaining -= 1;
+        }
+
+        if remaining == 0 {
+            node.terminal.push(index);
+        } else {
+            node.candidates.push(index);
+        }
+
+        self.patterns.push(pattern);
+    }
+
+    /// Returns all patterns matching the domain, along with their index.
+    fn matching(&self, domain: &FullyQualifiedDomainName) -> Vec<(usize, &Pattern)> {
+        let mut indices: Vec<usize> = Vec::new();
+        let mut node = Some(&self.tree);
+        let mut labels = domain.iter().rev();
+
+        while let Some(current) = node {
+            indices.extend(&current.candidates);
+
+            match labels.next() {
+                Some(label) => node = current.children.get(label.as_presentation().as_ref()),
+                None => {
+                    indices.extend(&current.terminal);
+                    break;
+                }
+            }
+        }
+
+        indices
+            .into_iter()
+            .map(|index| (index, &self.patterns[index]))
+            .filter(|(_, pattern)| pattern.matches(domain))
+            .collect()
+    }
+}
+
+impl FromIterator<Pattern> for Patterns {
+    fn from_iter<T: IntoIterator<Item = Pattern>>(iter: T) -> Self {
+        let mut patterns = Patterns::default();
+        for pattern in iter {
+            patterns.insert(pattern);
+        }
+
+        patterns
+    }
+}
+
+impl From<Vec<Pattern>> for Patterns {
+    fn from(value: Vec<Pattern>) -> Self {
+        value.into_iter().collect()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Patterns {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        crate::list::deserialize(deserializer).map(Patterns::from)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl JsonSchema for Patterns {
+    fn schema_name() -> String {
+        <Vec<Pattern> as JsonSchema>::schema_name()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        <Vec<Pattern> as JsonSchema>::json_schema(gen)
+    }
+}
+
+impl TryFrom<&str> for PatternSet {
+    type Error = PatternError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut set = PatternSet::new();
+
+        for pattern in value.split(',').map(str::trim) {
+            match pattern.strip_prefix('!') {
+                Some(excluded) => set.exclude.insert(Pattern::try_from(excluded)?),
+                None => set.include.insert(Pattern::try_from(pattern)?),
+            }
+        }
+
+        Ok(set)
+    }
+}
+
+impl TryFrom<String> for PatternSet {
+    type Error = PatternError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+
+impl FromStr for PatternSet {
+    type Err = PatternError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl Display for PatternSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let included = self.included().iter().map(|pattern| pattern.to_string());
+        let excluded = self.excluded().iter().map(|pattern| format!("!{pattern}"));
+
+        f.write_str(&included.chain(excluded).collect::<Vec<_>>().join(","))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{fqdn, FullyQualifiedDomainName, Pattern, PatternSet};
+
+    #[test]
+    fn exclusions() {
+        let set: PatternSet = "*.example.org,!internal.example.org,!*.internal.example.org"
+            .parse()
+            .unwrap();
+
+        assert!(set.matches(&fqdn!("www.example.org.")));
+        assert!(!set.matches(&fqdn!("internal.example.org.")));
+        assert!(!set.matches(&fqdn!("db.internal.example.org.")));
+        assert!(!set.matches(&fqdn!("example.com.")));
+
+        assert_eq!(
+            set.to_string(),
+            "*.example.org.,!internal.example.org.,!*.internal.example.org."
+        );
+
+        assert!(!PatternSet::new().matches(&fqdn!("example.org.")));
+    }
+
+    fn example_set() -> PatternSet {
+        PatternSet::new()
+            .include(Pattern::try_from("*").unwrap())
+            .exclude(Pattern::try_from("internal").unwrap())
+            .with_origin(&fqdn!("example.org."))
+    }
+
+    #[test]
+    fn builder() {
+        let set = example_set();
+
+        assert!(set.matches(&fqdn!("www.example.org.")));
+        assert!(!set.matches(&fqdn!("internal.example.org.")));
+
+        // Only affects serialization, so sets remain equal.
+        assert_eq!(set.clone().preserve_order(true), set);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let set = example_set();
+
+        let yaml = serde_yaml::to_string(&set).unwrap();
+        assert_eq!(
+            yaml,
+            "include:\n- '*.example.org.'\nexclude:\n- internal.example.org.\n"
+        );
+        let deserialized = serde_yaml::from_str::<PatternSet>(&yaml).unwrap();
+        deserialized.debug_validate();
+        assert_eq!(deserialized, set);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn stable_serialization() {
+        let forward: PatternSet = "*.example.org,www.example.org,api.example.org,w*.example.org"
+            .parse()
+            .unwrap();
+        let backward: PatternSet = "w*.example.org,api.example.org,www.example.org,*.example.org"
+            .parse()
+            .unwrap();
+
+        let yaml = serde_yaml::to_string(&forward).unwrap();
+        assert_eq!(yaml, serde_yaml::to_string(&backward).unwrap());
+        assert_eq!(
+            yaml,
+            "include:\n- api.example.org.\n- www.example.org.\n- w*.example.org.\n- '*.example.org.'\n"
+        );
+
+        assert_eq!(
+            serde_yaml::to_string(&backward.preserve_order(true)).unwrap(),
+            "include:\n- w*.example.org.\n- api.example.org.\n- www.example.org.\n- '*.example.org.'\n"
+        );
+    }
+
+    #[test]
+    fn best_match() {
+        let set: PatternSet = "*.example.org,w*.example.org,www.example.org,*,!*.example.com"
+            .parse()
+            .unwrap();
+
+        let best = |name: &str| {
+            set.best_match(&FullyQualifiedDomainName::try_from(name).unwrap())
+                .map(|pattern| pattern.to_string())
+        };
+
+        assert_eq!(
+            best("www.example.org."),
+            Some(String::from("www.example.org."))
+        );
+        assert_eq!(
+            best("web.example.org."),
+            Some(String::from("w*.example.org."))
+        );
+        assert_eq!(
+            best("api.example.org."),
+            Some(String::from("*.example.org."))
+        );
+        assert_eq!(best("example.net."), Some(String::from("*.")));
+        assert_eq!(best("www.example.com."), None);
+    }
+}
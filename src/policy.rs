@@ -0,0 +1,330 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use thiserror::Error;
+
+use crate::{FullyQualifiedDomainName, PatternSet, Record, Type, Zone};
+
+/// Policy metadata attached to a record [`Type`] through a [`TypeRegistry`].
+///
+/// Fields left as [`None`] are not constrained by the policy.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TypePolicy {
+    /// TTL applied to records of this type which do not specify one.
+    pub default_ttl: Option<u32>,
+    /// Maximum number of records in a single record set of this type.
+    pub max_rrset_size: Option<usize>,
+    /// Overrides whether records of this type are allowed at the zone apex.
+    pub allowed_at_apex: Option<bool>,
+}
+
+impl TypePolicy {
+    /// Returns a policy with the fields of `self`, falling back to
+    /// the fields of `fallback` where `self` does not specify them.
+    pub fn or(self, fallback: TypePolicy) -> TypePolicy {
+        TypePolicy {
+            default_ttl: self.default_ttl.or(fallback.default_ttl),
+            max_rrset_size: self.max_rrset_size.or(fallback.max_rrset_size),
+            allowed_at_apex: self.allowed_at_apex.or(fallback.allowed_at_apex),
+        }
+    }
+}
+
+/// Registry of per-[`Type`] policy metadata.
+///
+/// Policies registered for a specific type take precedence over the
+/// registry-wide default policy, field by field.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TypeRegistry {
+    default: TypePolicy,
+    policies: BTreeMap<Type, TypePolicy>,
+}
+
+impl TypeRegistry {
+    /// Constructs an empty registry, which imposes no policies.
+    pub fn new() -> Self {
+        TypeRegistry::default()
+    }
+
+    /// Sets the policy applied to all types, unless overridden.
+    pub fn with_default(mut self, policy: TypePolicy) -> Self {
+        self.default = policy;
+        self
+    }
+
+    /// Registers the policy for the given type, replacing any previous one.
+    pub fn with(mut self, r#type: Type, policy: TypePolicy) -> Self {
+        self.insert(r#type, policy);
+        self
+    }
+
+    /// Registers the policy for the given type, returning the policy it replaced.
+    pub fn insert(&mut self, r#type: Type, policy: TypePolicy) -> Option<TypePolicy> {
+        self.policies.insert(r#type, policy)
+    }
+
+    /// Removes the policy registered for the given type.
+    pub fn remove(&mut self, r#type: Type) -> Option<TypePolicy> {
+        self.policies.remove(&r#type)
+    }
+
+    /// Returns the effective policy for the given type.
+    pub fn get(&self, r#type: Type) -> TypePolicy {
+        self.policies
+            .get(&r#type)
+            .map_or(self.default, |policy| policy.or(self.default))
+    }
+
+    /// Default TTL for records of the given type.
+    pub fn default_ttl(&self, r#type: Type) -> Option<u32> {
+        self.get(r#type).default_ttl
+    }
+
+    /// Maximum record set size for records of the given type.
+    pub fn max_rrset_size(&self, r#type: Type) -> Option<usize> {
+        self.get(r#type).max_rrset_size
+    }
+
+    /// Whether records of the given type are allowed at the zone apex,
+    /// if the registry overrides it.
+    pub fn allowed_at_apex(&self, r#type: Type) -> Option<bool> {
+        self.get(r#type).allowed_at_apex
+    }
+}
+
+/// Violation of a [`ZonePolicy`].
+#[derive(Error, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PolicyViolation {
+    /// The zone contains more records than permitted.
+    #[error("zone contains {count} records, exceeding the maximum of {max}")]
+    TooManyRecords { count: usize, max: usize },
+    /// A record set contains more records than permitted.
+    #[error("{fqdn} {} record set contains {count} records, exceeding the maximum of {max}", .r#type)]
+    RecordSetTooLarge {
+        fqdn: FullyQualifiedDomainName,
+        r#type: Type,
+        count: usize,
+        max: usize,
+    },
+    /// The record's type is not one of the allowed types.
+    #[error("{fqdn} {} records are not allowed", .r#type)]
+    TypeNotAllowed {
+        fqdn: FullyQualifiedDomainName,
+        r#type: Type,
+    },
+    /// The record's TTL is below the minimum TTL.
+    #[error("{fqdn} {} record ttl {ttl} is below the minimum of {min}", .r#type)]
+    TtlTooLow {
+        fqdn: FullyQualifiedDomainName,
+        r#type: Type,
+        ttl: u32,
+        min: u32,
+    },
+    /// The record's TTL is above the maximum TTL.
+    #[error("{fqdn} {} record ttl {ttl} exceeds the maximum of {max}", .r#type)]
+    TtlTooHigh {
+        fqdn: FullyQualifiedDomainName,
+        r#type: Type,
+        ttl: u32,
+        max: u32,
+    },
+    /// The record's name does not match the allowed names.
+    #[error("{0} is not an allowed name")]
+    NameNotAllowed(FullyQualifiedDomainName),
+}
+
+/// Limits imposed on the contents of a [`Zone`].
+///
+/// Fields left as [`None`] are not constrained by the policy.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ZonePolicy {
+    /// Maximum number of records in the zone.
+    pub max_records: Option<usize>,
+    /// Maximum number of records in a single record set.
+    pub max_rrset_size: Option<usize>,
+    /// Record types which may be used in the zone.
+    pub allowed_types: Option<BTreeSet<Type>>,
+    /// Minimum TTL of any record.
+    pub min_ttl: Option<u32>,
+    /// Maximum TTL of any record.
+    pub max_ttl: Option<u32>,
+    /// Names which records may be created for.
+    pub allowed_names: Option<PatternSet>,
+}
+
+impl ZonePolicy {
+    /// Evaluates the policy against a single record, returning all violations.
+    ///
+    /// Limits on the number of records only apply to whole zones,
+    /// see [`ZonePolicy::evaluate`].
+    pub fn evaluate_record(&self, record: &Record) -> Vec<PolicyViolation> {
+        let mut violations = Vec::new();
+        let (fqdn, r#type, ttl) = (&record.fqdn, record.r#type, record.ttl);
+
+        if self
+            .allowed_types
+            .as_ref()
+            .is_some_and(|types| !types.contains(&r#type))
+        {
+            violations.push(PolicyViolation::TypeNotAllowed {
+                fqdn: fqdn.clone(),
+                r#type,
+            });
+        }
+
+        if let Some(min) = self.min_ttl.filter(|min| ttl < *min) {
+            violations.push(PolicyViolation::TtlTooLow {
+                fqdn: fqdn.clone(),
+                r#type,
+                ttl,
+                min,
+            });
+        }
+
+        if let Some(max) = self.max_ttl.filter(|max| ttl > *max) {
+            violations.push(PolicyViolation::TtlTooHigh {
+                fqdn: fqdn.clone(),
+                r#type,
+                ttl,
+                max,
+            });
+        }
+
+        if self
+            .allowed_names
+            .as_ref()
+            .is_some_and(|names| !names.matches(fqdn))
+        {
+            violations.push(PolicyViolation::NameNotAllowed(fqdn.clone()));
+        }
+
+        violations
+    }
+
+    /// Evaluates the policy against all records of the zone, returning
+    /// all violations.
+    pub fn evaluate(&self, zone: &Zone) -> Vec<PolicyViolation> {
+        let mut violations = Vec::new();
+
+        if let Some(max) = self.max_records.filter(|max| zone.len() > *max) {
+            violations.push(PolicyViolation::TooManyRecords {
+                count: zone.len(),
+                max,
+            });
+        }
+
+        if let Some(max) = self.max_rrset_size {
+            for ((fqdn, _, r#type), records) in zone.record_sets() {
+                if records.len() > max {
+                    violations.push(PolicyViolation::RecordSetTooLarge {
+                        fqdn: fqdn.clone(),
+                        r#type,
+                        count: records.len(),
+                        max,
+                    });
+                }
+            }
+        }
+
+        violations.extend(zone.iter().flat_map(|record| self.evaluate_record(record)));
+        violations
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use crate::{
+        policy::{PolicyViolation, ZonePolicy},
+        Class, FullyQualifiedDomainName, Record, RecordData, Type, TypePolicy, TypeRegistry, Zone,
+    };
+
+    #[test]
+    fn fallback_to_default() {
+        let mut registry = TypeRegistry::new()
+            .with_default(TypePolicy {
+                default_ttl: Some(3600),
+                allowed_at_apex: Some(true),
+                ..Default::default()
+            })
+            .with(
+                Type::CNAME,
+                TypePolicy {
+                    max_rrset_size: Some(1),
+                    allowed_at_apex: Some(false),
+                    ..Default::default()
+                },
+            );
+
+        assert_eq!(
+            registry.get(Type::CNAME),
+            TypePolicy {
+                default_ttl: Some(3600),
+                max_rrset_size: Some(1),
+                allowed_at_apex: Some(false),
+            }
+        );
+
+        assert_eq!(registry.allowed_at_apex(Type::A), Some(true));
+        assert_eq!(registry.max_rrset_size(Type::A), None);
+
+        registry.remove(Type::CNAME);
+        assert_eq!(registry.allowed_at_apex(Type::CNAME), Some(true));
+    }
+
+    #[test]
+    fn zone_policy() {
+        let fqdn = |name: &str| FullyQualifiedDomainName::try_from(name).unwrap();
+        let record = |name: &str, r#type: Type, ttl: u32, rdata: &str| Record {
+            fqdn: fqdn(name),
+            class: Class::IN,
+            r#type,
+            ttl,
+            rdata: RecordData::parse(r#type, rdata).unwrap(),
+        };
+
+        let mut zone = Zone::new(fqdn("example.org."));
+        for record in [
+            record("www.example.org.", Type::A, 300, "192.168.0.1"),
+            record("www.example.org.", Type::A, 300, "192.168.0.2"),
+            record("internal.example.org.", Type::TXT, 30, "\"secret\""),
+        ] {
+            zone.insert(record).unwrap();
+        }
+
+        let policy = ZonePolicy {
+            max_records: Some(2),
+            max_rrset_size: Some(1),
+            allowed_types: Some(BTreeSet::from([Type::A, Type::AAAA])),
+            min_ttl: Some(60),
+            max_ttl: None,
+            allowed_names: Some("*.example.org,!internal.example.org".parse().unwrap()),
+        };
+
+        assert_eq!(
+            policy.evaluate(&zone),
+            [
+                PolicyViolation::TooManyRecords { count: 3, max: 2 },
+                PolicyViolation::RecordSetTooLarge {
+                    fqdn: fqdn("www.example.org."),
+                    r#type: Type::A,
+                    count: 2,
+                    max: 1
+                },
+                PolicyViolation::TypeNotAllowed {
+                    fqdn: fqdn("internal.example.org."),
+                    r#type: Type::TXT
+                },
+                PolicyViolation::TtlTooLow {
+                    fqdn: fqdn("internal.example.org."),
+                    r#type: Type::TXT,
+                    ttl: 30,
+                    min: 60
+                },
+                PolicyViolation::NameNotAllowed(fqdn("internal.example.org.")),
+            ]
+        );
+
+        assert!(ZonePolicy::default().evaluate(&zone).is_empty());
+    }
+}
@@ -1,17 +1,30 @@
 use std::{
     fmt::{Display, Write},
     ops::Add,
+    str::FromStr,
 };
 
-use schemars::JsonSchema;
+#[cfg(feature = "schemars")]
+use schemars::{
+    schema::{InstanceType, Schema, SchemaObject, StringValidation},
+    JsonSchema,
+};
+#[cfg(feature = "serde")]
 use serde::{de::Error, Deserialize, Serialize};
 use thiserror::Error;
 
 use crate::{
-    segment::{DomainSegment, DomainSegmentError},
-    FullyQualifiedDomainName,
+    dn::DomainNameError,
+    segment::{
+        debug_validate_segments, parse_labels, strip_root, validate_name_length, DomainSegment,
+        DomainSegmentError, LabelError, NameLengthError,
+    },
+    DomainName, FullyQualifiedDomainName,
 };
 
+#[cfg(feature = "schemars")]
+use crate::segment::LABEL_REGEX;
+
 /// Produced when attempting to construct a [`PartiallyQualifiedDomainName`]
 /// from an invalid string.
 #[derive(Error, Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
@@ -20,13 +33,48 @@ pub enum PartiallyQualifiedDomainNameError {
     /// a trailing dot making it fully qualified.
     #[error("domain is fully qualified")]
     DomainIsFullyQualified,
-    /// One or more of the segments of the domain specified in the string
-    /// are invalid.
-    #[error("{0}")]
-    SegmentError(#[from] DomainSegmentError),
+    /// The label starting at byte `offset` within the input is invalid.
+    #[error("invalid label {label:?} at offset {offset}: {error}")]
+    SegmentError {
+        offset: usize,
+        label: String,
+        error: DomainSegmentError,
+    },
     /// Wildcard segments must only appear at the beginning of a record.
     #[error("non-leading wildcard segment")]
     NonLeadingWildcard,
+    /// The name contains a wildcard, but was parsed with [`WildcardPolicy::Reject`](crate::WildcardPolicy::Reject).
+    #[error("wildcard names are not permitted")]
+    WildcardNotPermitted,
+    /// The domain name, once fully qualified, is longer than the
+    /// permitted 255 octets in wire format.
+    #[error("domain too long {0} > 255")]
+    TooLong(usize),
+    /// The domain name, once fully qualified, consists of more than
+    /// the permitted 127 labels.
+    #[error("domain has too many labels {0} > 127")]
+    TooManyLabels(usize),
+}
+
+impl From<LabelError> for PartiallyQualifiedDomainNameError {
+    fn from(value: LabelError) -> Self {
+        PartiallyQualifiedDomainNameError::SegmentError {
+            offset: value.offset,
+            label: value.label,
+            error: value.error,
+        }
+    }
+}
+
+impl From<NameLengthError> for PartiallyQualifiedDomainNameError {
+    fn from(value: NameLengthError) -> Self {
+        match value {
+            NameLengthError::TooLong(length) => PartiallyQualifiedDomainNameError::TooLong(length),
+            NameLengthError::TooManyLabels(count) => {
+                PartiallyQualifiedDomainNameError::TooManyLabels(count)
+            }
+        }
+    }
 }
 
 /// Partially qualified domain name (PQDN).
@@ -40,24 +88,84 @@ pub enum PartiallyQualifiedDomainNameError {
 /// domain name is not known, or specified elsewhere.
 ///
 /// See also [`FullyQualifiedDomainName`]
-#[derive(Clone, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Clone, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub struct PartiallyQualifiedDomainName(pub(crate) Vec<DomainSegment>);
 
 impl PartiallyQualifiedDomainName {
+    /// Maximum length of a domain name in octets, in wire format,
+    /// once fully qualified.
+    pub const MAX_LENGTH: usize = FullyQualifiedDomainName::MAX_LENGTH;
+
+    /// Maximum number of labels in a domain name.
+    pub const MAX_LABELS: usize = FullyQualifiedDomainName::MAX_LABELS;
+
     /// Appends the fqdn to the end of the partial domain.
+    ///
+    /// The resulting domain name might exceed the maximum length,
+    /// see [`PartiallyQualifiedDomainName::try_with_origin`] for a
+    /// validating alternative.
     pub fn with_origin(&self, origin: &FullyQualifiedDomainName) -> FullyQualifiedDomainName {
         self + origin
     }
 
+    /// Appends the fqdn to the end of the partial domain, or returns an
+    /// error if the resulting domain name would be too long.
+    pub fn try_with_origin(
+        &self,
+        origin: &FullyQualifiedDomainName,
+    ) -> Result<FullyQualifiedDomainName, PartiallyQualifiedDomainNameError> {
+        let fqdn = self.with_origin(origin);
+        validate_name_length(fqdn.iter().map(DomainSegment::len))?;
+
+        Ok(fqdn)
+    }
+
+    /// Constructs a name from its segments, validating it like
+    /// [`FullyQualifiedDomainName::try_from_segments`].
+    pub fn try_from_segments(
+        segments: impl IntoIterator<Item = DomainSegment>,
+    ) -> Result<Self, PartiallyQualifiedDomainNameError> {
+        let segments: Vec<DomainSegment> = segments.into_iter().collect();
+
+        if segments.iter().skip(1).any(DomainSegment::is_wildcard) {
+            return Err(PartiallyQualifiedDomainNameError::NonLeadingWildcard);
+        }
+
+        validate_name_length(segments.iter().map(DomainSegment::len))?;
+
+        Ok(PartiallyQualifiedDomainName(segments))
+    }
+
     /// Iterates over all [`DomainSegment`]s that make up the domain name.
     pub fn iter(&self) -> core::slice::Iter<'_, DomainSegment> {
         self.0.iter()
     }
 
-    /// Length of the fully qualified domain name as a string.
+    /// Splits `www.example` into `www` and the remaining labels `example`,
+    /// without cloning, or returns [`None`] if the name has no labels.
+    /// Splitting a single label leaves no remaining labels.
+    pub fn split_first(&self) -> Option<(&DomainSegment, &[DomainSegment])> {
+        self.0.split_first()
+    }
+
+    /// Splits `www.example` into `example` and the remaining labels `www`,
+    /// the counterpart of [`PartiallyQualifiedDomainName::split_first`].
+    pub fn split_last(&self) -> Option<(&DomainSegment, &[DomainSegment])> {
+        self.0.split_last()
+    }
+
+    /// Length in octets of the name once fully qualified, *including* the
+    /// trailing dot, with escapes decoded, see [`FullyQualifiedDomainName::len`].
     #[allow(clippy::len_without_is_empty)]
     pub fn len(&self) -> usize {
-        self.0.iter().map(|segment| segment.len()).sum::<usize>() + self.0.len()
+        self.0.iter().map(DomainSegment::len).sum::<usize>() + self.0.len()
+    }
+
+    /// Asserts the invariants upheld by parsing.
+    ///
+    /// See [`FullyQualifiedDomainName::debug_validate`].
+    pub fn debug_validate(&self) {
+        debug_validate_segments(&self.0);
     }
 
     /// Coerce the domain name into a fully qualified one.
@@ -69,8 +177,38 @@ impl PartiallyQualifiedDomainName {
     pub fn to_fully_qualified(&self) -> FullyQualifiedDomainName {
         FullyQualifiedDomainName(self.0.clone())
     }
+
+    /// Prepends the labels of `prefix`, such as `www` or `*.dev`, which
+    /// are parsed and validated along with the resulting name.
+    ///
+    /// Offsets within errors refer to the resulting name.
+    pub fn try_prepend(
+        &self,
+        prefix: &str,
+    ) -> Result<PartiallyQualifiedDomainName, PartiallyQualifiedDomainNameError> {
+        if self.0.is_empty() {
+            return PartiallyQualifiedDomainName::try_from(prefix);
+        }
+
+        PartiallyQualifiedDomainName::try_from(format!("{prefix}.{self}"))
+    }
+
+    /// Appends the labels of `suffix`, which are parsed and validated
+    /// along with the resulting name. The result is fully qualified if
+    /// `suffix` ends with a dot, such as `example.org.`
+    ///
+    /// Offsets within errors refer to the resulting name.
+    pub fn try_append(&self, suffix: &str) -> Result<DomainName, DomainNameError> {
+        if self.0.is_empty() {
+            return DomainName::try_from(suffix);
+        }
+
+        DomainName::try_from(format!("{self}.{suffix}"))
+    }
 }
 
+/// Collects segments into a name without validating it, see
+/// [`PartiallyQualifiedDomainName::try_from_segments`].
 impl FromIterator<DomainSegment> for PartiallyQualifiedDomainName {
     fn from_iter<T: IntoIterator<Item = DomainSegment>>(iter: T) -> Self {
         PartiallyQualifiedDomainName(iter.into_iter().collect())
@@ -95,47 +233,66 @@ impl TryFrom<&str> for PartiallyQualifiedDomainName {
     type Error = PartiallyQualifiedDomainNameError;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        if value.ends_with('.') {
+        if strip_root(value).is_some() {
             Err(PartiallyQualifiedDomainNameError::DomainIsFullyQualified)
         } else {
-            let segments: Vec<DomainSegment> =
-                Result::from_iter(value.split('.').map(DomainSegment::try_from))?;
+            let segments = parse_labels(value)?;
 
             if segments.iter().skip(1).any(DomainSegment::is_wildcard) {
                 return Err(PartiallyQualifiedDomainNameError::NonLeadingWildcard);
             }
 
+            validate_name_length(segments.iter().map(DomainSegment::len))?;
+
             Ok(PartiallyQualifiedDomainName(segments))
         }
     }
 }
 
+impl FromStr for PartiallyQualifiedDomainName {
+    type Err = PartiallyQualifiedDomainNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
 impl Display for PartiallyQualifiedDomainName {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         for (index, segment) in self.0.iter().enumerate() {
-            segment.fmt(f)?;
-            if index != self.0.len() - 1 {
+            if index > 0 {
                 f.write_char('.')?;
             }
+
+            segment.fmt(f)?;
         }
 
         Ok(())
     }
 }
 
+impl std::fmt::Debug for PartiallyQualifiedDomainName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Pqdn").field(&self.to_string()).finish()
+    }
+}
+
+/// Concatenates the names without validating the length of the result,
+/// see [`PartiallyQualifiedDomainName::try_with_origin`].
 impl Add<&FullyQualifiedDomainName> for &PartiallyQualifiedDomainName {
     type Output = FullyQualifiedDomainName;
 
     fn add(self, rhs: &FullyQualifiedDomainName) -> Self::Output {
-        FullyQualifiedDomainName::from_iter(self.0.iter().chain(rhs.iter()).cloned())
+        FullyQualifiedDomainName::from_iter(self.0.iter().chain(rhs.iter()))
     }
 }
 
+/// Concatenates the names without validating the length of the result.
 impl Add for &PartiallyQualifiedDomainName {
     type Output = PartiallyQualifiedDomainName;
 
     fn add(self, rhs: &PartiallyQualifiedDomainName) -> Self::Output {
-        PartiallyQualifiedDomainName::from_iter(self.0.iter().chain(rhs.iter()).cloned())
+        PartiallyQualifiedDomainName::from_iter(self.0.iter().chain(rhs.iter()))
     }
 }
 
@@ -157,16 +314,29 @@ impl PartialEq<str> for PartiallyQualifiedDomainName {
     }
 }
 
+#[cfg(feature = "schemars")]
 impl JsonSchema for PartiallyQualifiedDomainName {
     fn schema_name() -> String {
-        <String as schemars::JsonSchema>::schema_name()
+        String::from("PartiallyQualifiedDomainName")
     }
 
-    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
-        <String as schemars::JsonSchema>::json_schema(gen)
+    fn json_schema(_: &mut schemars::gen::SchemaGenerator) -> Schema {
+        // The length limit is that of names without escapes, which are
+        // otherwise longer as written than in wire format.
+        SchemaObject {
+            instance_type: Some(InstanceType::String.into()),
+            string: Some(Box::new(StringValidation {
+                max_length: Some((Self::MAX_LENGTH - 2) as u32),
+                pattern: Some(format!(r"^(\*|{LABEL_REGEX})(\.{LABEL_REGEX})*$")),
+                ..Default::default()
+            })),
+            ..Default::default()
+        }
+        .into()
     }
 }
 
+#[cfg(feature = "serde")]
 impl<'de> Deserialize<'de> for PartiallyQualifiedDomainName {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -178,6 +348,7 @@ impl<'de> Deserialize<'de> for PartiallyQualifiedDomainName {
     }
 }
 
+#[cfg(feature = "serde")]
 impl Serialize for PartiallyQualifiedDomainName {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -187,11 +358,31 @@ impl Serialize for PartiallyQualifiedDomainName {
     }
 }
 
+#[cfg(feature = "valuable")]
+impl valuable::Valuable for PartiallyQualifiedDomainName {
+    fn as_value(&self) -> valuable::Value<'_> {
+        valuable::Value::Listable(self)
+    }
+
+    fn visit(&self, visit: &mut dyn valuable::Visit) {
+        DomainSegment::visit_slice(&self.0, visit)
+    }
+}
+
+#[cfg(feature = "valuable")]
+impl valuable::Listable for PartiallyQualifiedDomainName {
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.0.len(), Some(self.0.len()))
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::{
-        error::PartiallyQualifiedDomainNameError, segment::DomainSegment, FullyQualifiedDomainName,
-        PartiallyQualifiedDomainName,
+        error::{DomainSegmentError, PartiallyQualifiedDomainNameError},
+        fqdn, pqdn,
+        segment::DomainSegment,
+        DomainName, FullyQualifiedDomainName, PartiallyQualifiedDomainName,
     };
 
     #[test]
@@ -222,6 +413,23 @@ mod test {
         )
     }
 
+    #[test]
+    fn try_with_origin() {
+        let origin = FullyQualifiedDomainName::try_from("example.org.").unwrap();
+        let pqdn = PartiallyQualifiedDomainName::try_from("a.".repeat(125) + "a").unwrap();
+
+        assert_eq!(
+            pqdn.try_with_origin(&origin),
+            Err(PartiallyQualifiedDomainNameError::TooManyLabels(128))
+        );
+        assert_eq!(
+            PartiallyQualifiedDomainName::try_from("test")
+                .unwrap()
+                .try_with_origin(&origin),
+            Ok(FullyQualifiedDomainName::try_from("test.example.org.").unwrap())
+        );
+    }
+
     #[test]
     fn pqdn_addition() {
         assert_eq!(
@@ -230,4 +438,50 @@ mod test {
             PartiallyQualifiedDomainName::try_from("test.example").unwrap()
         )
     }
+
+    #[test]
+    fn string_concatenation() {
+        let dev = pqdn!("dev");
+
+        assert_eq!(dev.try_prepend("api.eu"), Ok(pqdn!("api.eu.dev")));
+        assert_eq!(
+            PartiallyQualifiedDomainName::default().try_prepend("www"),
+            Ok(pqdn!("www"))
+        );
+        assert_eq!(
+            dev.try_append("example.org."),
+            Ok(DomainName::Full(fqdn!("dev.example.org.")))
+        );
+        assert_eq!(
+            dev.try_append("internal"),
+            Ok(DomainName::Partial(pqdn!("dev.internal")))
+        );
+
+        assert_eq!(
+            fqdn!("example.org.").try_prepend("*.dev"),
+            Ok(fqdn!("*.dev.example.org."))
+        );
+        assert_eq!(
+            FullyQualifiedDomainName::default().try_prepend("org"),
+            Ok(fqdn!("org."))
+        );
+        assert_eq!(
+            DomainName::Full(fqdn!("example.org.")).try_prepend("www"),
+            Ok(DomainName::Full(fqdn!("www.example.org.")))
+        );
+
+        assert!(matches!(
+            dev.try_prepend("ex ample"),
+            Err(PartiallyQualifiedDomainNameError::SegmentError { offset: 0, .. })
+        ));
+        assert_eq!(
+            dev.try_prepend("www."),
+            Err(PartiallyQualifiedDomainNameError::SegmentError {
+                offset: 4,
+                label: String::new(),
+                error: DomainSegmentError::EmptyString
+            })
+        );
+        assert!(fqdn!("example.org.").try_prepend("api.*").is_err());
+    }
 }
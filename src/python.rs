@@ -0,0 +1,234 @@
+//! Python bindings exposing the core types as Python classes, so scripts
+//! can validate names and patterns with rules identical to the Rust crate.
+//!
+//! Building an importable extension requires compiling the crate as a
+//! `cdylib`, for example using [maturin](https://www.maturin.rs/).
+
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+};
+
+use pyo3::{exceptions::PyValueError, prelude::*};
+
+use crate::{
+    Canonicalize, FullyQualifiedDomainName, PartiallyQualifiedDomainName, Pattern, RecordIdent,
+    Suggest, Type,
+};
+
+/// Converts a parse error into a Python `ValueError`, including the
+/// suggestion for how to fix the input, if one is available.
+fn value_error<E: std::fmt::Display + Suggest>(err: E, input: &str) -> PyErr {
+    match err.suggestion(input) {
+        Some(suggestion) => PyValueError::new_err(format!("{err}: {suggestion}")),
+        None => PyValueError::new_err(err.to_string()),
+    }
+}
+
+fn hash_of<T: Hash>(value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Python wrapper around [`FullyQualifiedDomainName`].
+#[pyclass(name = "FullyQualifiedDomainName", frozen, eq)]
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct PyFullyQualifiedDomainName(pub FullyQualifiedDomainName);
+
+#[pymethods]
+impl PyFullyQualifiedDomainName {
+    #[new]
+    fn new(value: &str) -> PyResult<Self> {
+        FullyQualifiedDomainName::try_from(value)
+            .map(PyFullyQualifiedDomainName)
+            .map_err(|err| value_error(err, value))
+    }
+
+    /// Returns true if `parent` matches the tail end of this name.
+    fn is_subdomain_of(&self, parent: &PyFullyQualifiedDomainName) -> bool {
+        self.0.is_subdomain_of(&parent.0)
+    }
+
+    /// Returns the labels making up the name.
+    fn segments(&self) -> Vec<String> {
+        self.0.iter().map(ToString::to_string).collect()
+    }
+
+    /// Coerce the domain name into a partially qualified one.
+    fn to_partially_qualified(&self) -> PyPartiallyQualifiedDomainName {
+        PyPartiallyQualifiedDomainName(self.0.to_partially_qualified())
+    }
+
+    fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("FullyQualifiedDomainName({:?})", self.0.to_string())
+    }
+
+    fn __hash__(&self) -> u64 {
+        hash_of(&self.0)
+    }
+}
+
+/// Python wrapper around [`PartiallyQualifiedDomainName`].
+#[pyclass(name = "PartiallyQualifiedDomainName", frozen, eq)]
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct PyPartiallyQualifiedDomainName(pub PartiallyQualifiedDomainName);
+
+#[pymethods]
+impl PyPartiallyQualifiedDomainName {
+    #[new]
+    fn new(value: &str) -> PyResult<Self> {
+        PartiallyQualifiedDomainName::try_from(value)
+            .map(PyPartiallyQualifiedDomainName)
+            .map_err(|err| value_error(err, value))
+    }
+
+    /// Appends the fqdn to the end of the partial domain.
+    fn with_origin(&self, origin: &PyFullyQualifiedDomainName) -> PyFullyQualifiedDomainName {
+        PyFullyQualifiedDomainName(self.0.with_origin(&origin.0))
+    }
+
+    /// Returns the labels making up the name.
+    fn segments(&self) -> Vec<String> {
+        self.0.iter().map(ToString::to_string).collect()
+    }
+
+    fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("PartiallyQualifiedDomainName({:?})", self.0.to_string())
+    }
+
+    fn __hash__(&self) -> u64 {
+        hash_of(&self.0)
+    }
+}
+
+/// Python wrapper around [`Pattern`].
+#[pyclass(name = "Pattern", frozen, eq)]
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct PyPattern(pub Pattern);
+
+#[pymethods]
+impl PyPattern {
+    #[new]
+    fn new(value: &str) -> PyResult<Self> {
+        Pattern::try_from(value)
+            .map(PyPattern)
+            .map_err(|err| value_error(err, value))
+    }
+
+    /// Returns a new pattern with the origin appended.
+    fn with_origin(&self, origin: &PyFullyQualifiedDomainName) -> PyPattern {
+        PyPattern(self.0.with_origin(&origin.0))
+    }
+
+    /// Returns true if the pattern matches the given domain.
+    fn matches(&self, domain: &PyFullyQualifiedDomainName) -> bool {
+        self.0.matches(&domain.0)
+    }
+
+    fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Pattern({:?})", self.0.to_string())
+    }
+
+    fn __hash__(&self) -> u64 {
+        hash_of(&self.0)
+    }
+}
+
+/// Python wrapper around [`Type`].
+#[pyclass(name = "Type", frozen, eq)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PyType(pub Type);
+
+#[pymethods]
+impl PyType {
+    #[new]
+    fn new(value: &str) -> PyResult<Self> {
+        value
+            .parse()
+            .map(PyType)
+            .map_err(|err| PyValueError::new_err(err.to_string()))
+    }
+
+    fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Type({:?})", self.0.to_string())
+    }
+
+    fn __hash__(&self) -> u64 {
+        hash_of(&self.0)
+    }
+}
+
+/// Python wrapper around [`RecordIdent`].
+#[pyclass(name = "RecordIdent", frozen, eq)]
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct PyRecordIdent(pub RecordIdent);
+
+#[pymethods]
+impl PyRecordIdent {
+    #[new]
+    fn new(fqdn: &PyFullyQualifiedDomainName, r#type: &PyType, rdata: &str) -> PyResult<Self> {
+        RecordIdent::builder()
+            .fqdn(fqdn.0.clone())
+            .r#type(r#type.0)
+            .rdata(rdata)
+            .build()
+            .map(|ident| PyRecordIdent(ident.canonicalize()))
+            .map_err(|err| PyValueError::new_err(err.to_string()))
+    }
+
+    #[getter]
+    fn fqdn(&self) -> PyFullyQualifiedDomainName {
+        PyFullyQualifiedDomainName(self.0.fqdn.clone())
+    }
+
+    #[getter]
+    fn r#type(&self) -> PyType {
+        PyType(self.0.r#type)
+    }
+
+    #[getter]
+    fn rdata(&self) -> String {
+        self.0.rdata.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "RecordIdent({:?}, {:?}, {:?})",
+            self.0.fqdn.to_string(),
+            self.0.r#type.to_string(),
+            self.0.rdata.to_string()
+        )
+    }
+
+    fn __hash__(&self) -> u64 {
+        hash_of(&self.0)
+    }
+}
+
+/// Python module definition.
+#[pymodule]
+fn kubizone_common(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<PyFullyQualifiedDomainName>()?;
+    module.add_class::<PyPartiallyQualifiedDomainName>()?;
+    module.add_class::<PyPattern>()?;
+    module.add_class::<PyType>()?;
+    module.add_class::<PyRecordIdent>()?;
+    Ok(())
+}
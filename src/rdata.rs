@@ -0,0 +1,1239 @@
+use std::{
+    fmt::{Display, Write},
+    net::{Ipv4Addr, Ipv6Addr},
+};
+
+use thiserror::Error;
+
+use crate::{DomainName, FullyQualifiedDomainName, ParseOptions, Type, Validation, WildcardPolicy};
+
+/// Produced when record data is not valid for the record's [`Type`].
+#[derive(Error, Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum RecordDataError {
+    /// Record data is empty.
+    #[error("record data is empty")]
+    Empty,
+    /// Record data has the wrong number of whitespace-separated fields.
+    #[error("expected {expected} fields, found {found}")]
+    FieldCount { expected: usize, found: usize },
+    /// A field of the record data could not be parsed.
+    #[error("invalid {field}: {value:?}")]
+    InvalidField { field: &'static str, value: String },
+    /// A quoted character string is missing its closing quote.
+    #[error("unterminated character string")]
+    UnterminatedString,
+    /// A domain name field refers to the origin using `@`, but was
+    /// parsed without one, see [`RecordData::parse_with_origin`].
+    #[error("{0} refers to the origin @, but no origin is known")]
+    UnresolvedOrigin(&'static str),
+}
+
+/// Record types with a dedicated [`RecordData`] variant.
+const TYPED: [Type; 16] = [
+    Type::A,
+    Type::AAAA,
+    Type::AFSDB,
+    Type::CAA,
+    Type::CNAME,
+    Type::DLV,
+    Type::DNAME,
+    Type::HIP,
+    Type::KX,
+    Type::MX,
+    Type::NS,
+    Type::PTR,
+    Type::SOA,
+    Type::SRV,
+    Type::TA,
+    Type::TXT,
+];
+
+/// Typed record data.
+///
+/// Record types without a dedicated variant are stored as [`RecordData::Other`],
+/// containing the record data in presentation format.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum RecordData {
+    /// IPv4 address of an [`A`](Type::A) record.
+    A(Ipv4Addr),
+    /// IPv6 address of an [`AAAA`](Type::AAAA) record.
+    AAAA(Ipv6Addr),
+    /// AFS database location (RFC 1183).
+    AFSDB {
+        /// Type of server, `1` for AFS cell database servers and `2`
+        /// for DCE authenticated name servers.
+        subtype: u16,
+        hostname: DomainName,
+    },
+    /// Certification Authority Authorization.
+    CAA {
+        flags: u8,
+        tag: String,
+        /// Value of the property, as raw octets with escapes resolved.
+        value: Vec<u8>,
+    },
+    /// Canonical name target.
+    CNAME(DomainName),
+    /// DNSSEC lookaside validation (RFC 4431), in the format of a DS record.
+    DLV {
+        key_tag: u16,
+        algorithm: u8,
+        digest_type: u8,
+        /// Digest of the key, as uppercase hexadecimal.
+        digest: String,
+    },
+    /// Delegation name target.
+    DNAME(DomainName),
+    /// Host Identity Protocol.
+    HIP {
+        /// Public key algorithm.
+        algorithm: u8,
+        /// Host Identity Tag, as uppercase hexadecimal.
+        hit: String,
+        /// Public key, base64-encoded.
+        public_key: String,
+        /// Rendezvous servers, in order of preference.
+        rendezvous_servers: Vec<DomainName>,
+    },
+    /// Key exchanger (RFC 2230).
+    KX {
+        preference: u16,
+        exchanger: DomainName,
+    },
+    /// Mail exchange.
+    MX {
+        preference: u16,
+        exchange: DomainName,
+    },
+    /// Authoritative name server.
+    NS(DomainName),
+    /// Pointer target.
+    PTR(DomainName),
+    /// Start of authority.
+    SOA {
+        mname: DomainName,
+        rname: DomainName,
+        serial: u32,
+        refresh: u32,
+        retry: u32,
+        expire: u32,
+        minimum: u32,
+    },
+    /// Service locator.
+    SRV {
+        priority: u16,
+        weight: u16,
+        port: u16,
+        target: DomainName,
+    },
+    /// DNSSEC trust anchor, in the format of a DS record.
+    TA {
+        key_tag: u16,
+        algorithm: u8,
+        digest_type: u8,
+        /// Digest of the key, as uppercase hexadecimal.
+        digest: String,
+    },
+    /// One or more character strings, as raw octets with escapes resolved,
+    /// since they are not required to be valid UTF-8.
+    TXT(Vec<Vec<u8>>),
+    /// Record data of a type without a dedicated variant, in presentation format.
+    Other(String),
+}
+
+impl RecordData {
+    /// Parses the presentation format `rdata` of a record of the given type.
+    pub fn parse(r#type: Type, rdata: &str) -> Result<Self, RecordDataError> {
+        Self::parse_in(r#type, rdata, None)
+    }
+
+    /// Parses the presentation format `rdata` of a record of the given type,
+    /// substituting `@` in domain name fields with the origin, and
+    /// qualifying partially qualified names using it.
+    pub fn parse_with_origin(
+        r#type: Type,
+        rdata: &str,
+        origin: &FullyQualifiedDomainName,
+    ) -> Result<Self, RecordDataError> {
+        Ok(Self::parse_in(r#type, rdata, Some(origin))?.with_origin(origin))
+    }
+
+    /// Parses the record data, substituting `@` in domain name fields
+    /// with the origin, if one is given.
+    pub(crate) fn parse_in(
+        r#type: Type,
+        rdata: &str,
+        origin: Option<&FullyQualifiedDomainName>,
+    ) -> Result<Self, RecordDataError> {
+        let name = |field, value| self::name(field, value, origin);
+        let host = |field, value| self::host(field, value, origin);
+        let rdata = rdata.trim();
+
+        if rdata.is_empty() {
+            return Err(RecordDataError::Empty);
+        }
+
+        let fields = character_strings(rdata)?;
+        let fields: Vec<&str> = fields.iter().map(String::as_str).collect();
+
+        let data = match r#type {
+            Type::A => {
+                let [address] = exact(&fields)?;
+                RecordData::A(parse("ipv4 address", address)?)
+            }
+            Type::AAAA => {
+                let [address] = exact(&fields)?;
+                RecordData::AAAA(parse("ipv6 address", address)?)
+            }
+            Type::AFSDB => {
+                let [subtype, hostname] = exact(&fields)?;
+                RecordData::AFSDB {
+                    subtype: parse("subtype", subtype)?,
+                    hostname: host("hostname", hostname)?,
+                }
+            }
+            Type::CAA => {
+                let [flags, tag, value] = exact(&fields)?;
+                let tag = tag.to_ascii_lowercase();
+
+                if tag.is_empty() || !tag.chars().all(|c| c.is_ascii_alphanumeric()) {
+                    return Err(RecordDataError::InvalidField {
+                        field: "tag",
+                        value: tag,
+                    });
+                }
+
+                RecordData::CAA {
+                    flags: parse("flags", flags)?,
+                    tag,
+                    value: unescape(value)?,
+                }
+            }
+            Type::CNAME => {
+                let [target] = exact(&fields)?;
+                RecordData::CNAME(name("target", target)?)
+            }
+            Type::DLV => {
+                let (key_tag, algorithm, digest_type, digest) = delegation_signer(&fields)?;
+                RecordData::DLV {
+                    key_tag,
+                    algorithm,
+                    digest_type,
+                    digest,
+                }
+            }
+            Type::DNAME => {
+                let [target] = exact(&fields)?;
+                RecordData::DNAME(name("target", target)?)
+            }
+            Type::HIP => {
+                let [algorithm, hit, public_key, rendezvous_servers @ ..] = &fields[..] else {
+                    return Err(RecordDataError::FieldCount {
+                        expected: 3,
+                        found: fields.len(),
+                    });
+                };
+
+                if hit.is_empty()
+                    || !hit.len().is_multiple_of(2)
+                    || !hit.chars().all(|c| c.is_ascii_hexdigit())
+                {
+                    return Err(RecordDataError::InvalidField {
+                        field: "hit",
+                        value: hit.to_string(),
+                    });
+                }
+
+                if !is_base64(public_key) {
+                    return Err(RecordDataError::InvalidField {
+                        field: "public key",
+                        value: public_key.to_string(),
+                    });
+                }
+
+                RecordData::HIP {
+                    algorithm: parse("algorithm", algorithm)?,
+                    hit: hit.to_ascii_uppercase(),
+                    public_key: public_key.to_string(),
+                    rendezvous_servers: rendezvous_servers
+                        .iter()
+                        .map(|server| name("rendezvous server", server))
+                        .collect::<Result<_, _>>()?,
+                }
+            }
+            Type::KX => {
+                let [preference, exchanger] = exact(&fields)?;
+                RecordData::KX {
+                    preference: parse("preference", preference)?,
+                    exchanger: host("exchanger", exchanger)?,
+                }
+            }
+            Type::MX => {
+                let [preference, exchange] = exact(&fields)?;
+                RecordData::MX {
+                    preference: parse("preference", preference)?,
+                    exchange: name("exchange", exchange)?,
+                }
+            }
+            Type::NS => {
+                let [target] = exact(&fields)?;
+                RecordData::NS(name("name server", target)?)
+            }
+            Type::PTR => {
+                let [target] = exact(&fields)?;
+                RecordData::PTR(name("target", target)?)
+            }
+            Type::SOA => {
+                let [mname, rname, serial, refresh, retry, expire, minimum] = exact(&fields)?;
+                RecordData::SOA {
+                    mname: name("mname", mname)?,
+                    rname: name("rname", rname)?,
+                    serial: parse("serial", serial)?,
+                    refresh: parse("refresh", refresh)?,
+                    retry: parse("retry", retry)?,
+                    expire: parse("expire", expire)?,
+                    minimum: parse("minimum", minimum)?,
+                }
+            }
+            Type::SRV => {
+                let [priority, weight, port, target] = exact(&fields)?;
+                RecordData::SRV {
+                    priority: parse("priority", priority)?,
+                    weight: parse("weight", weight)?,
+                    port: parse("port", port)?,
+                    target: name("target", target)?,
+                }
+            }
+            Type::TA => {
+                let (key_tag, algorithm, digest_type, digest) = delegation_signer(&fields)?;
+                RecordData::TA {
+                    key_tag,
+                    algorithm,
+                    digest_type,
+                    digest,
+                }
+            }
+            Type::TXT => {
+                RecordData::TXT(fields.into_iter().map(unescape).collect::<Result<_, _>>()?)
+            }
+            _ => RecordData::Other(rdata.to_string()),
+        };
+
+        Ok(data)
+    }
+
+    /// Returns the record data with all partially qualified domain names
+    /// qualified by appending the origin.
+    pub fn with_origin(&self, origin: &FullyQualifiedDomainName) -> Self {
+        self.map_names(|name| DomainName::Full(name.with_origin(origin)))
+    }
+
+    /// Returns the record data with all domain names which are subdomains
+    /// of the origin made relative to it.
+    pub fn relative_to(&self, origin: &FullyQualifiedDomainName) -> Self {
+        self.map_names(|name| match name {
+            DomainName::Full(full) if full.is_subdomain_of(origin) => {
+                DomainName::Partial((full - origin).unwrap())
+            }
+            name => name.clone(),
+        })
+    }
+
+    /// Returns the record data with every domain name replaced by `map`.
+    pub(crate) fn map_names(&self, map: impl Fn(&DomainName) -> DomainName) -> Self {
+        match self {
+            RecordData::AFSDB { subtype, hostname } => RecordData::AFSDB {
+                subtype: *subtype,
+                hostname: map(hostname),
+            },
+            RecordData::CNAME(target) => RecordData::CNAME(map(target)),
+            RecordData::DNAME(target) => RecordData::DNAME(map(target)),
+            RecordData::HIP {
+                algorithm,
+                hit,
+                public_key,
+                rendezvous_servers,
+            } => RecordData::HIP {
+                algorithm: *algorithm,
+                hit: hit.clone(),
+                public_key: public_key.clone(),
+                rendezvous_servers: rendezvous_servers.iter().map(&map).collect(),
+            },
+            RecordData::KX {
+                preference,
+                exchanger,
+            } => RecordData::KX {
+                preference: *preference,
+                exchanger: map(exchanger),
+            },
+            RecordData::MX {
+                preference,
+                exchange,
+            } => RecordData::MX {
+                preference: *preference,
+                exchange: map(exchange),
+            },
+            RecordData::NS(target) => RecordData::NS(map(target)),
+            RecordData::PTR(target) => RecordData::PTR(map(target)),
+            RecordData::SOA {
+                mname,
+                rname,
+                serial,
+                refresh,
+                retry,
+                expire,
+                minimum,
+            } => RecordData::SOA {
+                mname: map(mname),
+                rname: map(rname),
+                serial: *serial,
+                refresh: *refresh,
+                retry: *retry,
+                expire: *expire,
+                minimum: *minimum,
+            },
+            RecordData::SRV {
+                priority,
+                weight,
+                port,
+                target,
+            } => RecordData::SRV {
+                priority: *priority,
+                weight: *weight,
+                port: *port,
+                target: map(target),
+            },
+            RecordData::A(_)
+            | RecordData::AAAA(_)
+            | RecordData::CAA { .. }
+            | RecordData::DLV { .. }
+            | RecordData::TA { .. }
+            | RecordData::TXT(_)
+            | RecordData::Other(_) => self.clone(),
+        }
+    }
+
+    /// Returns true if the data belongs to a record of the given type, such
+    /// that parsing it as that type produces this variant.
+    ///
+    /// [`RecordData::Other`] only belongs to types without a dedicated variant.
+    pub fn is_valid_for(&self, r#type: Type) -> bool {
+        match self.r#type() {
+            Some(own) => own == r#type,
+            None => !TYPED.contains(&r#type),
+        }
+    }
+
+    /// Returns the record [`Type`] this data belongs to, or [`None`]
+    /// for [`RecordData::Other`].
+    pub fn r#type(&self) -> Option<Type> {
+        match self {
+            RecordData::A(_) => Some(Type::A),
+            RecordData::AAAA(_) => Some(Type::AAAA),
+            RecordData::AFSDB { .. } => Some(Type::AFSDB),
+            RecordData::CAA { .. } => Some(Type::CAA),
+            RecordData::CNAME(_) => Some(Type::CNAME),
+            RecordData::DLV { .. } => Some(Type::DLV),
+            RecordData::DNAME(_) => Some(Type::DNAME),
+            RecordData::HIP { .. } => Some(Type::HIP),
+            RecordData::KX { .. } => Some(Type::KX),
+            RecordData::MX { .. } => Some(Type::MX),
+            RecordData::NS(_) => Some(Type::NS),
+            RecordData::PTR(_) => Some(Type::PTR),
+            RecordData::SOA { .. } => Some(Type::SOA),
+            RecordData::SRV { .. } => Some(Type::SRV),
+            RecordData::TA { .. } => Some(Type::TA),
+            RecordData::TXT(_) => Some(Type::TXT),
+            RecordData::Other(_) => None,
+        }
+    }
+}
+
+impl Display for RecordData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RecordData::A(address) => address.fmt(f),
+            RecordData::AAAA(address) => address.fmt(f),
+            RecordData::AFSDB { subtype, hostname } => write!(f, "{subtype} {hostname}"),
+            RecordData::CAA { flags, tag, value } => {
+                write!(f, "{flags} {tag} ")?;
+                write_character_string(f, value)
+            }
+            RecordData::CNAME(target)
+            | RecordData::DNAME(target)
+            | RecordData::NS(target)
+            | RecordData::PTR(target) => target.fmt(f),
+            RecordData::DLV {
+                key_tag,
+                algorithm,
+                digest_type,
+                digest,
+            }
+            | RecordData::TA {
+                key_tag,
+                algorithm,
+                digest_type,
+                digest,
+            } => write!(f, "{key_tag} {algorithm} {digest_type} {digest}"),
+            RecordData::HIP {
+                algorithm,
+                hit,
+                public_key,
+                rendezvous_servers,
+            } => {
+                write!(f, "{algorithm} {hit} {public_key}")?;
+                for server in rendezvous_servers {
+                    write!(f, " {server}")?;
+                }
+
+                Ok(())
+            }
+            RecordData::KX {
+                preference,
+                exchanger: exchange,
+            }
+            | RecordData::MX {
+                preference,
+                exchange,
+            } => write!(f, "{preference} {exchange}"),
+            RecordData::SOA {
+                mname,
+                rname,
+                serial,
+                refresh,
+                retry,
+                expire,
+                minimum,
+            } => write!(
+                f,
+                "{mname} {rname} {serial} {refresh} {retry} {expire} {minimum}"
+            ),
+            RecordData::SRV {
+                priority,
+                weight,
+                port,
+                target,
+            } => write!(f, "{priority} {weight} {port} {target}"),
+            RecordData::TXT(strings) => {
+                for (index, string) in strings.iter().enumerate() {
+                    if index != 0 {
+                        f.write_char(' ')?;
+                    }
+                    write_character_string(f, string)?;
+                }
+
+                Ok(())
+            }
+            RecordData::Other(rdata) => f.write_str(rdata),
+        }
+    }
+}
+
+/// Splits `rdata` into whitespace-separated fields, treating double-quoted
+/// character strings as single fields.
+///
+/// Escapes are kept as written, since domain names resolve them when
+/// parsed, while character strings are resolved using [`unescape`].
+fn character_strings(rdata: &str) -> Result<Vec<String>, RecordDataError> {
+    let mut fields = Vec::new();
+    let mut chars = rdata.chars().peekable();
+
+    while let Some(&next) = chars.peek() {
+        if next.is_whitespace() {
+            chars.next();
+            continue;
+        }
+
+        let mut field = String::new();
+
+        if next == '"' {
+            chars.next();
+            loop {
+                match chars.next() {
+                    Some('"') => break,
+                    Some('\\') => {
+                        field.push('\\');
+                        field.push(chars.next().ok_or(RecordDataError::UnterminatedString)?);
+                    }
+                    Some(c) => field.push(c),
+                    None => return Err(RecordDataError::UnterminatedString),
+                }
+            }
+        } else {
+            while let Some(c) = chars.next_if(|c| !c.is_whitespace()) {
+                field.push(c);
+                if c == '\\' {
+                    field.extend(chars.next());
+                }
+            }
+        }
+
+        fields.push(field);
+    }
+
+    Ok(fields)
+}
+
+/// Resolves the escapes of a character string, where `\DDD` is the octet
+/// with the decimal value `DDD`, and `\X` is the character `X`.
+fn unescape(field: &str) -> Result<Vec<u8>, RecordDataError> {
+    let invalid = || RecordDataError::InvalidField {
+        field: "character string",
+        value: field.to_string(),
+    };
+
+    let mut octets = Vec::with_capacity(field.len());
+    let mut rest = field;
+
+    while let Some(index) = rest.find('\\') {
+        octets.extend_from_slice(&rest.as_bytes()[..index]);
+        rest = &rest[index + 1..];
+
+        let digits = rest
+            .get(..3)
+            .filter(|digits| digits.bytes().all(|c| c.is_ascii_digit()));
+        if let Some(digits) = digits {
+            octets.push(digits.parse().map_err(|_| invalid())?);
+            rest = &rest[3..];
+            continue;
+        }
+
+        let escaped = rest.chars().next().ok_or_else(invalid)?;
+        octets.extend_from_slice(&rest.as_bytes()[..escaped.len_utf8()]);
+        rest = &rest[escaped.len_utf8()..];
+    }
+
+    octets.extend_from_slice(rest.as_bytes());
+
+    Ok(octets)
+}
+
+/// Parses the fields shared by record types in the format of a DS record
+/// (RFC 4034), where the digest may be split by whitespace.
+fn delegation_signer(fields: &[&str]) -> Result<(u16, u8, u8, String), RecordDataError> {
+    let (key_tag, algorithm, digest_type, digest) = match fields {
+        [key_tag, algorithm, digest_type, digest @ ..] if !digest.is_empty() => {
+            (key_tag, algorithm, digest_type, digest.concat())
+        }
+        _ => {
+            return Err(RecordDataError::FieldCount {
+                expected: 4,
+                found: fields.len(),
+            })
+        }
+    };
+
+    if !digest.len().is_multiple_of(2) || !digest.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(RecordDataError::InvalidField {
+            field: "digest",
+            value: digest,
+        });
+    }
+
+    Ok((
+        parse("key tag", key_tag)?,
+        parse("algorithm", algorithm)?,
+        parse("digest type", digest_type)?,
+        digest.to_ascii_uppercase(),
+    ))
+}
+
+fn is_base64(value: &str) -> bool {
+    let data = value.trim_end_matches('=');
+
+    !data.is_empty()
+        && value.len().is_multiple_of(4)
+        && value.len() - data.len() <= 2
+        && data
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '+' || c == '/')
+}
+
+/// Writes the octets as a quoted character string, escaping quotes and
+/// backslashes, as well as control characters and non-ASCII octets as
+/// `\DDD`.
+fn write_character_string(f: &mut std::fmt::Formatter<'_>, value: &[u8]) -> std::fmt::Result {
+    f.write_char('"')?;
+    for byte in value {
+        match byte {
+            b'"' | b'\\' => write!(f, "\\{}", char::from(*byte))?,
+            b' ' => f.write_char(' ')?,
+            byte if byte.is_ascii_graphic() => f.write_char(char::from(*byte))?,
+            byte => write!(f, "\\{byte:03}")?,
+        }
+    }
+    f.write_char('"')
+}
+
+fn exact<'a, const N: usize>(fields: &[&'a str]) -> Result<[&'a str; N], RecordDataError> {
+    <[&str; N]>::try_from(fields).map_err(|_| RecordDataError::FieldCount {
+        expected: N,
+        found: fields.len(),
+    })
+}
+
+fn parse<T: std::str::FromStr>(field: &'static str, value: &str) -> Result<T, RecordDataError> {
+    value.parse().map_err(|_| RecordDataError::InvalidField {
+        field,
+        value: value.to_string(),
+    })
+}
+
+fn name(
+    field: &'static str,
+    value: &str,
+    origin: Option<&FullyQualifiedDomainName>,
+) -> Result<DomainName, RecordDataError> {
+    if value == "@" {
+        return origin
+            .map(|origin| DomainName::Full(origin.clone()))
+            .ok_or(RecordDataError::UnresolvedOrigin(field));
+    }
+
+    DomainName::try_from(value).map_err(|_| RecordDataError::InvalidField {
+        field,
+        value: value.to_string(),
+    })
+}
+
+/// Parses a domain name field which must be a valid, non-wildcard hostname.
+fn host(
+    field: &'static str,
+    value: &str,
+    origin: Option<&FullyQualifiedDomainName>,
+) -> Result<DomainName, RecordDataError> {
+    if value == "@" {
+        return name(field, value, origin);
+    }
+
+    let options = ParseOptions::default()
+        .validation(Validation::Hostname)
+        .wildcards(WildcardPolicy::Reject);
+
+    DomainName::parse_with(value, options).map_err(|_| RecordDataError::InvalidField {
+        field,
+        value: value.to_string(),
+    })
+}
+
+#[cfg(feature = "valuable")]
+mod valuable_fields {
+    use valuable::{Fields, NamedField, VariantDef};
+
+    const fn named(fields: &'static [NamedField<'static>]) -> Fields<'static> {
+        Fields::Named(fields)
+    }
+
+    pub(super) const AFSDB: &[NamedField<'static>] =
+        &[NamedField::new("subtype"), NamedField::new("hostname")];
+    pub(super) const CAA: &[NamedField<'static>] = &[
+        NamedField::new("flags"),
+        NamedField::new("tag"),
+        NamedField::new("value"),
+    ];
+    pub(super) const DIGEST: &[NamedField<'static>] = &[
+        NamedField::new("key_tag"),
+        NamedField::new("algorithm"),
+        NamedField::new("digest_type"),
+        NamedField::new("digest"),
+    ];
+    pub(super) const HIP: &[NamedField<'static>] = &[
+        NamedField::new("algorithm"),
+        NamedField::new("hit"),
+        NamedField::new("public_key"),
+        NamedField::new("rendezvous_servers"),
+    ];
+    pub(super) const KX: &[NamedField<'static>] =
+        &[NamedField::new("preference"), NamedField::new("exchanger")];
+    pub(super) const MX: &[NamedField<'static>] =
+        &[NamedField::new("preference"), NamedField::new("exchange")];
+    pub(super) const SOA: &[NamedField<'static>] = &[
+        NamedField::new("mname"),
+        NamedField::new("rname"),
+        NamedField::new("serial"),
+        NamedField::new("refresh"),
+        NamedField::new("retry"),
+        NamedField::new("expire"),
+        NamedField::new("minimum"),
+    ];
+    pub(super) const SRV: &[NamedField<'static>] = &[
+        NamedField::new("priority"),
+        NamedField::new("weight"),
+        NamedField::new("port"),
+        NamedField::new("target"),
+    ];
+
+    /// Variants of [`RecordData`](super::RecordData), in order of declaration.
+    pub(super) static VARIANTS: [VariantDef<'static>; 17] = [
+        VariantDef::new("A", Fields::Unnamed(1)),
+        VariantDef::new("AAAA", Fields::Unnamed(1)),
+        VariantDef::new("AFSDB", named(AFSDB)),
+        VariantDef::new("CAA", named(CAA)),
+        VariantDef::new("CNAME", Fields::Unnamed(1)),
+        VariantDef::new("DLV", named(DIGEST)),
+        VariantDef::new("DNAME", Fields::Unnamed(1)),
+        VariantDef::new("HIP", named(HIP)),
+        VariantDef::new("KX", named(KX)),
+        VariantDef::new("MX", named(MX)),
+        VariantDef::new("NS", Fields::Unnamed(1)),
+        VariantDef::new("PTR", Fields::Unnamed(1)),
+        VariantDef::new("SOA", named(SOA)),
+        VariantDef::new("SRV", named(SRV)),
+        VariantDef::new("TA", named(DIGEST)),
+        VariantDef::new("TXT", Fields::Unnamed(1)),
+        VariantDef::new("Other", Fields::Unnamed(1)),
+    ];
+}
+
+/// Recorded as an enum with the parsed fields of each variant, such that
+/// nothing is formatted or allocated. Addresses are recorded as their
+/// octets, or for IPv6, their eight 16-bit segments.
+#[cfg(feature = "valuable")]
+impl valuable::Valuable for RecordData {
+    fn as_value(&self) -> valuable::Value<'_> {
+        valuable::Value::Enumerable(self)
+    }
+
+    fn visit(&self, visit: &mut dyn valuable::Visit) {
+        use valuable::{NamedValues, Value};
+        use valuable_fields as fields;
+
+        match self {
+            RecordData::A(address) => visit.visit_unnamed_fields(&[address.octets().as_value()]),
+            RecordData::AAAA(address) => {
+                visit.visit_unnamed_fields(&[address.segments().as_value()])
+            }
+            RecordData::AFSDB { subtype, hostname } => visit.visit_named_fields(&NamedValues::new(
+                fields::AFSDB,
+                &[Value::U16(*subtype), hostname.as_value()],
+            )),
+            RecordData::CAA { flags, tag, value } => visit.visit_named_fields(&NamedValues::new(
+                fields::CAA,
+                &[Value::U8(*flags), Value::String(tag), value.as_value()],
+            )),
+            RecordData::DLV {
+                key_tag,
+                algorithm,
+                digest_type,
+                digest,
+            }
+            | RecordData::TA {
+                key_tag,
+                algorithm,
+                digest_type,
+                digest,
+            } => visit.visit_named_fields(&NamedValues::new(
+                fields::DIGEST,
+                &[
+                    Value::U16(*key_tag),
+                    Value::U8(*algorithm),
+                    Value::U8(*digest_type),
+                    Value::String(digest),
+                ],
+            )),
+            RecordData::HIP {
+                algorithm,
+                hit,
+                public_key,
+                rendezvous_servers,
+            } => visit.visit_named_fields(&NamedValues::new(
+                fields::HIP,
+                &[
+                    Value::U8(*algorithm),
+                    Value::String(hit),
+                    Value::String(public_key),
+                    rendezvous_servers.as_value(),
+                ],
+            )),
+            RecordData::KX {
+                preference,
+                exchanger,
+            } => visit.visit_named_fields(&NamedValues::new(
+                fields::KX,
+                &[Value::U16(*preference), exchanger.as_value()],
+            )),
+            RecordData::MX {
+                preference,
+                exchange,
+            } => visit.visit_named_fields(&NamedValues::new(
+                fields::MX,
+                &[Value::U16(*preference), exchange.as_value()],
+            )),
+            RecordData::SOA {
+                mname,
+                rname,
+                serial,
+                refresh,
+                retry,
+                expire,
+                minimum,
+            } => visit.visit_named_fields(&NamedValues::new(
+                fields::SOA,
+                &[
+                    mname.as_value(),
+                    rname.as_value(),
+                    Value::U32(*serial),
+                    Value::U32(*refresh),
+                    Value::U32(*retry),
+                    Value::U32(*expire),
+                    Value::U32(*minimum),
+                ],
+            )),
+            RecordData::SRV {
+                priority,
+                weight,
+                port,
+                target,
+            } => visit.visit_named_fields(&NamedValues::new(
+                fields::SRV,
+                &[
+                    Value::U16(*priority),
+                    Value::U16(*weight),
+                    Value::U16(*port),
+                    target.as_value(),
+                ],
+            )),
+            RecordData::CNAME(name)
+            | RecordData::DNAME(name)
+            | RecordData::NS(name)
+            | RecordData::PTR(name) => visit.visit_unnamed_fields(&[name.as_value()]),
+            RecordData::TXT(strings) => visit.visit_unnamed_fields(&[strings.as_value()]),
+            RecordData::Other(rdata) => visit.visit_unnamed_fields(&[Value::String(rdata)]),
+        }
+    }
+}
+
+#[cfg(feature = "valuable")]
+impl valuable::Enumerable for RecordData {
+    fn definition(&self) -> valuable::EnumDef<'_> {
+        valuable::EnumDef::new_static("RecordData", &valuable_fields::VARIANTS)
+    }
+
+    fn variant(&self) -> valuable::Variant<'_> {
+        let index = match self {
+            RecordData::A(_) => 0,
+            RecordData::AAAA(_) => 1,
+            RecordData::AFSDB { .. } => 2,
+            RecordData::CAA { .. } => 3,
+            RecordData::CNAME(_) => 4,
+            RecordData::DLV { .. } => 5,
+            RecordData::DNAME(_) => 6,
+            RecordData::HIP { .. } => 7,
+            RecordData::KX { .. } => 8,
+            RecordData::MX { .. } => 9,
+            RecordData::NS(_) => 10,
+            RecordData::PTR(_) => 11,
+            RecordData::SOA { .. } => 12,
+            RecordData::SRV { .. } => 13,
+            RecordData::TA { .. } => 14,
+            RecordData::TXT(_) => 15,
+            RecordData::Other(_) => 16,
+        };
+
+        valuable::Variant::Static(&valuable_fields::VARIANTS[index])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::Ipv6Addr;
+
+    use crate::{
+        error::RecordDataError, fqdn, DomainName, FullyQualifiedDomainName, RecordData, Type,
+    };
+
+    #[test]
+    fn addresses() {
+        assert_eq!(
+            RecordData::parse(Type::AAAA, "2001:0db8:0:0:0:0:0:1").unwrap(),
+            RecordData::AAAA(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1))
+        );
+
+        assert_eq!(
+            RecordData::parse(Type::A, "10.0.0.256"),
+            Err(RecordDataError::InvalidField {
+                field: "ipv4 address",
+                value: String::from("10.0.0.256")
+            })
+        );
+    }
+
+    #[test]
+    fn field_counts() {
+        assert_eq!(
+            RecordData::parse(Type::MX, "mail.example.org."),
+            Err(RecordDataError::FieldCount {
+                expected: 2,
+                found: 1
+            })
+        );
+
+        assert_eq!(
+            RecordData::parse(Type::MX, "10   Mail.Example.org.")
+                .unwrap()
+                .to_string(),
+            "10 mail.example.org."
+        );
+
+        assert_eq!(
+            RecordData::parse(Type::TXT, "  "),
+            Err(RecordDataError::Empty)
+        );
+    }
+
+    #[test]
+    fn character_strings() {
+        let txt = RecordData::parse(Type::TXT, r#""v=spf1 -all" "say \"hi\"""#).unwrap();
+
+        assert_eq!(
+            txt,
+            RecordData::TXT(vec![b"v=spf1 -all".to_vec(), b"say \"hi\"".to_vec()])
+        );
+        assert_eq!(txt.to_string(), r#""v=spf1 -all" "say \"hi\"""#);
+
+        assert_eq!(
+            RecordData::parse(Type::TXT, r#""unterminated"#),
+            Err(RecordDataError::UnterminatedString)
+        );
+
+        // Decimal escapes denote a single octet, and control characters
+        // are escaped again when displayed.
+        let txt = RecordData::parse(Type::TXT, r#""\065b\\c" d\ e "\009""#).unwrap();
+        assert_eq!(
+            txt,
+            RecordData::TXT(vec![b"Ab\\c".to_vec(), b"d e".to_vec(), b"\t".to_vec()])
+        );
+        assert_eq!(txt.to_string(), r#""Ab\\c" "d e" "\009""#);
+        assert_eq!(RecordData::parse(Type::TXT, &txt.to_string()), Ok(txt));
+        assert!(RecordData::parse(Type::TXT, r#""\256""#).is_err());
+
+        // Character strings are not required to be valid UTF-8.
+        let binary = RecordData::parse(Type::TXT, r#""\255\000" "caf\195\169""#).unwrap();
+        assert_eq!(
+            binary,
+            RecordData::TXT(vec![vec![255, 0], "café".as_bytes().to_vec()])
+        );
+        assert_eq!(binary.to_string(), r#""\255\000" "caf\195\169""#);
+        assert_eq!(
+            RecordData::parse(Type::TXT, &binary.to_string()),
+            Ok(binary)
+        );
+
+        assert_eq!(
+            RecordData::parse(Type::CAA, r#"0 issue "letsencrypt.org""#)
+                .unwrap()
+                .to_string(),
+            r#"0 issue "letsencrypt.org""#
+        );
+    }
+
+    #[test]
+    fn names() {
+        // Null MX (RFC 7505).
+        let mx = RecordData::parse(Type::MX, "0 .").unwrap();
+        assert_eq!(
+            mx,
+            RecordData::MX {
+                preference: 0,
+                exchange: DomainName::Full(FullyQualifiedDomainName::default()),
+            }
+        );
+        assert_eq!(mx.to_string(), "0 .");
+
+        // Escapes within names are resolved by the name itself.
+        let cname = RecordData::parse(Type::CNAME, r"a\.b.example.org.").unwrap();
+        assert_eq!(cname.to_string(), r"a\.b.example.org.");
+        let RecordData::CNAME(DomainName::Full(target)) = cname else {
+            panic!("target is fully qualified");
+        };
+        assert_eq!(target.iter().count(), 3);
+
+        assert!(mx.is_valid_for(Type::MX));
+        assert!(!mx.is_valid_for(Type::KX));
+        assert!(RecordData::Other(String::from("x")).is_valid_for(Type::DNSKEY));
+        assert!(!RecordData::Other(String::from("x")).is_valid_for(Type::MX));
+    }
+
+    #[test]
+    fn hip() {
+        let hip = RecordData::parse(Type::HIP, "2 2001abcd AwEAAQ== rvs.example.com.").unwrap();
+
+        assert_eq!(hip.to_string(), "2 2001ABCD AwEAAQ== rvs.example.com.");
+
+        assert_eq!(
+            RecordData::parse(Type::HIP, "2 2001abc AwEAAQ=="),
+            Err(RecordDataError::InvalidField {
+                field: "hit",
+                value: String::from("2001abc")
+            })
+        );
+
+        assert_eq!(
+            RecordData::parse(Type::HIP, "2 2001abcd AwEA!Q=="),
+            Err(RecordDataError::InvalidField {
+                field: "public key",
+                value: String::from("AwEA!Q==")
+            })
+        );
+
+        assert_eq!(
+            RecordData::parse(Type::HIP, "2 2001abcd"),
+            Err(RecordDataError::FieldCount {
+                expected: 3,
+                found: 2
+            })
+        );
+    }
+
+    #[test]
+    fn delegation_signers() {
+        let ta = RecordData::parse(Type::TA, "20326 8 2 e06d44b8 0b8f1d39").unwrap();
+
+        assert_eq!(
+            ta,
+            RecordData::TA {
+                key_tag: 20326,
+                algorithm: 8,
+                digest_type: 2,
+                digest: String::from("E06D44B80B8F1D39"),
+            }
+        );
+        assert_eq!(ta.to_string(), "20326 8 2 E06D44B80B8F1D39");
+        assert_eq!(ta.r#type(), Some(Type::TA));
+
+        assert_eq!(
+            RecordData::parse(Type::DLV, "20326 8 2").unwrap_err(),
+            RecordDataError::FieldCount {
+                expected: 4,
+                found: 3
+            }
+        );
+        assert_eq!(
+            RecordData::parse(Type::DLV, "20326 8 2 E06D44B8X"),
+            Err(RecordDataError::InvalidField {
+                field: "digest",
+                value: String::from("E06D44B8X")
+            })
+        );
+        assert_eq!(
+            RecordData::parse(Type::DLV, "65536 8 2 E06D44B8"),
+            Err(RecordDataError::InvalidField {
+                field: "key tag",
+                value: String::from("65536")
+            })
+        );
+    }
+
+    #[test]
+    fn host_targets() {
+        assert_eq!(
+            RecordData::parse(Type::KX, "10 KX1.example.org."),
+            Ok(RecordData::KX {
+                preference: 10,
+                exchanger: DomainName::try_from("kx1.example.org.").unwrap()
+            })
+        );
+        assert_eq!(
+            RecordData::parse_with_origin(Type::AFSDB, "2 @", &fqdn!("example.org.")),
+            Ok(RecordData::AFSDB {
+                subtype: 2,
+                hostname: DomainName::try_from("example.org.").unwrap()
+            })
+        );
+
+        for (r#type, rdata, field) in [
+            (Type::AFSDB, "1 _afsdb.example.org.", "hostname"),
+            (Type::AFSDB, "1 *.example.org.", "hostname"),
+            (Type::KX, "10 kx_1", "exchanger"),
+        ] {
+            assert_eq!(
+                RecordData::parse(r#type, rdata),
+                Err(RecordDataError::InvalidField {
+                    field,
+                    value: rdata[rdata.find(' ').unwrap() + 1..].to_string()
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        for (r#type, rdata) in [
+            (Type::A, "10.0.0.1"),
+            (Type::CNAME, "www.example.org."),
+            (Type::NS, "ns1"),
+            (
+                Type::SOA,
+                "ns1.example.org. hostmaster.example.org. 2024010101 7200 3600 1209600 3600",
+            ),
+            (Type::SRV, "10 5 5060 sip.example.org."),
+            (Type::SSHFP, "2 1 123456789abcdef"),
+            (Type::AFSDB, "1 afsdb.example.org."),
+            (Type::KX, "10 kx"),
+            (
+                Type::HIP,
+                "2 200100107B1A74DF365639CC39F1D578 AwEAAbdxyhNuSutc5EMzxTs9LBPCIkOFH8cIvM4p9+LrV4e19WzK00+CI6zBCQTdtWsuxKbWIy87UOoJTwkUs7lBu+Upr1gsNrut79ryra+bSRGQb1slImA8YVJyuIDsj7kwzG7jnERNqnWxZ48AWkskmdHaVDP4BcelrTI3rMXdXF5D rvs1.example.com. rvs2.example.com.",
+            ),
+        ] {
+            assert_eq!(RecordData::parse(r#type, rdata).unwrap().to_string(), rdata);
+        }
+    }
+
+    #[test]
+    fn origin_substitution() {
+        let origin = fqdn!("example.org.");
+
+        assert_eq!(
+            RecordData::parse_with_origin(Type::MX, "10 @", &origin)
+                .unwrap()
+                .to_string(),
+            "10 example.org."
+        );
+        assert_eq!(
+            RecordData::parse_with_origin(
+                Type::SOA,
+                "ns1 hostmaster 1 7200 3600 1209600 3600",
+                &origin
+            )
+            .unwrap()
+            .to_string(),
+            "ns1.example.org. hostmaster.example.org. 1 7200 3600 1209600 3600"
+        );
+        assert_eq!(
+            RecordData::parse_with_origin(Type::TXT, "@", &origin),
+            Ok(RecordData::TXT(vec![b"@".to_vec()]))
+        );
+        assert_eq!(
+            RecordData::parse(Type::CNAME, "@"),
+            Err(RecordDataError::UnresolvedOrigin("target"))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "valuable")]
+    fn valuable() {
+        use valuable::{Enumerable, NamedValues, Valuable, Visit};
+
+        #[derive(Default)]
+        struct Fields(Vec<String>);
+
+        impl Visit for Fields {
+            fn visit_value(&mut self, _: valuable::Value<'_>) {}
+
+            fn visit_named_fields(&mut self, named_values: &NamedValues<'_>) {
+                for (field, value) in named_values {
+                    self.0.push(format!("{}={value:?}", field.name()));
+                }
+            }
+        }
+
+        let rdata = RecordData::parse(Type::MX, "10 mail.example.org.").unwrap();
+        assert_eq!(rdata.variant().name(), "MX");
+
+        let mut fields = Fields::default();
+        rdata.visit(&mut fields);
+        assert_eq!(fields.0[0], "preference=10");
+        assert!(fields.0[1].starts_with("exchange="));
+    }
+}
@@ -0,0 +1,321 @@
+use std::{fmt::Display, str::FromStr};
+
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+#[cfg(feature = "serde")]
+use serde::{de::Error as _, Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::{
+    error::{FullyQualifiedDomainNameError, RecordDataError, TtlError},
+    Class, FullyQualifiedDomainName, RecordData, RecordIdent, Ttl, Type,
+};
+
+/// Produced when parsing a [`Record`] from a single presentation format line.
+#[derive(Error, Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum RecordError {
+    /// The owner name is not a valid fully qualified domain name.
+    #[error("invalid owner name: {0}")]
+    InvalidOwner(#[from] FullyQualifiedDomainNameError),
+    /// The TTL is neither a number of seconds, nor a BIND duration.
+    #[error("invalid ttl: {0}")]
+    InvalidTtl(#[from] TtlError),
+    /// The line does not contain a known record type.
+    #[error("missing or unknown record type")]
+    MissingType,
+    /// The record data is not valid for the record type.
+    #[error("invalid record data: {0}")]
+    InvalidRecordData(#[from] RecordDataError),
+}
+
+/// A single DNS resource record.
+///
+/// Serialized as a map with the record data in presentation format, which
+/// is parsed according to the record type when deserializing:
+///
+/// ```text
+/// fqdn: www.example.org.
+/// class: IN
+/// type: A
+/// ttl: 3600
+/// rdata: 192.168.0.1
+/// ```
+///
+/// The class defaults to [`Class::IN`], and the TTL may be given
+/// as a BIND style duration such as `1h`.
+///
+/// Parsing always produces record data belonging to the record type, which
+/// [`Zone::insert`](crate::Zone::insert) also requires of records
+/// constructed otherwise, see [`RecordData::is_valid_for`].
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Record {
+    pub fqdn: FullyQualifiedDomainName,
+    pub class: Class,
+    pub r#type: Type,
+    pub ttl: u32,
+    pub rdata: RecordData,
+}
+
+impl Record {
+    /// Returns the [`RecordIdent`] uniquely identifying this record within a zone.
+    pub fn ident(&self) -> RecordIdent {
+        RecordIdent {
+            fqdn: self.fqdn.clone(),
+            r#type: self.r#type,
+            rdata: self.rdata.clone(),
+        }
+    }
+}
+
+impl From<Record> for RecordIdent {
+    /// Drops the class and TTL of the record.
+    fn from(value: Record) -> Self {
+        RecordIdent {
+            fqdn: value.fqdn,
+            r#type: value.r#type,
+            rdata: value.rdata,
+        }
+    }
+}
+
+impl Display for Record {
+    /// Renders the record as a single zone file line, with all names fully qualified.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {} {} {} {}",
+            self.fqdn, self.ttl, self.class, self.r#type, self.rdata
+        )
+    }
+}
+
+/// Splits off the first whitespace-separated token.
+fn token(line: &str) -> Option<(&str, &str)> {
+    let line = line.trim_start();
+    if line.is_empty() {
+        return None;
+    }
+
+    Some(line.split_once(char::is_whitespace).unwrap_or((line, "")))
+}
+
+impl TryFrom<&str> for Record {
+    type Error = RecordError;
+
+    /// Parses a single presentation format line, as printed by `dig`:
+    ///
+    /// ```text
+    /// www.example.org. 3600 IN A 192.168.0.1
+    /// ```
+    ///
+    /// The owner name must be fully qualified. TTL and class may appear in
+    /// either order, and may be omitted, defaulting to a TTL of zero and
+    /// [`Class::IN`] respectively. Unlike the zone file parser, directives,
+    /// comments and parentheses are not supported.
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let (owner, mut rest) = token(value).ok_or(RecordError::MissingType)?;
+        let fqdn = FullyQualifiedDomainName::try_from(owner)?;
+
+        let (mut ttl, mut class) = (None, None);
+
+        let r#type = loop {
+            let (field, remainder) = token(rest).ok_or(RecordError::MissingType)?;
+            rest = remainder;
+
+            if ttl.is_none() && field.starts_with(|c: char| c.is_ascii_digit()) {
+                ttl = Some(Ttl::try_from(field)?);
+            } else if let (None, Ok(parsed)) = (class, field.parse()) {
+                class = Some(parsed);
+            } else {
+                break field.parse().map_err(|_| RecordError::MissingType)?;
+            }
+        };
+
+        Ok(Record {
+            fqdn,
+            class: class.unwrap_or_default(),
+            r#type,
+            ttl: ttl.unwrap_or_default().as_secs(),
+            rdata: RecordData::parse(r#type, rest)?,
+        })
+    }
+}
+
+impl TryFrom<String> for Record {
+    type Error = RecordError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+
+impl FromStr for Record {
+    type Err = RecordError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+/// Serialized representation of a [`Record`].
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema), schemars(rename = "Record"))]
+struct RecordRepr {
+    fqdn: FullyQualifiedDomainName,
+    #[serde(default)]
+    class: Class,
+    r#type: Type,
+    ttl: Ttl,
+    rdata: String,
+}
+
+#[cfg(feature = "schemars")]
+impl JsonSchema for Record {
+    fn schema_name() -> String {
+        RecordRepr::schema_name()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        RecordRepr::json_schema(gen)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Record {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let repr = RecordRepr::deserialize(deserializer)?;
+        let rdata = RecordData::parse(repr.r#type, &repr.rdata).map_err(D::Error::custom)?;
+
+        Ok(Record {
+            fqdn: repr.fqdn,
+            class: repr.class,
+            r#type: repr.r#type,
+            ttl: repr.ttl.as_secs(),
+            rdata,
+        })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Record {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        /// Serialized like [`RecordRepr`], but with the TTL as is, such that
+        /// records with TTLs beyond [`Ttl::MAX`] can still be serialized.
+        #[derive(Serialize)]
+        struct RecordReprRef<'a> {
+            fqdn: &'a FullyQualifiedDomainName,
+            class: Class,
+            r#type: Type,
+            ttl: u32,
+            rdata: String,
+        }
+
+        RecordReprRef {
+            fqdn: &self.fqdn,
+            class: self.class,
+            r#type: self.r#type,
+            ttl: self.ttl,
+            rdata: self.rdata.to_string(),
+        }
+        .serialize(serializer)
+    }
+}
+
+/// Parses a record from a line in zone file format, such as
+/// `www.example.org. 300 IN A 192.168.0.1`, panicking if it is invalid.
+#[cfg(test)]
+pub(crate) fn record(line: &str) -> Record {
+    line.parse().unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::{RecordDataError, RecordError},
+        Class, FullyQualifiedDomainName, Record, RecordData, Type,
+    };
+
+    #[test]
+    fn display() {
+        let record = Record {
+            fqdn: FullyQualifiedDomainName::try_from("example.org.").unwrap(),
+            class: Class::IN,
+            r#type: Type::MX,
+            ttl: 300,
+            rdata: RecordData::parse(Type::MX, "10 mail.example.org.").unwrap(),
+        };
+
+        assert_eq!(
+            record.to_string(),
+            "example.org. 300 IN MX 10 mail.example.org."
+        );
+    }
+
+    #[test]
+    fn presentation_lines() {
+        let record: Record = "www.example.org.\t300\tIN\tTXT\t\"hello  world\" \"!\""
+            .parse()
+            .unwrap();
+
+        assert_eq!(record.ttl, 300);
+        assert_eq!(record.r#type, Type::TXT);
+        assert_eq!(record.to_string().parse(), Ok(record));
+
+        let record: Record = "example.org. CH 1h NS ns1.example.org.".parse().unwrap();
+        assert_eq!((record.class, record.ttl), (Class::CH, 3600));
+
+        let record: Record = "example.org. A 10.0.0.1".parse().unwrap();
+        assert_eq!((record.class, record.ttl), (Class::IN, 0));
+
+        assert_eq!(
+            "example.org. 300 IN".parse::<Record>(),
+            Err(RecordError::MissingType)
+        );
+        assert_eq!(
+            "example.org. 300 IN A".parse::<Record>(),
+            Err(RecordError::InvalidRecordData(RecordDataError::Empty))
+        );
+        assert!(matches!(
+            "www.example.org 300 IN A 10.0.0.1".parse::<Record>(),
+            Err(RecordError::InvalidOwner(_))
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let record: Record = serde_yaml::from_str(
+            "fqdn: www.example.org.\ntype: AAAA\nttl: 1h\nrdata: 2001:0db8::0001\n",
+        )
+        .unwrap();
+
+        assert_eq!(record.class, Class::IN);
+        assert_eq!(record.ttl, 3600);
+        assert_eq!(record.rdata.to_string(), "2001:db8::1");
+
+        assert_eq!(
+            serde_yaml::to_string(&record).unwrap(),
+            "fqdn: www.example.org.\nclass: IN\ntype: AAAA\nttl: 3600\nrdata: 2001:db8::1\n"
+        );
+
+        assert!(serde_yaml::from_str::<Record>(
+            "fqdn: www.example.org.\ntype: A\nttl: 300\nrdata: www.example.org.\n"
+        )
+        .is_err());
+
+        let record = Record {
+            ttl: u32::MAX,
+            ..record
+        };
+        assert!(serde_yaml::to_string(&record)
+            .unwrap()
+            .contains("ttl: 4294967295\n"));
+    }
+}
@@ -0,0 +1,236 @@
+use crate::{DomainSegment, FullyQualifiedDomainName};
+
+/// Snapshot of the [Public Suffix List](https://publicsuffix.org/list/),
+/// parsed on first use.
+#[cfg(feature = "psl")]
+fn bundled_list() -> &'static publicsuffix::List {
+    static LIST: std::sync::OnceLock<publicsuffix::List> = std::sync::OnceLock::new();
+
+    LIST.get_or_init(|| {
+        include_str!("../data/public_suffix_list.dat")
+            .parse()
+            .expect("bundled public suffix list is valid")
+    })
+}
+
+impl FullyQualifiedDomainName {
+    /// Top-level domain of the name, such as `org` for `www.example.org.`,
+    /// or [`None`] for the root.
+    pub fn tld(&self) -> Option<&DomainSegment> {
+        self.0.last()
+    }
+
+    /// Returns true if both names belong to the same top-level domain.
+    ///
+    /// The root does not share a top-level domain with any name.
+    pub fn ends_with_tld_of(&self, other: &FullyQualifiedDomainName) -> bool {
+        self.tld().is_some_and(|tld| other.tld() == Some(tld))
+    }
+
+    /// Domain which can be registered at a registrar, such that
+    /// `www.example.org.` yields `example.org.`
+    ///
+    /// With the `psl` feature, this consults the snapshot of the Public
+    /// Suffix List bundled with the crate, such that `www.example.co.uk.`
+    /// yields `example.co.uk.`, see `registrable_domain_in` for consulting
+    /// another version of the list. Otherwise, every top-level domain is
+    /// assumed to be the only public suffix, which is incorrect for names
+    /// below multi-label public suffixes, such as `co.uk`.
+    pub fn registrable_domain(&self) -> Option<FullyQualifiedDomainName> {
+        self.last_labels(self.public_suffix_labels()? + 1)
+    }
+
+    /// Public suffix of the name according to the bundled snapshot of the
+    /// Public Suffix List, see `public_suffix_in`.
+    #[cfg(feature = "psl")]
+    pub fn public_suffix(&self) -> Option<FullyQualifiedDomainName> {
+        self.public_suffix_in(bundled_list())
+    }
+
+    /// Returns true if the name is itself a public suffix according to the
+    /// bundled snapshot of the Public Suffix List, see `is_public_suffix_in`.
+    #[cfg(feature = "psl")]
+    pub fn is_public_suffix(&self) -> bool {
+        self.is_public_suffix_in(bundled_list())
+    }
+
+    /// Returns true if both names are within the same [registrable domain](FullyQualifiedDomainName::registrable_domain).
+    pub fn shares_registrable_domain_with(&self, other: &FullyQualifiedDomainName) -> bool {
+        self.registrable_domain()
+            .is_some_and(|domain| other.registrable_domain() == Some(domain))
+    }
+
+    /// Domain which can be registered at a registrar according to the
+    /// given Public Suffix List, such that `www.example.co.uk.` yields
+    /// `example.co.uk.`
+    ///
+    /// Returns [`None`] if the name is itself a public suffix.
+    #[cfg(feature = "psl")]
+    pub fn registrable_domain_in(
+        &self,
+        list: &impl publicsuffix::Psl,
+    ) -> Option<FullyQualifiedDomainName> {
+        self.last_labels(self.public_suffix_labels_in(list)? + 1)
+    }
+
+    /// Public suffix of the name according to the given Public Suffix List,
+    /// below which domains can be registered, such that `www.example.co.uk.`
+    /// yields `co.uk.`
+    ///
+    /// Names without a matching rule are treated as if their top-level domain
+    /// was listed, as prescribed by the list's algorithm.
+    #[cfg(feature = "psl")]
+    pub fn public_suffix_in(
+        &self,
+        list: &impl publicsuffix::Psl,
+    ) -> Option<FullyQualifiedDomainName> {
+        self.last_labels(self.public_suffix_labels_in(list)?)
+    }
+
+    /// Returns true if the name is itself a public suffix according to the
+    /// given Public Suffix List, such that registering it would claim every
+    /// domain below it, as is the case for `co.uk.`
+    #[cfg(feature = "psl")]
+    pub fn is_public_suffix_in(&self, list: &impl publicsuffix::Psl) -> bool {
+        self.public_suffix_in(list).as_ref() == Some(self)
+    }
+
+    /// Returns true if both names are within the same registrable domain
+    /// according to the given Public Suffix List.
+    #[cfg(feature = "psl")]
+    pub fn shares_registrable_domain_in(
+        &self,
+        other: &FullyQualifiedDomainName,
+        list: &impl publicsuffix::Psl,
+    ) -> bool {
+        self.registrable_domain_in(list)
+            .is_some_and(|domain| other.registrable_domain_in(list) == Some(domain))
+    }
+
+    /// Number of labels making up the public suffix of the name, according
+    /// to the given list, or [`None`] for the root.
+    #[cfg(feature = "psl")]
+    fn public_suffix_labels_in(&self, list: &impl publicsuffix::Psl) -> Option<usize> {
+        let labels: Vec<Vec<u8>> = self
+            .iter()
+            .rev()
+            .map(|label| label.as_raw().to_ascii_lowercase())
+            .collect();
+
+        // Length of the suffix in octets, including the dots between its labels.
+        let mut remaining = list.find(labels.iter().map(Vec::as_slice)).len;
+
+        let count = labels
+            .iter()
+            .take_while(|label| {
+                let within = remaining > 0;
+                remaining = remaining.saturating_sub(label.len() + 1);
+                within
+            })
+            .count();
+
+        (count > 0).then_some(count)
+    }
+
+    /// Number of labels making up the public suffix of the name, see
+    /// [`FullyQualifiedDomainName::registrable_domain`].
+    #[cfg(feature = "psl")]
+    fn public_suffix_labels(&self) -> Option<usize> {
+        self.public_suffix_labels_in(bundled_list())
+    }
+
+    /// Number of labels making up the public suffix of the name, see
+    /// [`FullyQualifiedDomainName::registrable_domain`].
+    #[cfg(not(feature = "psl"))]
+    fn public_suffix_labels(&self) -> Option<usize> {
+        self.tld().map(|_| 1)
+    }
+
+    /// Name consisting of the last `count` labels, if there are that many.
+    fn last_labels(&self, count: usize) -> Option<FullyQualifiedDomainName> {
+        let start = self.0.len().checked_sub(count)?;
+
+        Some(FullyQualifiedDomainName::from_iter(&self.0[start..]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fqdn;
+
+    #[test]
+    fn registrable_domains() {
+        let www = fqdn!("www.example.org.");
+
+        assert_eq!(www.tld().unwrap().as_presentation(), "org");
+        assert!(www.ends_with_tld_of(&fqdn!("example.org.")));
+        assert!(!www.ends_with_tld_of(&fqdn!("example.com.")));
+        assert!(!crate::FullyQualifiedDomainName::default()
+            .ends_with_tld_of(&crate::FullyQualifiedDomainName::default()));
+
+        assert_eq!(www.registrable_domain(), Some(fqdn!("example.org.")));
+        assert_eq!(fqdn!("org.").registrable_domain(), None);
+        assert!(www.shares_registrable_domain_with(&fqdn!("api.dev.example.org.")));
+        assert!(!www.shares_registrable_domain_with(&fqdn!("example.net.")));
+    }
+
+    #[test]
+    #[cfg(feature = "psl")]
+    fn public_suffix_list() {
+        let list: publicsuffix::List =
+            "// ===BEGIN ICANN DOMAINS===\norg\nuk\nco.uk\n// ===END ICANN DOMAINS===\n"
+                .parse()
+                .unwrap();
+
+        assert_eq!(
+            fqdn!("www.example.co.uk.").registrable_domain_in(&list),
+            Some(fqdn!("example.co.uk."))
+        );
+        assert_eq!(fqdn!("co.uk.").registrable_domain_in(&list), None);
+
+        assert_eq!(
+            fqdn!("www.example.co.uk.").public_suffix_in(&list),
+            Some(fqdn!("co.uk."))
+        );
+        assert!(fqdn!("co.uk.").is_public_suffix_in(&list));
+        assert!(fqdn!("internal.").is_public_suffix_in(&list));
+        assert!(!fqdn!("example.co.uk.").is_public_suffix_in(&list));
+        assert!(!crate::FullyQualifiedDomainName::default().is_public_suffix_in(&list));
+        assert!(!fqdn!("a.co.uk.").shares_registrable_domain_in(&fqdn!("b.co.uk."), &list));
+        assert!(
+            fqdn!("www.example.org.").shares_registrable_domain_in(&fqdn!("example.org."), &list)
+        );
+
+        // Labels are matched as is, rather than split at escaped dots.
+        let escaped = crate::FullyQualifiedDomainName::try_from("www.example\\.co.uk.").unwrap();
+        assert_eq!(escaped.public_suffix_in(&list), Some(fqdn!("uk.")));
+        assert_eq!(
+            escaped.registrable_domain_in(&list).unwrap().to_string(),
+            "example\\.co.uk."
+        );
+        assert_eq!(
+            fqdn!("WWW.Example.CO.UK.").registrable_domain_in(&list),
+            Some(fqdn!("Example.CO.UK."))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "psl")]
+    fn bundled_public_suffix_list() {
+        assert_eq!(
+            fqdn!("www.example.co.uk.").registrable_domain(),
+            Some(fqdn!("example.co.uk."))
+        );
+        assert_eq!(
+            fqdn!("www.example.co.uk.").public_suffix(),
+            Some(fqdn!("co.uk."))
+        );
+        assert!(fqdn!("co.uk.").is_public_suffix());
+        assert!(!fqdn!("example.co.uk.").is_public_suffix());
+        assert!(!fqdn!("a.co.uk.").shares_registrable_domain_with(&fqdn!("b.co.uk.")));
+        assert_eq!(
+            crate::FullyQualifiedDomainName::default().public_suffix(),
+            None
+        );
+    }
+}
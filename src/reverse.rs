@@ -0,0 +1,139 @@
+use std::{
+    borrow::Cow,
+    net::{IpAddr, Ipv4Addr, Ipv6Addr},
+};
+
+use crate::{DomainSegment, FullyQualifiedDomainName};
+
+const IPV4_SUFFIX: [&str; 2] = ["in-addr", "arpa"];
+const IPV6_SUFFIX: [&str; 2] = ["ip6", "arpa"];
+
+/// Constructs a name from the given labels, followed by the suffix.
+fn with_suffix(
+    labels: impl Iterator<Item = String>,
+    suffix: [&str; 2],
+) -> FullyQualifiedDomainName {
+    labels
+        .map(|label| DomainSegment::new_unchecked(&label))
+        .chain(suffix.map(DomainSegment::new_unchecked))
+        .collect()
+}
+
+impl FullyQualifiedDomainName {
+    /// Reverse lookup name of an IPv4 address, such as
+    /// `1.0.168.192.in-addr.arpa.` for `192.168.0.1`.
+    pub fn from_ipv4_ptr(address: Ipv4Addr) -> Self {
+        with_suffix(
+            address
+                .octets()
+                .into_iter()
+                .rev()
+                .map(|octet| octet.to_string()),
+            IPV4_SUFFIX,
+        )
+    }
+
+    /// Reverse lookup name of an IPv6 address in nibble format, such as
+    /// `1.0.0.0.[...].0.ip6.arpa.` for `::1`.
+    pub fn from_ipv6_ptr(address: Ipv6Addr) -> Self {
+        with_suffix(
+            address
+                .octets()
+                .into_iter()
+                .rev()
+                .flat_map(|octet| [octet & 0x0f, octet >> 4])
+                .map(|nibble| format!("{nibble:x}")),
+            IPV6_SUFFIX,
+        )
+    }
+
+    /// Reverse lookup name of an IPv4 or IPv6 address.
+    pub fn from_ip_ptr(address: IpAddr) -> Self {
+        match address {
+            IpAddr::V4(address) => Self::from_ipv4_ptr(address),
+            IpAddr::V6(address) => Self::from_ipv6_ptr(address),
+        }
+    }
+
+    /// Parses the address from a reverse lookup name produced by
+    /// [`FullyQualifiedDomainName::from_ip_ptr`].
+    ///
+    /// Returns [`None`] if the name is not a reverse lookup name for a
+    /// complete address, such as names of reverse zones like `168.192.in-addr.arpa.`
+    pub fn to_ip_addr(&self) -> Option<IpAddr> {
+        let labels: Vec<Cow<str>> = self.iter().map(DomainSegment::as_presentation).collect();
+        let (address, suffix) = labels.split_at(labels.len().checked_sub(2)?);
+
+        if suffix == IPV4_SUFFIX && address.len() == 4 {
+            let mut octets = [0u8; 4];
+            for (octet, label) in octets.iter_mut().rev().zip(address) {
+                // Reject non-canonical forms such as leading zeroes.
+                *octet = label
+                    .parse()
+                    .ok()
+                    .filter(|octet: &u8| octet.to_string() == *label)?;
+            }
+
+            return Some(IpAddr::V4(Ipv4Addr::from(octets)));
+        }
+
+        if suffix == IPV6_SUFFIX && address.len() == 32 {
+            let mut octets = [0u8; 16];
+            for (octet, nibbles) in octets.iter_mut().rev().zip(address.chunks(2)) {
+                let [low, high] = [&nibbles[0], &nibbles[1]].map(|nibble| {
+                    let mut characters = nibble.chars();
+                    match (characters.next(), characters.next()) {
+                        (Some(character), None) => character.to_digit(16),
+                        _ => None,
+                    }
+                });
+
+                *octet = (high? << 4 | low?) as u8;
+            }
+
+            return Some(IpAddr::V6(Ipv6Addr::from(octets)));
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+    use crate::FullyQualifiedDomainName;
+
+    #[test]
+    fn ipv4() {
+        let address = Ipv4Addr::new(192, 168, 0, 1);
+        let fqdn = FullyQualifiedDomainName::from_ipv4_ptr(address);
+
+        assert_eq!(fqdn.to_string(), "1.0.168.192.in-addr.arpa.");
+        assert_eq!(fqdn.to_ip_addr(), Some(IpAddr::V4(address)));
+
+        for name in [
+            "168.192.in-addr.arpa.",
+            "01.0.168.192.in-addr.arpa.",
+            "arpa.",
+        ] {
+            let fqdn = FullyQualifiedDomainName::try_from(name).unwrap();
+            assert_eq!(fqdn.to_ip_addr(), None);
+        }
+    }
+
+    #[test]
+    fn ipv6() {
+        let address: Ipv6Addr = "2001:db8::567:89ab".parse().unwrap();
+        let fqdn = FullyQualifiedDomainName::from_ip_ptr(IpAddr::V6(address));
+
+        assert_eq!(
+            fqdn.to_string(),
+            "b.a.9.8.7.6.5.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.8.b.d.0.1.0.0.2.ip6.arpa."
+        );
+        assert_eq!(fqdn.to_ip_addr(), Some(IpAddr::V6(address)));
+
+        let parsed = FullyQualifiedDomainName::try_from(fqdn.to_string().to_uppercase()).unwrap();
+        assert_eq!(parsed.to_ip_addr(), Some(IpAddr::V6(address)));
+    }
+}
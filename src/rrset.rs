@@ -0,0 +1,283 @@
+use std::collections::{btree_map::Entry, BTreeMap, BTreeSet};
+
+use thiserror::Error;
+
+use crate::{
+    diff::{ChangeBatch, RecordSet},
+    Class, FullyQualifiedDomainName, Record, Type,
+};
+
+/// Produced when a record would violate the invariants upheld by [`RRSets`].
+#[derive(Error, Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum RRSetError {
+    /// A name may only have a single CNAME record.
+    #[error("{0} already has a CNAME record")]
+    MultipleCnames(FullyQualifiedDomainName),
+    /// A name with a CNAME record may not have records of any other type,
+    /// except for those used by DNSSEC.
+    #[error("{fqdn} CNAME record conflicts with {} record", .r#type)]
+    CnameConflict {
+        fqdn: FullyQualifiedDomainName,
+        r#type: Type,
+    },
+}
+
+type Key = (FullyQualifiedDomainName, Class, Type);
+
+/// Collection of records grouped into [`RecordSet`]s by owner, class and type.
+///
+/// Each set contains unique record data, and uses the lowest TTL of the
+/// records it was built from. Names with a CNAME record may have only that
+/// single CNAME, along with DNSSEC records.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RRSets {
+    sets: BTreeMap<Key, RecordSet>,
+}
+
+/// Types which may coexist with a CNAME record at the same name (RFC 4035).
+fn coexists_with_cname(r#type: Type) -> bool {
+    matches!(r#type, Type::RRSIG | Type::NSEC | Type::NSEC3)
+}
+
+impl RRSets {
+    /// Constructs an empty collection.
+    pub fn new() -> Self {
+        RRSets::default()
+    }
+
+    /// Constructs a collection from the given records.
+    pub fn from_records<'a>(
+        records: impl IntoIterator<Item = &'a Record>,
+    ) -> Result<Self, RRSetError> {
+        let mut sets = RRSets::new();
+
+        for record in records {
+            sets.insert(record)?;
+        }
+
+        Ok(sets)
+    }
+
+    /// Checks whether adding a record of the given type to `fqdn` would
+    /// violate the CNAME invariants.
+    fn check(
+        &self,
+        fqdn: &FullyQualifiedDomainName,
+        class: Class,
+        r#type: Type,
+    ) -> Result<(), RRSetError> {
+        let mut types = self
+            .sets
+            .range((fqdn.clone(), class, Type::MIN)..)
+            .take_while(|((name, set_class, _), _)| name == fqdn && *set_class == class)
+            .map(|(_, set)| set.r#type)
+            .filter(|existing| *existing != r#type);
+
+        match r#type {
+            Type::CNAME => match types.find(|existing| !coexists_with_cname(*existing)) {
+                Some(existing) => Err(RRSetError::CnameConflict {
+                    fqdn: fqdn.clone(),
+                    r#type: existing,
+                }),
+                None => Ok(()),
+            },
+            r#type
+                if !coexists_with_cname(r#type)
+                    && types.any(|existing| existing == Type::CNAME) =>
+            {
+                Err(RRSetError::CnameConflict {
+                    fqdn: fqdn.clone(),
+                    r#type,
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Adds the record to its record set, lowering the TTL of the set if
+    /// the record's TTL is lower.
+    ///
+    /// Returns false if the set already contained the record data.
+    pub fn insert(&mut self, record: &Record) -> Result<bool, RRSetError> {
+        self.check(&record.fqdn, record.class, record.r#type)?;
+
+        match self
+            .sets
+            .entry((record.fqdn.clone(), record.class, record.r#type))
+        {
+            Entry::Vacant(entry) => {
+                entry.insert(RecordSet {
+                    fqdn: record.fqdn.clone(),
+                    class: record.class,
+                    r#type: record.r#type,
+                    ttl: record.ttl,
+                    rdata: BTreeSet::from([record.rdata.clone()]),
+                });
+
+                Ok(true)
+            }
+            Entry::Occupied(mut entry) => {
+                let set = entry.get_mut();
+
+                if record.r#type == Type::CNAME && !set.rdata.contains(&record.rdata) {
+                    return Err(RRSetError::MultipleCnames(record.fqdn.clone()));
+                }
+
+                set.ttl = set.ttl.min(record.ttl);
+                Ok(set.rdata.insert(record.rdata.clone()))
+            }
+        }
+    }
+
+    /// Removes the record from its record set, removing the set entirely
+    /// if it becomes empty.
+    ///
+    /// Returns false if the record was not present.
+    pub fn remove(&mut self, record: &Record) -> bool {
+        let key = (record.fqdn.clone(), record.class, record.r#type);
+
+        let Some(set) = self.sets.get_mut(&key) else {
+            return false;
+        };
+
+        let removed = set.rdata.remove(&record.rdata);
+        if set.rdata.is_empty() {
+            self.sets.remove(&key);
+        }
+
+        removed
+    }
+
+    /// Returns the record set with the given owner, class and type.
+    pub fn get(
+        &self,
+        fqdn: &FullyQualifiedDomainName,
+        class: Class,
+        r#type: Type,
+    ) -> Option<&RecordSet> {
+        self.sets.get(&(fqdn.clone(), class, r#type))
+    }
+
+    /// Adds all records of `other` to this collection.
+    ///
+    /// Fails without modifying this collection if the combined
+    /// records would violate the invariants.
+    pub fn merge(&mut self, other: &RRSets) -> Result<(), RRSetError> {
+        let mut merged = self.clone();
+
+        for record in other.records() {
+            merged.insert(&record)?;
+        }
+
+        *self = merged;
+        Ok(())
+    }
+
+    /// Computes the changes required to go from `self` to `desired`.
+    pub fn diff(&self, desired: &RRSets) -> ChangeBatch {
+        ChangeBatch::between_sets(self.sets.clone(), desired.sets.clone())
+    }
+
+    /// Iterates over all record sets, ordered by owner, class and type.
+    pub fn iter(&self) -> impl Iterator<Item = &RecordSet> + '_ {
+        self.sets.values()
+    }
+
+    /// Iterates over the individual records of all record sets.
+    pub fn records(&self) -> impl Iterator<Item = Record> + '_ {
+        self.iter().flat_map(RecordSet::records)
+    }
+
+    /// Number of record sets.
+    pub fn len(&self) -> usize {
+        self.sets.len()
+    }
+
+    /// Returns true if the collection contains no record sets.
+    pub fn is_empty(&self) -> bool {
+        self.sets.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{error::RRSetError, record::record, Class, FullyQualifiedDomainName, RRSets, Type};
+
+    #[test]
+    fn grouping() {
+        let mut sets = RRSets::from_records(&[
+            record("www.example.org. 300 IN A 192.168.0.1"),
+            record("www.example.org. 60 IN A 192.168.0.2"),
+            record("www.example.org. 300 IN AAAA 2001:db8::1"),
+        ])
+        .unwrap();
+
+        assert_eq!(sets.len(), 2);
+        assert_eq!(
+            sets.insert(&record("www.example.org. 300 IN A 192.168.0.1")),
+            Ok(false)
+        );
+
+        let www = FullyQualifiedDomainName::try_from("www.example.org.").unwrap();
+        let a = sets.get(&www, Class::IN, Type::A).unwrap();
+        assert_eq!((a.ttl, a.rdata.len()), (60, 2));
+
+        assert!(sets.remove(&record("www.example.org. 300 IN AAAA 2001:db8::1")));
+        assert_eq!(sets.get(&www, Class::IN, Type::AAAA), None);
+        assert_eq!(sets.records().count(), 2);
+    }
+
+    #[test]
+    fn cname_invariants() {
+        let mut sets =
+            RRSets::from_records(&[record("api.example.org. 300 IN CNAME www.example.org.")])
+                .unwrap();
+
+        let api = FullyQualifiedDomainName::try_from("api.example.org.").unwrap();
+
+        assert_eq!(
+            sets.insert(&record("api.example.org. 300 IN CNAME web.example.org.")),
+            Err(RRSetError::MultipleCnames(api.clone()))
+        );
+        assert_eq!(
+            sets.insert(&record("api.example.org. 300 IN A 192.168.0.1")),
+            Err(RRSetError::CnameConflict {
+                fqdn: api.clone(),
+                r#type: Type::A
+            })
+        );
+
+        let other = RRSets::from_records(&[
+            record("www.example.org. 300 IN A 192.168.0.1"),
+            record("api.example.org. 300 IN TXT hello"),
+        ])
+        .unwrap();
+
+        assert_eq!(
+            sets.merge(&other),
+            Err(RRSetError::CnameConflict {
+                fqdn: api,
+                r#type: Type::TXT
+            })
+        );
+        assert_eq!(sets.len(), 1);
+    }
+
+    #[test]
+    fn diff() {
+        let current =
+            RRSets::from_records(&[record("www.example.org. 300 IN A 192.168.0.1")]).unwrap();
+
+        let mut desired = current.clone();
+        desired
+            .merge(
+                &RRSets::from_records(&[record("www.example.org. 300 IN A 192.168.0.2")]).unwrap(),
+            )
+            .unwrap();
+
+        let batch = current.diff(&desired);
+        assert_eq!(batch.len(), 1);
+        assert_eq!(batch.changes[0].record_set().rdata.len(), 2);
+        assert!(desired.diff(&desired).is_empty());
+    }
+}
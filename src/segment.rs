@@ -1,35 +1,233 @@
-use std::{fmt::Display, ops::Add};
+use std::{
+    borrow::Cow,
+    cmp::Ordering,
+    fmt::{Debug, Display, Write},
+    hash::{Hash, Hasher},
+    ops::Add,
+};
 
 use thiserror::Error;
 
 use crate::{DomainName, FullyQualifiedDomainName, PartiallyQualifiedDomainName};
 
+/// Maximum length of a domain segment in octets.
+const MAX_LENGTH: usize = 63;
+
 /// Segment of a domain.
 ///
 /// This is the part between dots.
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct DomainSegment(String);
+///
+/// Segments are stored inline rather than on the heap, so a domain name
+/// only needs a single allocation for all of its segments. Only segments
+/// which require escapes in presentation format additionally keep their
+/// escaped form on the heap.
+#[derive(Clone)]
+pub struct DomainSegment {
+    length: u8,
+    bytes: [u8; MAX_LENGTH],
+    escaped: Option<Box<str>>,
+}
 
 impl DomainSegment {
     /// Constructs a new DomainSegment without checking the validity of it.
+    ///
+    /// The caller must ensure that `segment` is at most 63 bytes long,
+    /// which is checked in debug builds only. Longer segments cannot be
+    /// stored, and cause a panic rather than being truncated.
     pub fn new_unchecked(segment: &str) -> Self {
-        DomainSegment(segment.to_string())
+        debug_assert!(
+            segment.len() <= MAX_LENGTH,
+            "segment too long {} > {MAX_LENGTH}",
+            segment.len()
+        );
+
+        let mut bytes = [0; MAX_LENGTH];
+        bytes[..segment.len()].copy_from_slice(segment.as_bytes());
+
+        DomainSegment::from_bytes(bytes, segment.len())
+    }
+
+    /// Constructs a segment from its first `length` bytes, escaping them
+    /// up front if necessary.
+    fn from_bytes(bytes: [u8; MAX_LENGTH], length: usize) -> Self {
+        let raw = &bytes[..length];
+
+        let escaped = match std::str::from_utf8(raw) {
+            Ok(value) if value.chars().all(|c| VALID_CHARACTERS.contains(c)) => None,
+            _ => Some(escape(raw).into_boxed_str()),
+        };
+
+        DomainSegment {
+            length: length as u8,
+            bytes,
+            escaped,
+        }
     }
 
-    /// Length in characters of the domain segment.
+    /// Length of the presentation format kept on the heap, which is zero
+    /// for segments that require no escapes.
+    #[cfg(feature = "heapsize")]
+    pub(crate) fn escaped_len(&self) -> usize {
+        self.escaped.as_ref().map_or(0, |escaped| escaped.len())
+    }
+
+    /// Copy of the segment with all ASCII letters lowercased, which is
+    /// only necessary for segments constructed using
+    /// [`DomainSegment::new_unchecked`].
+    pub(crate) fn to_lowercase(&self) -> Self {
+        let mut bytes = self.bytes;
+        bytes.make_ascii_lowercase();
+
+        DomainSegment::from_bytes(bytes, self.len())
+    }
+
+    /// Length of the domain segment in octets, once escapes are decoded.
     pub fn len(&self) -> usize {
-        self.0.len()
+        usize::from(self.length)
     }
 
     /// Returns true if the segment is empty.
     pub fn is_empty(&self) -> bool {
-        self.0.is_empty()
+        self.length == 0
     }
 
     // Returns true if the segment is equal to "*"
     pub fn is_wildcard(&self) -> bool {
-        self.0 == "*"
+        self.as_raw() == b"*"
+    }
+
+    /// Concatenates two segments into a single segment, such that
+    /// `www` and `2` become `www2`.
+    ///
+    /// See [`DomainSegment::try_join`].
+    pub fn concat(&self, other: &DomainSegment) -> Result<DomainSegment, DomainSegmentError> {
+        DomainSegment::try_join([self, other], "")
+    }
+
+    /// Joins the segments into a single segment, placing the separator
+    /// between each of them, such that `api` and `7f3a` joined by `-`
+    /// become `api-7f3a`.
+    ///
+    /// The joined segment is validated like any other, so joining fails
+    /// if it would exceed 63 characters, or if the separator introduces
+    /// invalid characters or hyphens.
+    pub fn try_join<'a>(
+        segments: impl IntoIterator<Item = &'a DomainSegment>,
+        separator: &str,
+    ) -> Result<DomainSegment, DomainSegmentError> {
+        let mut label = Label::default();
+
+        for (index, segment) in segments.into_iter().enumerate() {
+            if index > 0 {
+                for c in separator.chars() {
+                    label.push_unescaped(c, label.length);
+                }
+            }
+
+            segment.as_raw().iter().for_each(|byte| label.push(*byte));
+        }
+
+        label.finish()
+    }
+
+    /// Raw octets of the segment, as they appear in wire format, with
+    /// all escapes decoded. A segment written as `a\.b` is `a.b`.
+    ///
+    /// Use [`DomainSegment::as_presentation`] when comparing against, or
+    /// joining the segment into, domain names written as text.
+    pub fn as_raw(&self) -> &[u8] {
+        &self.bytes[..self.len()]
+    }
+
+    /// Segment in presentation format, with characters which are not
+    /// permitted unescaped written as `\X` or `\DDD`, exactly as rendered
+    /// by [`Display`]. A segment parsed from `a\.b` is `a\.b`.
+    ///
+    /// Also available through [`AsRef<str>`], since the presentation
+    /// format is computed once the segment is constructed.
+    pub fn as_presentation(&self) -> Cow<'_, str> {
+        Cow::Borrowed(self.as_ref())
+    }
+
+    /// Constructs a segment from the raw octets of a label, as found in
+    /// wire format. Any octet is permitted, since it can be represented
+    /// using escapes.
+    pub(crate) fn from_raw(bytes: &[u8]) -> Result<Self, DomainSegmentError> {
+        let mut label = Label::default();
+        bytes.iter().for_each(|byte| label.push(*byte));
+        label.finish()
+    }
+
+    /// Asserts that the segment is one which could have been parsed,
+    /// and that the unused part of its buffer is zeroed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the segment is invalid, which is only possible if it
+    /// was constructed using [`DomainSegment::new_unchecked`].
+    pub fn debug_validate(&self) {
+        assert!(
+            self.bytes[self.len()..].iter().all(|byte| *byte == 0),
+            "segment {self:?} has trailing bytes"
+        );
+
+        assert_eq!(
+            DomainSegment::try_from(self.to_string()).as_ref(),
+            Ok(self),
+            "segment {self:?} does not round-trip"
+        );
+    }
+}
+
+/// Maximum length of a domain name in octets, in wire format.
+pub(crate) const MAX_NAME_LENGTH: usize = 255;
+
+/// Maximum number of labels in a domain name, excluding the root.
+pub(crate) const MAX_LABELS: usize = 127;
+
+/// Produced when a domain name exceeds [`MAX_NAME_LENGTH`] or [`MAX_LABELS`],
+/// and converted into the error type of the name being constructed.
+pub(crate) enum NameLengthError {
+    TooLong(usize),
+    TooManyLabels(usize),
+}
+
+/// Validates the length of a domain name with labels of the given lengths,
+/// as it would be encoded in wire format once fully qualified.
+pub(crate) fn validate_name_length(
+    labels: impl IntoIterator<Item = usize>,
+) -> Result<(), NameLengthError> {
+    let (count, length) = labels.into_iter().fold((0, 1), |(count, length), label| {
+        (count + 1, length + label + 1)
+    });
+
+    if count > MAX_LABELS {
+        return Err(NameLengthError::TooManyLabels(count));
+    }
+
+    if length > MAX_NAME_LENGTH {
+        return Err(NameLengthError::TooLong(length));
+    }
+
+    Ok(())
+}
+
+/// Asserts that all segments of a domain name are valid, and that
+/// wildcards only appear as the leading segment.
+pub(crate) fn debug_validate_segments(segments: &[DomainSegment]) {
+    for segment in segments {
+        segment.debug_validate();
     }
+
+    assert!(
+        !segments.iter().skip(1).any(DomainSegment::is_wildcard),
+        "non-leading wildcard segment in {segments:?}"
+    );
+
+    assert!(
+        validate_name_length(segments.iter().map(DomainSegment::len)).is_ok(),
+        "domain name {segments:?} is too long"
+    );
 }
 
 /// Produced when attempting to construct a [`DomainSegment`] from
@@ -40,12 +238,14 @@ pub enum DomainSegmentError {
     ///
     /// * Not at the beginning of a segment.
     /// * Not at the end of a segment.
-    /// * Not at the 3rd and 4th position *simultaneously* (used for [Punycode encoding](https://en.wikipedia.org/wiki/Punycode))
+    /// * Not at the 3rd and 4th position *simultaneously*, unless the segment starts
+    ///   with `xn--` (used for [Punycode encoding](https://en.wikipedia.org/wiki/Punycode))
     #[error("illegal hyphen at position {0}")]
     IllegalHyphen(usize),
-    /// Segment contains invalid character.
-    #[error("invalid character {0}")]
-    InvalidCharacter(char),
+    /// Segment contains an invalid character, at the given byte
+    /// position within the segment as written.
+    #[error("invalid character {character:?} at position {position}")]
+    InvalidCharacter { character: char, position: usize },
     /// Domain segment is longer than the permitted 63 characters.
     #[error("segment too long {0} > 63")]
     TooLong(usize),
@@ -55,45 +255,234 @@ pub enum DomainSegmentError {
     /// Domain segments can be wildcards, but must then *only* contain the wildcard.
     #[error("wildcard segments must have length 1")]
     NonStandaloneWildcard,
+    /// Escape sequence is incomplete, or a `\DDD` escape exceeds 255.
+    #[error("invalid escape sequence {0:?}")]
+    InvalidEscape(String),
 }
 
+/// Characters which may appear in a segment without being escaped.
 const VALID_CHARACTERS: &str = "_-0123456789abcdefghijklmnopqrstuvwxyz*";
 
-impl TryFrom<&str> for DomainSegment {
-    type Error = DomainSegmentError;
+/// Regular expression matching a non-wildcard label in presentation
+/// format, including escapes, without anchors.
+#[cfg(feature = "schemars")]
+pub(crate) const LABEL_REGEX: &str = r"([a-zA-Z0-9_-]|\\[0-9]{3}|\\[^0-9]){1,63}";
+
+/// Splits a name in presentation format into its labels,
+/// at every dot which is not escaped.
+pub(crate) fn split_labels(name: &str) -> impl Iterator<Item = &str> {
+    let mut rest = Some(name);
+
+    std::iter::from_fn(move || {
+        let current = rest?;
+        let mut escaped = false;
+
+        for (index, c) in current.char_indices() {
+            match (escaped, c) {
+                (true, _) => escaped = false,
+                (false, '\\') => escaped = true,
+                (false, '.') => {
+                    rest = Some(&current[index + 1..]);
+                    return Some(&current[..index]);
+                }
+                _ => {}
+            }
+        }
 
-    fn try_from(value: &str) -> Result<Self, Self::Error> {
-        let value = value.to_ascii_lowercase();
+        rest = None;
+        Some(current)
+    })
+}
+
+/// Splits a name like [`split_labels`], along with the byte offset
+/// of each label within the name.
+pub(crate) fn label_offsets(name: &str) -> impl Iterator<Item = (usize, &str)> {
+    split_labels(name).scan(0, |offset, label| {
+        let start = *offset;
+        *offset += label.len() + 1;
+        Some((start, label))
+    })
+}
+
+/// Parses each label of a name, locating the first invalid label
+/// within the name.
+pub(crate) fn parse_labels(name: &str) -> Result<Vec<DomainSegment>, LabelError> {
+    label_offsets(name)
+        .map(|(offset, label)| {
+            DomainSegment::try_from(label).map_err(|error| LabelError {
+                offset,
+                label: label.to_string(),
+                error,
+            })
+        })
+        .collect()
+}
+
+/// Invalid label found at a byte offset within a name, which is
+/// converted into the `SegmentError` of the name being parsed.
+#[derive(Debug)]
+pub(crate) struct LabelError {
+    pub offset: usize,
+    pub label: String,
+    pub error: DomainSegmentError,
+}
+
+/// Byte position within a label as written of the octet at `index`
+/// once escapes are decoded.
+pub(crate) fn written_position(label: &str, index: usize) -> usize {
+    let mut chars = label.char_indices();
+    let mut decoded = 0;
+
+    while let Some((start, c)) = chars.next() {
+        if decoded == index {
+            return start;
+        }
+
+        if c == '\\' && chars.next().is_some_and(|(_, c)| c.is_ascii_digit()) {
+            chars.nth(1);
+        }
+
+        decoded += c.len_utf8();
+    }
+
+    label.len()
+}
+
+/// Strips the trailing dot from a name in presentation format,
+/// returning [`None`] if there is none, or it is escaped.
+pub(crate) fn strip_root(name: &str) -> Option<&str> {
+    let stripped = name.strip_suffix('.')?;
+    let backslashes = stripped.len() - stripped.trim_end_matches('\\').len();
+
+    (backslashes % 2 == 0).then_some(stripped)
+}
+
+/// Bytes of a segment being constructed, which are validated once complete.
+struct Label {
+    bytes: [u8; MAX_LENGTH],
+    /// Total length, which may exceed the length of the buffer.
+    length: usize,
+    /// First unescaped character which is not permitted in segments,
+    /// along with its position.
+    invalid: Option<(char, usize)>,
+}
+
+impl Default for Label {
+    fn default() -> Self {
+        Label {
+            bytes: [0; MAX_LENGTH],
+            length: 0,
+            invalid: None,
+        }
+    }
+}
+
+impl Label {
+    /// Appends a byte, exempt from the character restrictions.
+    fn push(&mut self, byte: u8) {
+        if let Some(target) = self.bytes.get_mut(self.length) {
+            *target = byte.to_ascii_lowercase();
+        }
+
+        self.length += 1;
+    }
 
-        if value.is_empty() {
+    /// Appends a character found at `position`, which must be one of
+    /// [`VALID_CHARACTERS`].
+    fn push_unescaped(&mut self, c: char, position: usize) {
+        let c = c.to_ascii_lowercase();
+
+        if !VALID_CHARACTERS.contains(c) {
+            self.invalid.get_or_insert((c, position));
+        }
+
+        c.encode_utf8(&mut [0; 4])
+            .bytes()
+            .for_each(|byte| self.push(byte));
+    }
+
+    fn finish(self) -> Result<DomainSegment, DomainSegmentError> {
+        if self.length == 0 {
             return Err(DomainSegmentError::EmptyString);
         }
 
-        if value.len() > 63 {
-            return Err(DomainSegmentError::TooLong(value.len()));
+        if self.length > MAX_LENGTH {
+            return Err(DomainSegmentError::TooLong(self.length));
         }
 
-        if value.contains('*') && value.len() != 1 {
+        let value = &self.bytes[..self.length];
+
+        if value.contains(&b'*') && value.len() != 1 {
             return Err(DomainSegmentError::NonStandaloneWildcard);
         }
 
-        if let Some(character) = value.chars().find(|c| !VALID_CHARACTERS.contains(*c)) {
-            return Err(DomainSegmentError::InvalidCharacter(character));
+        if let Some((character, position)) = self.invalid {
+            return Err(DomainSegmentError::InvalidCharacter {
+                character,
+                position,
+            });
         }
 
-        if value.starts_with('-') {
+        if value.starts_with(b"-") {
             return Err(DomainSegmentError::IllegalHyphen(1));
         }
 
-        if value.ends_with('-') {
+        if value.ends_with(b"-") {
             return Err(DomainSegmentError::IllegalHyphen(value.len()));
         }
 
-        if value.get(2..4) == Some("--") {
+        if value.get(2..4) == Some(b"--") && !value.starts_with(b"xn--") {
             return Err(DomainSegmentError::IllegalHyphen(3));
         }
 
-        Ok(DomainSegment(value))
+        Ok(DomainSegment::from_bytes(self.bytes, self.length))
+    }
+}
+
+impl TryFrom<&str> for DomainSegment {
+    type Error = DomainSegmentError;
+
+    /// Parses a segment in presentation format, where characters other
+    /// than letters, digits, hyphens and underscores must be escaped as
+    /// either `\X` or `\DDD`, such as `\.` or `\032` for a space (RFC 1035).
+    ///
+    /// A `\DDD` escape may produce any octet from 0 to 255, while `\X`
+    /// produces the character X itself. Note that an escaped `\*` on its
+    /// own is still treated as a wildcard.
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut label = Label::default();
+        let mut chars = value.char_indices();
+
+        while let Some((start, c)) = chars.next() {
+            if c != '\\' {
+                label.push_unescaped(c, start);
+                continue;
+            }
+
+            let invalid =
+                |end: usize| DomainSegmentError::InvalidEscape(value[start..end].to_string());
+
+            match chars.next() {
+                Some((_, digit)) if digit.is_ascii_digit() => {
+                    let end = start + 4;
+                    let byte = value
+                        .get(start + 1..end)
+                        .filter(|digits| digits.bytes().all(|byte| byte.is_ascii_digit()))
+                        .and_then(|digits| digits.parse::<u8>().ok())
+                        .ok_or_else(|| invalid(value.len().min(end)))?;
+
+                    chars.nth(1);
+                    label.push(byte);
+                }
+                Some((_, escaped)) => escaped
+                    .encode_utf8(&mut [0; 4])
+                    .bytes()
+                    .for_each(|byte| label.push(byte)),
+                None => return Err(invalid(value.len())),
+            }
+        }
+
+        label.finish()
     }
 }
 
@@ -105,15 +494,82 @@ impl TryFrom<String> for DomainSegment {
     }
 }
 
+/// Escapes the raw octets of a segment into presentation format.
+fn escape(raw: &[u8]) -> String {
+    let mut escaped = String::with_capacity(raw.len());
+
+    for byte in raw {
+        let c = char::from(*byte);
+
+        if VALID_CHARACTERS.contains(c) {
+            escaped.push(c);
+        } else if byte.is_ascii_graphic() {
+            escaped.push('\\');
+            escaped.push(c);
+        } else {
+            write!(escaped, "\\{byte:03}").expect("writing to a string cannot fail");
+        }
+    }
+
+    escaped
+}
+
 impl Display for DomainSegment {
+    /// Renders the segment in presentation format, escaping characters
+    /// which are not permitted unescaped.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_ref())
+    }
+}
+
+impl Debug for DomainSegment {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_str(&self.0)
+        f.debug_tuple("DomainSegment")
+            .field(&self.as_presentation())
+            .finish()
     }
 }
 
+/// Presentation format of the segment, see [`DomainSegment::as_presentation`].
 impl AsRef<str> for DomainSegment {
     fn as_ref(&self) -> &str {
-        self.0.as_str()
+        match &self.escaped {
+            Some(escaped) => escaped,
+            None => std::str::from_utf8(self.as_raw()).expect("unescaped segments are ASCII"),
+        }
+    }
+}
+
+/// Raw octets of the segment, see [`DomainSegment::as_raw`].
+impl AsRef<[u8]> for DomainSegment {
+    fn as_ref(&self) -> &[u8] {
+        self.as_raw()
+    }
+}
+
+impl PartialEq for DomainSegment {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_raw() == other.as_raw()
+    }
+}
+
+impl Eq for DomainSegment {}
+
+impl PartialOrd for DomainSegment {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for DomainSegment {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_raw().cmp(other.as_raw())
+    }
+}
+
+impl Hash for DomainSegment {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_raw().hash(state)
     }
 }
 
@@ -185,13 +641,32 @@ impl Add<&DomainName> for DomainSegment {
     }
 }
 
+/// Recorded as its presentation format.
+#[cfg(feature = "valuable")]
+impl valuable::Valuable for DomainSegment {
+    fn as_value(&self) -> valuable::Value<'_> {
+        valuable::Value::String(self.as_ref())
+    }
+
+    fn visit(&self, visit: &mut dyn valuable::Visit) {
+        visit.visit_value(self.as_value())
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::segment::{DomainSegment, DomainSegmentError};
+    use crate::{
+        error::FullyQualifiedDomainNameError,
+        segment::{DomainSegment, DomainSegmentError},
+        FullyQualifiedDomainName,
+    };
 
     #[test]
     fn segment_construction() {
-        assert_eq!(DomainSegment::try_from("abcd").unwrap().as_ref(), "abcd");
+        assert_eq!(
+            DomainSegment::try_from("abcd").unwrap().as_presentation(),
+            "abcd"
+        );
 
         assert_eq!(
             DomainSegment::try_from(""),
@@ -203,21 +678,44 @@ mod tests {
     fn invalid_character() {
         assert_eq!(
             DomainSegment::try_from("ab.cd"),
-            Err(DomainSegmentError::InvalidCharacter('.'))
+            Err(DomainSegmentError::InvalidCharacter {
+                character: '.',
+                position: 2
+            })
+        );
+        assert_eq!(
+            DomainSegment::try_from("a\\.b c"),
+            Err(DomainSegmentError::InvalidCharacter {
+                character: ' ',
+                position: 4
+            })
         );
     }
 
     #[test]
     fn invalid_hyphens() {
-        assert_eq!(DomainSegment::try_from("ab-cd").unwrap().as_ref(), "ab-cd");
+        assert_eq!(
+            DomainSegment::try_from("ab-cd").unwrap().as_presentation(),
+            "ab-cd"
+        );
 
-        assert_eq!(DomainSegment::try_from("abc-d").unwrap().as_ref(), "abc-d");
+        assert_eq!(
+            DomainSegment::try_from("abc-d").unwrap().as_presentation(),
+            "abc-d"
+        );
 
         assert_eq!(
             DomainSegment::try_from("ab--cd"),
             Err(DomainSegmentError::IllegalHyphen(3))
         );
 
+        assert_eq!(
+            DomainSegment::try_from("xn--bcher-kva")
+                .unwrap()
+                .as_presentation(),
+            "xn--bcher-kva"
+        );
+
         assert_eq!(
             DomainSegment::try_from("-abcd"),
             Err(DomainSegmentError::IllegalHyphen(1))
@@ -231,8 +729,108 @@ mod tests {
 
     #[test]
     fn wildcards() {
-        assert_eq!(DomainSegment::try_from("*").unwrap().as_ref(), "*");
+        assert_eq!(DomainSegment::try_from("*").unwrap().as_presentation(), "*");
 
         assert!(DomainSegment::try_from("*").unwrap().is_wildcard())
     }
+
+    #[test]
+    fn inline_storage() {
+        let segment = DomainSegment::try_from("ABC").unwrap();
+
+        assert_eq!(format!("{segment:?}"), "DomainSegment(\"abc\")");
+        assert!(segment < DomainSegment::try_from("abcd").unwrap());
+        assert!(segment > DomainSegment::try_from("ab").unwrap());
+
+        let longest = "a".repeat(63);
+        assert_eq!(DomainSegment::try_from(longest.as_str()).unwrap().len(), 63);
+    }
+
+    #[test]
+    fn joining() {
+        fn segment(value: &str) -> DomainSegment {
+            DomainSegment::try_from(value).unwrap()
+        }
+
+        assert_eq!(segment("www").concat(&segment("2")), Ok(segment("www2")));
+        assert_eq!(
+            DomainSegment::try_join([&segment("api"), &segment("7f3a")], "-"),
+            Ok(segment("api-7f3a"))
+        );
+
+        let long = segment(&"a".repeat(40));
+        assert_eq!(
+            DomainSegment::try_join([&long, &long], "-"),
+            Err(DomainSegmentError::TooLong(81))
+        );
+        assert_eq!(
+            DomainSegment::try_join([&segment("api"), &segment("v1")], "."),
+            Err(DomainSegmentError::InvalidCharacter {
+                character: '.',
+                position: 3
+            })
+        );
+        assert_eq!(
+            segment("*").concat(&segment("a")),
+            Err(DomainSegmentError::NonStandaloneWildcard)
+        );
+        assert_eq!(
+            DomainSegment::try_join([], "-"),
+            Err(DomainSegmentError::EmptyString)
+        );
+    }
+
+    #[test]
+    fn escapes() {
+        let space = DomainSegment::try_from("a\\032B").unwrap();
+        assert_eq!(space.as_raw(), b"a b");
+        assert_eq!(space.to_string(), "a\\032b");
+        assert_eq!(AsRef::<str>::as_ref(&space), "a\\032b");
+        assert_eq!(AsRef::<[u8]>::as_ref(&space), b"a b");
+
+        let dot = DomainSegment::try_from("www\\.x").unwrap();
+        assert_eq!(dot.as_raw(), b"www.x");
+        assert_eq!(dot.to_string(), "www\\.x");
+        assert_eq!(DomainSegment::try_from(dot.to_string()), Ok(dot));
+
+        let octet = DomainSegment::try_from("a\\255").unwrap();
+        assert_eq!(octet.as_raw(), b"a\xff");
+        assert_eq!(octet.as_presentation(), "a\\255");
+        assert_eq!(
+            DomainSegment::try_from("a\\256"),
+            Err(DomainSegmentError::InvalidEscape(String::from("\\256")))
+        );
+        assert_eq!(
+            DomainSegment::try_from("a\\03"),
+            Err(DomainSegmentError::InvalidEscape(String::from("\\03")))
+        );
+        assert_eq!(
+            DomainSegment::try_from("a\\"),
+            Err(DomainSegmentError::InvalidEscape(String::from("\\")))
+        );
+
+        let fqdn = FullyQualifiedDomainName::try_from("www\\.x.example.org.").unwrap();
+        assert_eq!(fqdn.iter().count(), 3);
+        assert_eq!(fqdn.to_string(), "www\\.x.example.org.");
+        assert_eq!(
+            FullyQualifiedDomainName::try_from("example\\."),
+            Err(FullyQualifiedDomainNameError::DomainIsPartiallyQualified)
+        );
+    }
+
+    #[test]
+    fn debug_validate() {
+        DomainSegment::try_from("Example").unwrap().debug_validate();
+        DomainSegment::new_unchecked("example").debug_validate();
+
+        let invalid = DomainSegment::new_unchecked("Example");
+        assert!(std::panic::catch_unwind(|| invalid.debug_validate()).is_err());
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "segment too long 64 > 63")]
+    fn new_unchecked_too_long() {
+        DomainSegment::new_unchecked(&"a".repeat(64));
+    }
 }
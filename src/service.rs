@@ -0,0 +1,240 @@
+use std::{fmt::Display, str::FromStr};
+
+use thiserror::Error;
+
+use crate::{
+    error::{DomainSegmentError, FullyQualifiedDomainNameError},
+    segment::validate_name_length,
+    DomainSegment, FullyQualifiedDomainName,
+};
+
+/// Produced when constructing or splitting a [`ServiceName`] fails.
+#[derive(Error, Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ServiceNameError {
+    /// The service is not a valid label once prefixed with an underscore.
+    #[error("invalid service: {0}")]
+    InvalidService(DomainSegmentError),
+    /// The protocol is not one of `tcp`, `udp` or `sctp`.
+    #[error("unknown protocol {0:?}")]
+    UnknownProtocol(String),
+    /// The name does not start with an underscore-prefixed service label.
+    #[error("missing service label")]
+    MissingService,
+    /// The service label is not followed by an underscore-prefixed protocol label.
+    #[error("missing protocol label")]
+    MissingProtocol,
+    /// The resulting name is invalid, because it is too long.
+    #[error("invalid name: {0}")]
+    InvalidName(FullyQualifiedDomainNameError),
+}
+
+/// Transport protocol of a service, as used in SRV and DNS-SD names.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Protocol {
+    Tcp,
+    Udp,
+    Sctp,
+}
+
+impl Display for Protocol {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Protocol::Tcp => f.write_str("tcp"),
+            Protocol::Udp => f.write_str("udp"),
+            Protocol::Sctp => f.write_str("sctp"),
+        }
+    }
+}
+
+impl TryFrom<&str> for Protocol {
+    type Error = ServiceNameError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.to_ascii_lowercase().as_str() {
+            "tcp" => Ok(Protocol::Tcp),
+            "udp" => Ok(Protocol::Udp),
+            "sctp" => Ok(Protocol::Sctp),
+            _ => Err(ServiceNameError::UnknownProtocol(value.to_string())),
+        }
+    }
+}
+
+impl FromStr for Protocol {
+    type Err = ServiceNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+/// Service and protocol labels prefixed to a domain, such as
+/// `_ldap._tcp` in `_ldap._tcp.example.org.` (RFC 2782, RFC 6763).
+///
+/// ```text
+/// ServiceName::new("ldap", Protocol::Tcp).with_domain(&example_org)
+///     => _ldap._tcp.example.org.
+///
+/// ServiceName::split(&"_ldap._tcp.example.org.")
+///     => (ServiceName { service: "ldap", protocol: Tcp }, example.org.)
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ServiceName {
+    service: String,
+    protocol: Protocol,
+}
+
+impl ServiceName {
+    /// Constructs a service name, where `service` is given in presentation
+    /// format without its leading underscore, such that characters like
+    /// dots must be escaped, as in `ld\\.ap`.
+    ///
+    /// The service is validated once combined with a domain.
+    pub fn new(service: impl Into<String>, protocol: Protocol) -> Self {
+        ServiceName {
+            service: service.into(),
+            protocol,
+        }
+    }
+
+    /// Name of the service in presentation format, without its leading
+    /// underscore.
+    pub fn service(&self) -> &str {
+        &self.service
+    }
+
+    /// Transport protocol of the service.
+    pub fn protocol(&self) -> Protocol {
+        self.protocol
+    }
+
+    /// Prefixes the domain with the service and protocol labels.
+    pub fn with_domain(
+        &self,
+        domain: &FullyQualifiedDomainName,
+    ) -> Result<FullyQualifiedDomainName, ServiceNameError> {
+        if self.service.starts_with('_') {
+            return Err(ServiceNameError::InvalidService(
+                DomainSegmentError::InvalidCharacter {
+                    character: '_',
+                    position: 0,
+                },
+            ));
+        }
+
+        let service = DomainSegment::try_from(format!("_{}", self.service))
+            .map_err(ServiceNameError::InvalidService)?;
+
+        let protocol = DomainSegment::new_unchecked(&format!("_{}", self.protocol));
+
+        let fqdn = service + (protocol + domain);
+
+        validate_name_length(fqdn.iter().map(DomainSegment::len))
+            .map_err(|err| ServiceNameError::InvalidName(err.into()))?;
+
+        Ok(fqdn)
+    }
+
+    /// Splits a name such as `_ldap._tcp.example.org.` into its
+    /// service name and domain.
+    ///
+    /// The service is kept in presentation format, so
+    /// [`ServiceName::with_domain`] rebuilds the same name.
+    pub fn split(
+        fqdn: &FullyQualifiedDomainName,
+    ) -> Result<(ServiceName, FullyQualifiedDomainName), ServiceNameError> {
+        let mut labels = fqdn.iter().map(DomainSegment::as_presentation);
+
+        let service = labels.next().unwrap_or_default();
+        let service = service
+            .strip_prefix('_')
+            .ok_or(ServiceNameError::MissingService)?;
+
+        let protocol = labels.next().unwrap_or_default();
+        let protocol = protocol
+            .strip_prefix('_')
+            .ok_or(ServiceNameError::MissingProtocol)?;
+
+        Ok((
+            ServiceName::new(service, Protocol::try_from(protocol)?),
+            fqdn.iter().skip(2).cloned().collect(),
+        ))
+    }
+}
+
+impl Display for ServiceName {
+    /// Renders the service and protocol labels in presentation format.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "_{}._{}", self.service, self.protocol)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::{DomainSegmentError, ServiceNameError},
+        FullyQualifiedDomainName, Protocol, ServiceName,
+    };
+
+    #[test]
+    fn construction() {
+        let domain = FullyQualifiedDomainName::try_from("example.org.").unwrap();
+        let name = ServiceName::new("ldap", Protocol::Tcp);
+
+        let fqdn = name.with_domain(&domain).unwrap();
+        assert_eq!(fqdn.to_string(), "_ldap._tcp.example.org.");
+        assert_eq!(ServiceName::split(&fqdn), Ok((name, domain.clone())));
+
+        // Escaped labels are rebuilt identically.
+        for escaped in ["_ld\\.ap._tcp.example.org.", "_ld\\032ap._udp.example.org."] {
+            let fqdn = FullyQualifiedDomainName::try_from(escaped).unwrap();
+            let (name, domain) = ServiceName::split(&fqdn).unwrap();
+
+            assert_eq!(name.with_domain(&domain), Ok(fqdn));
+            assert_eq!(format!("{name}.{domain}"), escaped);
+        }
+
+        assert_eq!(
+            ServiceName::new("ld.ap", Protocol::Udp).with_domain(&domain),
+            Err(ServiceNameError::InvalidService(
+                DomainSegmentError::InvalidCharacter {
+                    character: '.',
+                    position: 3
+                }
+            ))
+        );
+        assert_eq!(
+            ServiceName::new("_ldap", Protocol::Udp).with_domain(&domain),
+            Err(ServiceNameError::InvalidService(
+                DomainSegmentError::InvalidCharacter {
+                    character: '_',
+                    position: 0
+                }
+            ))
+        );
+    }
+
+    #[test]
+    fn splitting() {
+        let split = |name: &str| {
+            ServiceName::split(&FullyQualifiedDomainName::try_from(name).unwrap())
+                .map(|(service, domain)| (service.to_string(), domain.to_string()))
+        };
+
+        assert_eq!(
+            split("_443._TCP.www.example.org."),
+            Ok((String::from("_443._tcp"), String::from("www.example.org.")))
+        );
+        assert_eq!(
+            split("www.example.org."),
+            Err(ServiceNameError::MissingService)
+        );
+        assert_eq!(
+            split("_ldap.example.org."),
+            Err(ServiceNameError::MissingProtocol)
+        );
+        assert_eq!(
+            split("_ldap._quic.example.org."),
+            Err(ServiceNameError::UnknownProtocol(String::from("quic")))
+        );
+    }
+}
@@ -0,0 +1,108 @@
+use crate::DomainName;
+
+/// Weighted edit distance between two sequences, where inserting or
+/// deleting an element costs `weight` and substituting one costs `substitute`.
+fn edit_distance<T>(
+    left: &[T],
+    right: &[T],
+    weight: impl Fn(&T) -> usize,
+    substitute: impl Fn(&T, &T) -> usize,
+) -> usize {
+    let mut previous: Vec<usize> = std::iter::once(0)
+        .chain(right.iter().scan(0, |total, item| {
+            *total += weight(item);
+            Some(*total)
+        }))
+        .collect();
+
+    for item in left {
+        let mut current = Vec::with_capacity(previous.len());
+        current.push(previous[0] + weight(item));
+
+        for (index, other) in right.iter().enumerate() {
+            current.push(
+                (previous[index] + substitute(item, other))
+                    .min(previous[index + 1] + weight(item))
+                    .min(current[index] + weight(other)),
+            );
+        }
+
+        previous = current;
+    }
+
+    previous[right.len()]
+}
+
+impl DomainName {
+    /// Label-aware edit distance between two domain names.
+    ///
+    /// Labels are aligned with each other first, so that a typo within
+    /// a label costs the edit distance between the two labels, while an
+    /// extra or missing label costs its length, including its dot.
+    /// Differing qualification counts as a single edit.
+    pub fn levenshtein(&self, other: &DomainName) -> usize {
+        let labels = edit_distance(
+            self.as_ref(),
+            other.as_ref(),
+            |label| label.len() + 1,
+            |left, right| {
+                edit_distance(
+                    left.as_raw(),
+                    right.as_raw(),
+                    |_| 1,
+                    |left, right| usize::from(left != right),
+                )
+            },
+        );
+
+        labels + usize::from(self.is_fully_qualified() != other.is_fully_qualified())
+    }
+
+    /// Similarity between two domain names, from `0.0` for completely
+    /// different names to `1.0` for identical ones.
+    ///
+    /// Based on [`DomainName::levenshtein`], relative to the length of the
+    /// longer of the two names.
+    pub fn similarity(&self, other: &DomainName) -> f64 {
+        let length = self.to_string().len().max(other.to_string().len());
+        if length == 0 {
+            return 1.0;
+        }
+
+        (1.0 - self.levenshtein(other) as f64 / length as f64).max(0.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::DomainName;
+
+    fn name(value: &str) -> DomainName {
+        DomainName::try_from(value).unwrap()
+    }
+
+    #[test]
+    fn levenshtein() {
+        let original = name("www.example.org.");
+
+        assert_eq!(original.levenshtein(&original), 0);
+        assert_eq!(original.levenshtein(&name("www.exmaple.org.")), 2);
+        assert_eq!(original.levenshtein(&name("example.org.")), 4);
+        assert_eq!(original.levenshtein(&name("www.example.org")), 1);
+        assert_eq!(
+            name("example.org.").levenshtein(&name("www.example.org.")),
+            original.levenshtein(&name("example.org."))
+        );
+    }
+
+    #[test]
+    fn similarity() {
+        let original = name("example.org.");
+
+        assert_eq!(original.similarity(&original), 1.0);
+        assert!(
+            original.similarity(&name("exampel.org.")) > original.similarity(&name("test.org."))
+        );
+        assert_eq!(name("abc").similarity(&name("xyz")), 0.0);
+    }
+}
@@ -0,0 +1,303 @@
+use std::{collections::VecDeque, fmt::Display};
+
+use thiserror::Error;
+
+use crate::{stagger::stable_hash, FullyQualifiedDomainName, Record, Soa, Zone};
+
+/// Version of a zone, as found in the serial field of its SOA record.
+///
+/// Serials wrap around, so the derived ordering is only numeric. Use
+/// [`Serial::is_newer_than`] to compare versions of a zone.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Serial(pub u32);
+
+impl Serial {
+    /// Returns true if this serial succeeds `other` according to
+    /// serial number arithmetic (RFC 1982).
+    pub fn is_newer_than(&self, other: Serial) -> bool {
+        Soa::serial_gt(self.0, other.0)
+    }
+}
+
+impl From<u32> for Serial {
+    fn from(value: u32) -> Self {
+        Serial(value)
+    }
+}
+
+impl Display for Serial {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// Produced when a [`ZoneHistory`] cannot record a snapshot or compute a delta.
+#[derive(Error, Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum HistoryError {
+    /// The snapshot is of a zone with a different origin than the history.
+    #[error("snapshot of {found} cannot be added to history of {expected}")]
+    OriginMismatch {
+        expected: FullyQualifiedDomainName,
+        found: FullyQualifiedDomainName,
+    },
+    /// The snapshot's serial does not succeed the latest serial in the history.
+    #[error("serial {serial} is not newer than the latest serial {latest}")]
+    StaleSerial { latest: Serial, serial: Serial },
+    /// No snapshot with the serial is retained in the history.
+    #[error("no snapshot with serial {0}")]
+    UnknownSerial(Serial),
+}
+
+/// Immutable version of a [`Zone`], identified by its serial and
+/// a hash of its contents.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ZoneSnapshot {
+    zone: Zone,
+    serial: Serial,
+    content_hash: u64,
+}
+
+impl ZoneSnapshot {
+    /// Captures the zone as the version identified by `serial`.
+    pub fn new(zone: Zone, serial: impl Into<Serial>) -> Self {
+        let contents: String = zone.iter().map(|record| format!("{record}\n")).collect();
+
+        ZoneSnapshot {
+            content_hash: stable_hash(contents.as_bytes()),
+            serial: serial.into(),
+            zone,
+        }
+    }
+
+    /// Zone captured by the snapshot.
+    pub fn zone(&self) -> &Zone {
+        &self.zone
+    }
+
+    /// Serial identifying the snapshot.
+    pub fn serial(&self) -> Serial {
+        self.serial
+    }
+
+    /// Hash of the records in the zone, which is stable across releases.
+    ///
+    /// Snapshots with different hashes contain different records, while
+    /// equal hashes only indicate that the records are likely the same,
+    /// since distinct zones may collide.
+    pub fn content_hash(&self) -> u64 {
+        self.content_hash
+    }
+}
+
+/// Incremental changes between two versions of a zone, in the style of
+/// an IXFR response (RFC 1995): records of the old version to remove,
+/// followed by records of the new version to add.
+///
+/// Records whose TTL changed appear in both lists.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ZoneDelta {
+    pub from: Serial,
+    pub to: Serial,
+    pub removed: Vec<Record>,
+    pub added: Vec<Record>,
+}
+
+impl ZoneDelta {
+    /// Computes the changes required to go from `from` to `to`.
+    pub fn between(from: &ZoneSnapshot, to: &ZoneSnapshot) -> Self {
+        let changed = |source: &Zone, target: &Zone| -> Vec<Record> {
+            source
+                .iter()
+                .filter(|record| target.get(&record.ident()) != Some(*record))
+                .cloned()
+                .collect()
+        };
+
+        ZoneDelta {
+            from: from.serial,
+            to: to.serial,
+            removed: changed(&from.zone, &to.zone),
+            added: changed(&to.zone, &from.zone),
+        }
+    }
+
+    /// Returns true if the versions contain the same records.
+    pub fn is_empty(&self) -> bool {
+        self.removed.is_empty() && self.added.is_empty()
+    }
+}
+
+/// Bounded history of the most recent snapshots of a single zone, from
+/// which deltas between any two retained versions can be computed.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ZoneHistory {
+    capacity: usize,
+    snapshots: VecDeque<ZoneSnapshot>,
+}
+
+impl ZoneHistory {
+    /// Constructs an empty history, retaining at most `capacity` snapshots.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "history must retain at least one snapshot");
+
+        ZoneHistory {
+            capacity,
+            snapshots: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    /// Records a new version of the zone, evicting the oldest
+    /// snapshot if the history is full.
+    pub fn push(&mut self, snapshot: ZoneSnapshot) -> Result<(), HistoryError> {
+        if let Some(latest) = self.latest() {
+            if latest.zone.origin() != snapshot.zone.origin() {
+                return Err(HistoryError::OriginMismatch {
+                    expected: latest.zone.origin().clone(),
+                    found: snapshot.zone.origin().clone(),
+                });
+            }
+
+            if !snapshot.serial.is_newer_than(latest.serial) {
+                return Err(HistoryError::StaleSerial {
+                    latest: latest.serial,
+                    serial: snapshot.serial,
+                });
+            }
+        }
+
+        if self.snapshots.len() == self.capacity {
+            self.snapshots.pop_front();
+        }
+
+        self.snapshots.push_back(snapshot);
+        Ok(())
+    }
+
+    /// Most recent snapshot, if any.
+    pub fn latest(&self) -> Option<&ZoneSnapshot> {
+        self.snapshots.back()
+    }
+
+    /// Returns the snapshot with the given serial, if it is retained.
+    pub fn get(&self, serial: impl Into<Serial>) -> Option<&ZoneSnapshot> {
+        let serial = serial.into();
+        self.snapshots
+            .iter()
+            .find(|snapshot| snapshot.serial == serial)
+    }
+
+    /// Computes the changes between the snapshots with the given serials.
+    ///
+    /// Deltas may also be computed backwards, from a newer to an older
+    /// version, for example to roll back a change.
+    pub fn delta(
+        &self,
+        from: impl Into<Serial>,
+        to: impl Into<Serial>,
+    ) -> Result<ZoneDelta, HistoryError> {
+        let (from, to) = (from.into(), to.into());
+
+        let from = self.get(from).ok_or(HistoryError::UnknownSerial(from))?;
+        let to = self.get(to).ok_or(HistoryError::UnknownSerial(to))?;
+
+        Ok(ZoneDelta::between(from, to))
+    }
+
+    /// Iterates over the retained snapshots, from oldest to newest.
+    pub fn iter(&self) -> impl Iterator<Item = &ZoneSnapshot> + '_ {
+        self.snapshots.iter()
+    }
+
+    /// Number of retained snapshots.
+    pub fn len(&self) -> usize {
+        self.snapshots.len()
+    }
+
+    /// Returns true if no snapshots have been recorded.
+    pub fn is_empty(&self) -> bool {
+        self.snapshots.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::HistoryError, record::record, FullyQualifiedDomainName, Serial, Zone, ZoneHistory,
+        ZoneSnapshot,
+    };
+
+    fn zone(records: &[&str]) -> Zone {
+        let mut zone = Zone::new(FullyQualifiedDomainName::try_from("example.org.").unwrap());
+
+        for record in records {
+            zone.insert(record.parse().unwrap()).unwrap();
+        }
+
+        zone
+    }
+
+    #[test]
+    fn snapshots() {
+        let a = ZoneSnapshot::new(zone(&["www.example.org. 300 IN A 192.168.0.1"]), 1);
+        let b = ZoneSnapshot::new(zone(&["www.example.org. 300 IN A 192.168.0.1"]), 2);
+        let c = ZoneSnapshot::new(zone(&["www.example.org. 60 IN A 192.168.0.1"]), 3);
+
+        assert_eq!(a.content_hash(), b.content_hash());
+        assert_ne!(a.content_hash(), c.content_hash());
+        assert!(Serial(0).is_newer_than(Serial(u32::MAX)));
+    }
+
+    #[test]
+    fn history() {
+        let mut history = ZoneHistory::new(2);
+
+        history
+            .push(ZoneSnapshot::new(
+                zone(&["www.example.org. 300 IN A 192.168.0.1"]),
+                u32::MAX,
+            ))
+            .unwrap();
+        history
+            .push(ZoneSnapshot::new(
+                zone(&[
+                    "www.example.org. 60 IN A 192.168.0.1",
+                    "api.example.org. 300 IN A 192.168.0.2",
+                ]),
+                0,
+            ))
+            .unwrap();
+
+        assert_eq!(
+            history.push(ZoneSnapshot::new(zone(&[]), 0)),
+            Err(HistoryError::StaleSerial {
+                latest: Serial(0),
+                serial: Serial(0)
+            })
+        );
+
+        let delta = history.delta(u32::MAX, 0).unwrap();
+        assert_eq!(
+            delta.removed,
+            vec![record("www.example.org. 300 IN A 192.168.0.1")]
+        );
+        assert_eq!(
+            delta.added,
+            vec![
+                record("api.example.org. 300 IN A 192.168.0.2"),
+                record("www.example.org. 60 IN A 192.168.0.1"),
+            ]
+        );
+        assert!(history.delta(0, 0).unwrap().is_empty());
+
+        history.push(ZoneSnapshot::new(zone(&[]), 1)).unwrap();
+        assert_eq!(history.len(), 2);
+        assert_eq!(
+            history.delta(u32::MAX, 1),
+            Err(HistoryError::UnknownSerial(Serial(u32::MAX)))
+        );
+    }
+}
@@ -0,0 +1,227 @@
+use std::{fmt::Display, str::FromStr};
+
+use crate::{
+    error::{RecordDataError, TimestampError},
+    ttl::parse_duration,
+    DomainName, RecordData, Timestamp, Type,
+};
+
+/// Start of authority record data, describing the zone's primary name
+/// server, responsible party, and the timers used by secondaries.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Soa {
+    /// Primary name server of the zone.
+    pub mname: DomainName,
+    /// Mailbox of the person responsible for the zone, with the `@` replaced by a dot.
+    pub rname: DomainName,
+    /// Version of the zone, compared using serial number arithmetic.
+    pub serial: u32,
+    /// Seconds between refreshes of the zone by secondaries.
+    pub refresh: u32,
+    /// Seconds before a failed refresh is retried.
+    pub retry: u32,
+    /// Seconds after which secondaries stop answering, if refreshes keep failing.
+    pub expire: u32,
+    /// TTL of negative responses (RFC 2308).
+    pub minimum: u32,
+}
+
+impl Soa {
+    /// Compares serial numbers according to RFC 1982, returning true if
+    /// `left` is greater than `right`, taking wraparound into account.
+    ///
+    /// ```text
+    /// serial_gt(2, 1)          => true
+    /// serial_gt(0, 4294967295) => true
+    /// serial_gt(1, 2147483649) => false
+    /// ```
+    ///
+    /// Serials exactly 2^31 apart are undefined in relation to each
+    /// other, in which case this returns false in both directions.
+    pub fn serial_gt(left: u32, right: u32) -> bool {
+        let distance = left.wrapping_sub(right);
+        distance != 0 && distance < 1 << 31
+    }
+
+    /// Increments the serial by one, wrapping around to zero after `u32::MAX`.
+    pub fn increment(&mut self) {
+        self.serial = self.serial.wrapping_add(1);
+    }
+
+    /// Advances the serial to the conventional `YYYYMMDDnn` format for the
+    /// date of `now`, or increments it if it is already at or beyond
+    /// the first serial of that day.
+    ///
+    /// The serial is never decreased, so zones changed more than a hundred
+    /// times a day will run ahead of the date until it catches up.
+    ///
+    /// Fails with [`TimestampError::OutOfRange`] for dates from the year
+    /// 4295 onwards, whose serials do not fit in 32 bits, leaving the
+    /// serial unchanged.
+    pub fn bump_to_date_serial(&mut self, now: Timestamp) -> Result<(), TimestampError> {
+        let (year, month, day) = now.date();
+        let date = u32::try_from(year * 1_000_000 + month * 10_000 + day * 100)
+            .map_err(|_| TimestampError::OutOfRange)?;
+
+        if Self::serial_gt(date, self.serial) {
+            self.serial = date;
+        } else {
+            self.increment();
+        }
+
+        Ok(())
+    }
+}
+
+impl From<Soa> for RecordData {
+    fn from(value: Soa) -> Self {
+        RecordData::SOA {
+            mname: value.mname,
+            rname: value.rname,
+            serial: value.serial,
+            refresh: value.refresh,
+            retry: value.retry,
+            expire: value.expire,
+            minimum: value.minimum,
+        }
+    }
+}
+
+impl TryFrom<RecordData> for Soa {
+    type Error = RecordData;
+
+    /// Extracts the SOA from the record data, returning the record
+    /// data as-is if it is of another type.
+    fn try_from(value: RecordData) -> Result<Self, Self::Error> {
+        match value {
+            RecordData::SOA {
+                mname,
+                rname,
+                serial,
+                refresh,
+                retry,
+                expire,
+                minimum,
+            } => Ok(Soa {
+                mname,
+                rname,
+                serial,
+                refresh,
+                retry,
+                expire,
+                minimum,
+            }),
+            other => Err(other),
+        }
+    }
+}
+
+impl TryFrom<&str> for Soa {
+    type Error = RecordDataError;
+
+    /// Parses SOA record data in zone file syntax, where the timers
+    /// may use the same unit syntax as TTLs, such as `1h`.
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut fields: Vec<String> = value.split_whitespace().map(String::from).collect();
+
+        if fields.len() == 7 {
+            for timer in &mut fields[3..] {
+                if let Some(seconds) = parse_duration(timer) {
+                    *timer = seconds.to_string();
+                }
+            }
+        }
+
+        match RecordData::parse(Type::SOA, &fields.join(" "))?.try_into() {
+            Ok(soa) => Ok(soa),
+            Err(_) => unreachable!("SOA record data parsed as another type"),
+        }
+    }
+}
+
+impl TryFrom<String> for Soa {
+    type Error = RecordDataError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+
+impl FromStr for Soa {
+    type Err = RecordDataError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl Display for Soa {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {} {} {} {} {} {}",
+            self.mname,
+            self.rname,
+            self.serial,
+            self.refresh,
+            self.retry,
+            self.expire,
+            self.minimum
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{error::TimestampError, RecordData, Soa, Timestamp, Type};
+
+    #[test]
+    fn serial_arithmetic() {
+        assert!(Soa::serial_gt(2, 1));
+        assert!(!Soa::serial_gt(1, 2));
+        assert!(!Soa::serial_gt(1, 1));
+        assert!(Soa::serial_gt(0, u32::MAX));
+        assert!(Soa::serial_gt(1 << 30, u32::MAX));
+        assert!(!Soa::serial_gt(1, (1 << 31) + 1));
+        assert!(!Soa::serial_gt((1 << 31) + 1, 1));
+    }
+
+    #[test]
+    fn date_serials() {
+        let mut soa: Soa = "ns1.example.org. hostmaster.example.org. 1 1h 15m 1w 5m"
+            .parse()
+            .unwrap();
+        assert_eq!((soa.refresh, soa.retry, soa.expire), (3600, 900, 604800));
+
+        // 2024-01-31T12:00:00Z
+        let now = Timestamp::from_unix(1_706_702_400).unwrap();
+
+        soa.bump_to_date_serial(now).unwrap();
+        assert_eq!(soa.serial, 2024013100);
+        soa.bump_to_date_serial(now).unwrap();
+        assert_eq!(soa.serial, 2024013101);
+
+        let far = Timestamp::try_from("4295-01-01T00:00:00Z").unwrap();
+        assert_eq!(
+            soa.bump_to_date_serial(far),
+            Err(TimestampError::OutOfRange)
+        );
+        assert_eq!(soa.serial, 2024013101);
+
+        soa.serial = u32::MAX;
+        soa.increment();
+        assert_eq!(soa.serial, 0);
+
+        assert_eq!(
+            soa.to_string(),
+            "ns1.example.org. hostmaster.example.org. 0 3600 900 604800 300"
+        );
+
+        let rdata = RecordData::from(soa.clone());
+        assert_eq!(
+            RecordData::parse(Type::SOA, &soa.to_string()),
+            Ok(rdata.clone())
+        );
+        assert_eq!(Soa::try_from(rdata), Ok(soa));
+    }
+}
@@ -0,0 +1,113 @@
+use std::fmt::Display;
+
+use crate::FullyQualifiedDomainName;
+
+/// Special-use domain, reserved by RFC 6761 and related RFCs, which
+/// names must not be published to the public DNS under.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SpecialUseDomain {
+    /// `localhost.`, resolving to the loopback address (RFC 6761).
+    Localhost,
+    /// `local.`, resolved using multicast DNS (RFC 6762).
+    Local,
+    /// `invalid.`, guaranteed not to exist (RFC 6761).
+    Invalid,
+    /// `test.`, reserved for testing (RFC 6761).
+    Test,
+    /// `onion.`, resolved within the Tor network (RFC 7686).
+    Onion,
+    /// `home.arpa.`, for use within home networks (RFC 8375).
+    HomeArpa,
+}
+
+impl SpecialUseDomain {
+    /// All special-use domains.
+    pub const ALL: [SpecialUseDomain; 6] = [
+        SpecialUseDomain::Localhost,
+        SpecialUseDomain::Local,
+        SpecialUseDomain::Invalid,
+        SpecialUseDomain::Test,
+        SpecialUseDomain::Onion,
+        SpecialUseDomain::HomeArpa,
+    ];
+
+    /// Labels of the domain, ordered from the root.
+    fn labels(&self) -> &'static [&'static str] {
+        match self {
+            SpecialUseDomain::Localhost => &["localhost"],
+            SpecialUseDomain::Local => &["local"],
+            SpecialUseDomain::Invalid => &["invalid"],
+            SpecialUseDomain::Test => &["test"],
+            SpecialUseDomain::Onion => &["onion"],
+            SpecialUseDomain::HomeArpa => &["arpa", "home"],
+        }
+    }
+
+    /// Returns true if the name is this domain, or a subdomain of it.
+    pub fn contains(&self, fqdn: &FullyQualifiedDomainName) -> bool {
+        let labels = self.labels();
+
+        fqdn.iter().len() >= labels.len()
+            && fqdn
+                .iter()
+                .rev()
+                .zip(labels)
+                .all(|(segment, label)| segment.as_presentation() == *label)
+    }
+}
+
+impl Display for SpecialUseDomain {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for label in self.labels().iter().rev() {
+            write!(f, "{label}.")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FullyQualifiedDomainName {
+    /// Returns the special-use domain this name falls under, if any.
+    pub fn special_use(&self) -> Option<SpecialUseDomain> {
+        SpecialUseDomain::ALL
+            .into_iter()
+            .find(|domain| domain.contains(self))
+    }
+
+    /// Returns true if the name falls under a special-use domain,
+    /// and should therefore not be published to the public DNS.
+    pub fn is_special_use(&self) -> bool {
+        self.special_use().is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{FullyQualifiedDomainName, SpecialUseDomain};
+
+    fn special_use(name: &str) -> Option<SpecialUseDomain> {
+        FullyQualifiedDomainName::try_from(name)
+            .unwrap()
+            .special_use()
+    }
+
+    #[test]
+    fn detection() {
+        assert_eq!(special_use("localhost."), Some(SpecialUseDomain::Localhost));
+        assert_eq!(special_use("printer.LOCAL."), Some(SpecialUseDomain::Local));
+        assert_eq!(
+            special_use("www.example.test."),
+            Some(SpecialUseDomain::Test)
+        );
+        assert_eq!(
+            special_use("router.home.arpa."),
+            Some(SpecialUseDomain::HomeArpa)
+        );
+
+        assert_eq!(special_use("arpa."), None);
+        assert_eq!(special_use("localhost.example.org."), None);
+        assert_eq!(special_use("onion.example.org."), None);
+
+        assert_eq!(SpecialUseDomain::HomeArpa.to_string(), "home.arpa.");
+    }
+}
@@ -0,0 +1,102 @@
+use crate::{Canonicalize, Record};
+
+/// Deterministically spreads record TTLs within a band, so caches which
+/// fetched many records at once do not all expire them at the same time.
+///
+/// TTLs are only ever lowered, by a pseudo-random offset derived from the
+/// seed and the owner name, class and type of the record. All records of
+/// the same record set therefore receive the same TTL, and repeated runs
+/// with the same seed produce identical output.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TtlStagger {
+    seed: u64,
+    band: u32,
+}
+
+impl TtlStagger {
+    /// Constructs a stagger which lowers TTLs by up to `band` seconds,
+    /// but never by more than half of the original TTL.
+    pub fn new(seed: u64, band: u32) -> Self {
+        TtlStagger { seed, band }
+    }
+
+    /// Returns the staggered TTL for the given record.
+    pub fn ttl(&self, record: &Record) -> u32 {
+        let band = self.band.min(record.ttl / 2);
+        if band == 0 {
+            return record.ttl;
+        }
+
+        let key = format!(
+            "{} {} {}",
+            record.fqdn.canonicalize(),
+            record.class,
+            record.r#type
+        );
+
+        record.ttl - (self.hash(key.as_bytes()) % (u64::from(band) + 1)) as u32
+    }
+
+    /// Replaces the TTL of each record with its staggered TTL.
+    pub fn apply<'a>(&self, records: impl IntoIterator<Item = &'a mut Record>) {
+        for record in records {
+            record.ttl = self.ttl(record);
+        }
+    }
+
+    fn hash(&self, bytes: &[u8]) -> u64 {
+        stable_hash(self.seed.to_le_bytes().iter().chain(bytes))
+    }
+}
+
+/// FNV-1a, followed by the SplitMix64 finalizer to spread the
+/// otherwise poorly mixed low bits. Unlike the standard library
+/// hashers, this is guaranteed stable across releases.
+pub(crate) fn stable_hash<'a>(bytes: impl IntoIterator<Item = &'a u8>) -> u64 {
+    let mut hash = bytes
+        .into_iter()
+        .fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+            (hash ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3)
+        });
+
+    hash = (hash ^ (hash >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    hash = (hash ^ (hash >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    hash ^ (hash >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{record::record, Record, TtlStagger};
+
+    #[test]
+    fn within_band() {
+        let stagger = TtlStagger::new(42, 300);
+
+        let mut records: Vec<Record> = (0..100)
+            .map(|index| record(&format!("host{index}.example.org. 3600 IN A 192.168.0.1")))
+            .collect();
+
+        stagger.apply(&mut records);
+
+        assert!(records
+            .iter()
+            .all(|record| (3300..=3600).contains(&record.ttl)));
+
+        // Names should not all end up with the same TTL.
+        assert!(records.iter().any(|record| record.ttl != records[0].ttl));
+    }
+
+    #[test]
+    fn deterministic_per_rrset() {
+        let stagger = TtlStagger::new(7, 600);
+
+        let first = record("www.example.org. 3600 IN A 192.168.0.1");
+        let second = record("WWW.example.org. 3600 IN A 192.168.0.2");
+
+        assert_eq!(stagger.ttl(&first), stagger.ttl(&second));
+        assert_eq!(stagger.ttl(&first), TtlStagger::new(7, 600).ttl(&first));
+
+        // The band never exceeds half the TTL.
+        assert!(stagger.ttl(&record("www.example.org. 60 IN A 192.168.0.1")) >= 30);
+    }
+}
@@ -0,0 +1,275 @@
+use crate::{
+    error::{
+        DomainNameError, DomainSegmentError, FullyQualifiedDomainNameError,
+        PartiallyQualifiedDomainNameError, PatternError, PatternSegmentError,
+    },
+    pattern::PatternSyntax,
+    segment::{split_labels, DomainSegment},
+    PatternSegment,
+};
+
+/// Computes actionable suggestions for resolving parse errors.
+///
+/// Errors retain at most the offending label, so the original input
+/// must be provided in order to produce concrete suggestions.
+pub trait Suggest {
+    /// Returns a human-readable suggestion for how `input` could be changed
+    /// to avoid this error, if one can be determined.
+    fn suggestion(&self, input: &str) -> Option<String>;
+}
+
+impl Suggest for DomainSegmentError {
+    fn suggestion(&self, input: &str) -> Option<String> {
+        // The error may have been produced while parsing a whole domain name,
+        // in which case we narrow the input down to the offending label.
+        let label = split_labels(input)
+            .find(|label| DomainSegment::try_from(*label).as_ref() == Err(self))
+            .unwrap_or(input);
+
+        match self {
+            DomainSegmentError::IllegalHyphen(3) => Some(format!(
+                "hyphens in both the 3rd and 4th position are reserved for punycode (\"xn--\"), remove one of the hyphens in \"{label}\""
+            )),
+            DomainSegmentError::IllegalHyphen(_) => Some(format!(
+                "labels cannot start or end with a hyphen, remove it from \"{label}\""
+            )),
+            DomainSegmentError::InvalidCharacter { character, .. } => {
+                Some(invalid_character_suggestion(*character, label))
+            }
+            DomainSegmentError::TooLong(length) => Some(too_long_suggestion(*length, label)),
+            DomainSegmentError::EmptyString => Some(String::from(
+                "remove leading or repeated dots, labels cannot be empty",
+            )),
+            DomainSegmentError::NonStandaloneWildcard => Some(format!(
+                "wildcard labels must consist of only \"*\", use a pattern to match \"{label}\" instead"
+            )),
+            DomainSegmentError::InvalidEscape(escape) => Some(format!(
+                "escapes must be a backslash followed by a single character or three digits no greater than 255, fix {escape:?} in \"{label}\""
+            )),
+        }
+    }
+}
+
+impl Suggest for FullyQualifiedDomainNameError {
+    fn suggestion(&self, input: &str) -> Option<String> {
+        match self {
+            FullyQualifiedDomainNameError::DomainIsPartiallyQualified => Some(format!(
+                "add a trailing dot to make this a fully qualified name: \"{input}.\""
+            )),
+            FullyQualifiedDomainNameError::SegmentError { label, error, .. } => {
+                error.suggestion(label)
+            }
+            FullyQualifiedDomainNameError::NonLeadingWildcard => Some(String::from(
+                "wildcards may only appear as the first label of a name",
+            )),
+            FullyQualifiedDomainNameError::WildcardNotPermitted => Some(String::from(
+                "wildcard names are not permitted here, remove the leading \"*\" label",
+            )),
+            FullyQualifiedDomainNameError::TooLong(length) => {
+                Some(name_too_long_suggestion(*length))
+            }
+            FullyQualifiedDomainNameError::TooManyLabels(count) => {
+                Some(too_many_labels_suggestion(*count))
+            }
+        }
+    }
+}
+
+impl Suggest for PartiallyQualifiedDomainNameError {
+    fn suggestion(&self, input: &str) -> Option<String> {
+        match self {
+            PartiallyQualifiedDomainNameError::DomainIsFullyQualified => Some(format!(
+                "remove the trailing dot to make this a partially qualified name: \"{}\"",
+                input.trim_end_matches('.')
+            )),
+            PartiallyQualifiedDomainNameError::SegmentError { label, error, .. } => {
+                error.suggestion(label)
+            }
+            PartiallyQualifiedDomainNameError::NonLeadingWildcard => Some(String::from(
+                "wildcards may only appear as the first label of a name",
+            )),
+            PartiallyQualifiedDomainNameError::WildcardNotPermitted => Some(String::from(
+                "wildcard names are not permitted here, remove the leading \"*\" label",
+            )),
+            PartiallyQualifiedDomainNameError::TooLong(length) => {
+                Some(name_too_long_suggestion(*length))
+            }
+            PartiallyQualifiedDomainNameError::TooManyLabels(count) => {
+                Some(too_many_labels_suggestion(*count))
+            }
+        }
+    }
+}
+
+impl Suggest for DomainNameError {
+    fn suggestion(&self, _input: &str) -> Option<String> {
+        match self {
+            DomainNameError::SegmentError { label, error, .. } => error.suggestion(label),
+            DomainNameError::NonLeadingWildcard => Some(String::from(
+                "wildcards may only appear as the first label of a name",
+            )),
+            DomainNameError::WildcardNotPermitted => Some(String::from(
+                "wildcard names are not permitted here, remove the leading \"*\" label",
+            )),
+            DomainNameError::TooLong(length) => Some(name_too_long_suggestion(*length)),
+            DomainNameError::TooManyLabels(count) => Some(too_many_labels_suggestion(*count)),
+        }
+    }
+}
+
+impl Suggest for PatternSegmentError {
+    fn suggestion(&self, input: &str) -> Option<String> {
+        let label = split_labels(input)
+            .find(|label| {
+                [PatternSyntax::Wildcard, PatternSyntax::Glob]
+                    .into_iter()
+                    .any(|syntax| PatternSegment::parse(label, syntax).as_ref() == Err(self))
+            })
+            .unwrap_or(input);
+
+        match self {
+            PatternSegmentError::IllegalHyphen(3) => Some(format!(
+                "hyphens in both the 3rd and 4th position are reserved for punycode (\"xn--\"), remove one of the hyphens in \"{label}\""
+            )),
+            PatternSegmentError::IllegalHyphen(_) => Some(format!(
+                "pattern segments cannot start or end with a hyphen, remove it from \"{label}\""
+            )),
+            PatternSegmentError::InvalidCharacter { character, .. } => {
+                Some(invalid_character_suggestion(*character, label))
+            }
+            PatternSegmentError::TooLong(length) => Some(too_long_suggestion(*length, label)),
+            PatternSegmentError::EmptyString => Some(String::from(
+                "remove leading or repeated dots, pattern segments cannot be empty",
+            )),
+            PatternSegmentError::MultipleWildcards => Some(format!(
+                "pattern segments can only contain a single \"*\", remove all but one from \"{label}\""
+            )),
+            PatternSegmentError::InvalidCharacterClass => Some(format!(
+                "character classes must be closed and contain characters or ascending ranges, such as \"[0-9]\", fix the classes in \"{label}\""
+            )),
+            PatternSegmentError::InvalidRegex(_) => Some(format!(
+                "fix the regular expression {label}, or remove the enclosing slashes to match it literally"
+            )),
+            PatternSegmentError::InvalidEscape(_) => Some(format!(
+                "segments containing escapes must be valid labels without wildcards, write escapes as \\X or \\DDD with DDD at most 255 in \"{label}\""
+            )),
+        }
+    }
+}
+
+impl Suggest for PatternError {
+    fn suggestion(&self, _input: &str) -> Option<String> {
+        match self {
+            PatternError::SegmentError { segment, error, .. } => error.suggestion(segment),
+            PatternError::MisplacedOrigin(_) => Some(String::from(
+                "the origin \"@\" can only be the last segment of a pattern, remove the segments following it",
+            )),
+            PatternError::NonLeadingWildcard(index) => Some(format!(
+                "standalone \"*\" can only be the first segment of a pattern, use \"**\" to match any number of labels, or remove the {index} segments preceding it"
+            )),
+            PatternError::NotExact(_) => Some(String::from(
+                "only patterns without \"*\", \"?\" or character classes can be converted into names",
+            )),
+            PatternError::TooLong(length) => Some(format!(
+                "pattern can only match names longer than 253 characters, shorten it by at least {}",
+                length.saturating_sub(253)
+            )),
+        }
+    }
+}
+
+fn invalid_character_suggestion(character: char, label: &str) -> String {
+    if character.is_whitespace() {
+        format!("remove whitespace from \"{label}\"")
+    } else if !character.is_ascii() {
+        format!(
+            "internationalized labels must be punycode-encoded (\"xn--...\"), encode \"{label}\" before use"
+        )
+    } else {
+        format!("replace '{character}' in \"{label}\" with '-' or remove it")
+    }
+}
+
+fn name_too_long_suggestion(length: usize) -> String {
+    format!(
+        "names cannot exceed 255 octets including length prefixes, shorten it by at least {}",
+        length.saturating_sub(255)
+    )
+}
+
+fn too_many_labels_suggestion(count: usize) -> String {
+    format!(
+        "names cannot have more than 127 labels, remove at least {}",
+        count.saturating_sub(127)
+    )
+}
+
+fn too_long_suggestion(length: usize, label: &str) -> String {
+    match label.get(..63).zip(label.get(63..)) {
+        Some((head, tail)) => format!(
+            "label exceeds 63 characters by {}, shorten it or split it into \"{head}.{tail}\"",
+            length.saturating_sub(63)
+        ),
+        None => format!(
+            "label exceeds 63 characters by {}, shorten it",
+            length.saturating_sub(63)
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::{FullyQualifiedDomainNameError, PartiallyQualifiedDomainNameError, PatternError},
+        FullyQualifiedDomainName, PartiallyQualifiedDomainName, Suggest,
+    };
+
+    #[test]
+    fn trailing_dot() {
+        let err = PartiallyQualifiedDomainName::try_from("example.org.").unwrap_err();
+        assert_eq!(
+            err,
+            PartiallyQualifiedDomainNameError::DomainIsFullyQualified
+        );
+
+        assert_eq!(
+            err.suggestion("example.org.").unwrap(),
+            "remove the trailing dot to make this a partially qualified name: \"example.org\""
+        );
+    }
+
+    #[test]
+    fn offending_label() {
+        let input = "www.exa mple.org.";
+        let err = FullyQualifiedDomainName::try_from(input).unwrap_err();
+
+        assert_eq!(
+            err.suggestion(input).unwrap(),
+            "remove whitespace from \"exa mple\""
+        );
+    }
+
+    #[test]
+    fn too_long() {
+        let input = format!("{}.org.", "a".repeat(70));
+        let err = FullyQualifiedDomainName::try_from(input.as_str()).unwrap_err();
+
+        assert_eq!(
+            err.suggestion(&input).unwrap(),
+            format!(
+                "label exceeds 63 characters by 7, shorten it or split it into \"{}.{}\"",
+                "a".repeat(63),
+                "a".repeat(7)
+            )
+        );
+
+        // Errors constructed by hand may carry lengths within the limits.
+        assert!(FullyQualifiedDomainNameError::TooLong(10)
+            .suggestion("example.org.")
+            .is_some());
+        assert!(FullyQualifiedDomainNameError::TooManyLabels(2)
+            .suggestion("example.org.")
+            .is_some());
+        assert!(PatternError::TooLong(10).suggestion("example").is_some());
+    }
+}
@@ -0,0 +1,116 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use crate::{error::DomainSegmentError, stagger::stable_hash, DomainSegment};
+
+/// Number of hexadecimal digits in the hash of [`DomainSegment::hashed`].
+const HASH_DIGITS: usize = 10;
+
+impl DomainSegment {
+    /// Synthesizes a segment from a prefix and a short hash of `input`,
+    /// such as `k8s-3f1c0a9b2e` for the prefix `k8s`.
+    ///
+    /// The hash consists of the first 40 bits of a stable 64-bit hash of
+    /// the input, encoded as lowercase hexadecimal. The output for a given
+    /// prefix and input is guaranteed not to change between releases.
+    ///
+    /// Fails if the prefix is not a valid segment, or is too long to
+    /// accommodate the hash.
+    pub fn hashed(prefix: &str, input: impl AsRef<[u8]>) -> Result<Self, DomainSegmentError> {
+        let hash = stable_hash(input.as_ref()) >> (64 - 4 * HASH_DIGITS);
+
+        DomainSegment::try_join(
+            [
+                &DomainSegment::try_from(prefix)?,
+                &DomainSegment::new_unchecked(&format!("{hash:0HASH_DIGITS$x}")),
+            ],
+            "-",
+        )
+    }
+
+    /// Synthesizes a segment from an IPv4 address, such as `10-0-0-1`
+    /// for `10.0.0.1`.
+    pub fn from_ipv4_dashed(address: Ipv4Addr) -> Self {
+        let [a, b, c, d] = address.octets();
+
+        DomainSegment::new_unchecked(&format!("{a}-{b}-{c}-{d}"))
+    }
+
+    /// Synthesizes a segment from an IPv6 address, such as
+    /// `2001-db8-0-0-0-0-0-1` for `2001:db8::1`.
+    ///
+    /// All eight groups are included without leading zeroes, since the
+    /// `::` shorthand would produce leading, trailing or repeated hyphens.
+    pub fn from_ipv6_dashed(address: Ipv6Addr) -> Self {
+        let groups: Vec<String> = address
+            .segments()
+            .iter()
+            .map(|group| format!("{group:x}"))
+            .collect();
+
+        DomainSegment::new_unchecked(&groups.join("-"))
+    }
+
+    /// Synthesizes a segment from an IPv4 or IPv6 address.
+    ///
+    /// See [`DomainSegment::from_ipv4_dashed`] and [`DomainSegment::from_ipv6_dashed`].
+    pub fn from_ip_dashed(address: IpAddr) -> Self {
+        match address {
+            IpAddr::V4(address) => DomainSegment::from_ipv4_dashed(address),
+            IpAddr::V6(address) => DomainSegment::from_ipv6_dashed(address),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{Ipv4Addr, Ipv6Addr};
+
+    use crate::{error::DomainSegmentError, DomainSegment};
+
+    #[test]
+    fn hashed() {
+        let segment = DomainSegment::hashed("k8s", "default/nginx-7f9c").unwrap();
+
+        // Guards against accidental changes to the output.
+        assert_eq!(segment.as_presentation(), "k8s-1226386b50");
+        assert_eq!(
+            DomainSegment::hashed("K8S", "default/nginx-7f9c"),
+            Ok(segment)
+        );
+        assert_ne!(
+            DomainSegment::hashed("k8s", "default/nginx-7f9d")
+                .unwrap()
+                .as_presentation(),
+            "k8s-1226386b50"
+        );
+
+        assert_eq!(
+            DomainSegment::hashed(&"a".repeat(53), ""),
+            Err(DomainSegmentError::TooLong(64))
+        );
+        assert_eq!(
+            DomainSegment::hashed("-k8s", ""),
+            Err(DomainSegmentError::IllegalHyphen(1))
+        );
+    }
+
+    #[test]
+    fn dashed_addresses() {
+        let ipv4 = DomainSegment::from_ipv4_dashed(Ipv4Addr::new(10, 0, 0, 1));
+        assert_eq!(ipv4.as_presentation(), "10-0-0-1");
+        ipv4.debug_validate();
+
+        for (address, expected) in [
+            ("2001:db8::1", "2001-db8-0-0-0-0-0-1"),
+            ("::", "0-0-0-0-0-0-0-0"),
+            (
+                "ffff:ffff:ffff:ffff:ffff:ffff:ffff:ffff",
+                &"ffff-".repeat(8)[..39],
+            ),
+        ] {
+            let segment = DomainSegment::from_ipv6_dashed(address.parse::<Ipv6Addr>().unwrap());
+            assert_eq!(segment.as_presentation(), expected);
+            segment.debug_validate();
+        }
+    }
+}
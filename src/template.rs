@@ -0,0 +1,326 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    ops::Range,
+    str::FromStr,
+};
+
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+#[cfg(feature = "serde")]
+use serde::{de::Error as _, Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::{
+    error::{ZoneError, ZoneFileError},
+    zonefile::ZoneFileParser,
+    Class, FullyQualifiedDomainName, Zone,
+};
+
+/// Produced when a [`ZoneTemplate`] is invalid, or cannot be instantiated.
+#[derive(Error, Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum TemplateError {
+    /// A `${` placeholder is missing its closing brace.
+    #[error("unterminated variable placeholder")]
+    UnterminatedVariable,
+    /// Variable names must be non-empty, and consist only of ASCII
+    /// letters, digits and underscores.
+    #[error("invalid variable name {0:?}")]
+    InvalidVariable(String),
+    /// The template references a variable which was not provided.
+    #[error("undefined variable {0:?}")]
+    UndefinedVariable(String),
+    /// The value of a variable would not be substituted as part of a single
+    /// token, because it is empty, or contains whitespace, control characters,
+    /// or characters with a special meaning in zone files.
+    #[error("invalid value {value:?} for variable {name:?}")]
+    InvalidValue { name: String, value: String },
+    /// The instantiated template is not a valid zone file.
+    #[error("{0}")]
+    ZoneFileError(#[from] ZoneFileError),
+    /// A record of the instantiated template does not belong in the zone.
+    #[error("{0}")]
+    ZoneError(#[from] ZoneError),
+}
+
+/// Zone file with `${variable}` placeholders, which can be instantiated
+/// into a [`Zone`] for any number of concrete origins.
+///
+/// Names in the template are relative to the origin of the instantiated
+/// zone, with `@` referring to the origin itself:
+///
+/// ```text
+/// $TTL 3600
+/// @   NS ns1.${provider}.
+/// www A  ${address}
+/// ```
+///
+/// Values of variables are substituted within a single token, and cannot
+/// contain whitespace, control characters, or any of `;()"$\`, such that
+/// they cannot introduce additional records or directives.
+///
+/// `$INCLUDE` directives are not supported within templates.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ZoneTemplate(String);
+
+/// Finds all `${variable}` placeholders, returning their position
+/// within `source` and the name of the variable.
+fn placeholders(source: &str) -> Result<Vec<(Range<usize>, &str)>, TemplateError> {
+    let mut placeholders = Vec::new();
+    let mut offset = 0;
+
+    while let Some(start) = source[offset..].find("${").map(|start| offset + start) {
+        let length = source[start..]
+            .find('}')
+            .ok_or(TemplateError::UnterminatedVariable)?;
+
+        let name = &source[start + 2..start + length];
+        if name.is_empty()
+            || !name
+                .chars()
+                .all(|character| character.is_ascii_alphanumeric() || character == '_')
+        {
+            return Err(TemplateError::InvalidVariable(name.to_string()));
+        }
+
+        offset = start + length + 1;
+        placeholders.push((start..offset, name));
+    }
+
+    Ok(placeholders)
+}
+
+/// Returns true if the value cannot change how the template is tokenized.
+fn is_token_value(value: &str) -> bool {
+    !value.is_empty()
+        && value.chars().all(|character| {
+            !character.is_whitespace()
+                && !character.is_control()
+                && !matches!(character, ';' | '(' | ')' | '"' | '$' | '\\')
+        })
+}
+
+impl ZoneTemplate {
+    /// Names of all variables referenced by the template.
+    pub fn variables(&self) -> BTreeSet<&str> {
+        // Placeholders are validated on construction.
+        placeholders(&self.0)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(_, name)| name)
+            .collect()
+    }
+
+    /// Substitutes the variables into the template, without parsing it.
+    pub fn render(&self, variables: &BTreeMap<String, String>) -> Result<String, TemplateError> {
+        let mut output = String::with_capacity(self.0.len());
+        let mut offset = 0;
+
+        for (range, name) in placeholders(&self.0)? {
+            let value = variables
+                .get(name)
+                .ok_or_else(|| TemplateError::UndefinedVariable(name.to_string()))?;
+
+            if !is_token_value(value) {
+                return Err(TemplateError::InvalidValue {
+                    name: name.to_string(),
+                    value: value.clone(),
+                });
+            }
+
+            output.push_str(&self.0[offset..range.start]);
+            output.push_str(value);
+            offset = range.end;
+        }
+
+        output.push_str(&self.0[offset..]);
+        Ok(output)
+    }
+
+    /// Instantiates the template for the given origin, substituting
+    /// the variables and validating the resulting records.
+    ///
+    /// The zone takes the class of the first record, defaulting to
+    /// [`Class::IN`] for templates without records.
+    pub fn instantiate(
+        &self,
+        origin: FullyQualifiedDomainName,
+        variables: &BTreeMap<String, String>,
+    ) -> Result<Zone, TemplateError> {
+        let records = ZoneFileParser::new(origin.clone())
+            .include_with(|_| {
+                Err(std::io::Error::new(
+                    std::io::ErrorKind::Unsupported,
+                    "$INCLUDE is not supported in templates",
+                ))
+            })
+            .parse(&self.render(variables)?)?;
+
+        let class = records.first().map_or(Class::IN, |record| record.class);
+        let mut zone = Zone::with_class(origin, class);
+
+        for record in records {
+            zone.insert(record)?;
+        }
+
+        Ok(zone)
+    }
+}
+
+impl TryFrom<String> for ZoneTemplate {
+    type Error = TemplateError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        placeholders(&value)?;
+
+        Ok(ZoneTemplate(value))
+    }
+}
+
+impl TryFrom<&str> for ZoneTemplate {
+    type Error = TemplateError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::try_from(value.to_string())
+    }
+}
+
+impl FromStr for ZoneTemplate {
+    type Err = TemplateError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl AsRef<str> for ZoneTemplate {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl JsonSchema for ZoneTemplate {
+    fn schema_name() -> String {
+        <String as JsonSchema>::schema_name()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        <String as JsonSchema>::json_schema(gen)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for ZoneTemplate {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+
+        Self::try_from(value).map_err(D::Error::custom)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for ZoneTemplate {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::{
+        error::{TemplateError, ZoneError, ZoneFileError},
+        FullyQualifiedDomainName, ZoneTemplate,
+    };
+
+    const TEMPLATE: &str = "$TTL 3600\n\
+                            @   NS ns1.${provider}.\n\
+                            www A  ${address}\n";
+
+    fn variables(address: &str) -> BTreeMap<String, String> {
+        BTreeMap::from([
+            (String::from("provider"), String::from("example.net")),
+            (String::from("address"), String::from(address)),
+        ])
+    }
+
+    #[test]
+    fn instantiate() {
+        let template = ZoneTemplate::try_from(TEMPLATE).unwrap();
+        assert_eq!(
+            template.variables().into_iter().collect::<Vec<_>>(),
+            ["address", "provider"]
+        );
+
+        let origin = FullyQualifiedDomainName::try_from("customer.example.org.").unwrap();
+        let zone = template
+            .instantiate(origin.clone(), &variables("192.168.0.1"))
+            .unwrap();
+
+        assert_eq!(zone.origin(), &origin);
+        assert_eq!(
+            zone.iter()
+                .map(|record| format!("{} {} {}", record.fqdn, record.r#type, record.rdata))
+                .collect::<Vec<_>>(),
+            [
+                "customer.example.org. NS ns1.example.net.",
+                "www.customer.example.org. A 192.168.0.1"
+            ]
+        );
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            ZoneTemplate::try_from("www A ${address"),
+            Err(TemplateError::UnterminatedVariable)
+        );
+        assert_eq!(
+            ZoneTemplate::try_from("www A ${ip-address}"),
+            Err(TemplateError::InvalidVariable(String::from("ip-address")))
+        );
+
+        let origin = FullyQualifiedDomainName::try_from("example.org.").unwrap();
+        let template = ZoneTemplate::try_from(TEMPLATE).unwrap();
+
+        assert_eq!(
+            template.instantiate(origin.clone(), &BTreeMap::new()),
+            Err(TemplateError::UndefinedVariable(String::from("provider")))
+        );
+        assert!(matches!(
+            template.instantiate(origin.clone(), &variables("192.168.0.256")),
+            Err(TemplateError::ZoneFileError(
+                ZoneFileError::InvalidRecordData { line: 3, .. }
+            ))
+        ));
+
+        // Values cannot inject additional records or directives.
+        for value in [
+            "192.168.0.1\n@ NS evil.example.",
+            "192.168.0.1 ; x",
+            "$INCLUDE",
+            "",
+        ] {
+            assert_eq!(
+                template.instantiate(origin.clone(), &variables(value)),
+                Err(TemplateError::InvalidValue {
+                    name: String::from("address"),
+                    value: String::from(value)
+                })
+            );
+        }
+
+        let template = ZoneTemplate::try_from("www.example.com. 60 A 192.168.0.1").unwrap();
+        assert!(matches!(
+            template.instantiate(origin, &BTreeMap::new()),
+            Err(TemplateError::ZoneError(ZoneError::OutOfZone(_)))
+        ));
+    }
+}
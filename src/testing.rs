@@ -0,0 +1,232 @@
+//! Deterministic generators of realistic sample data, for use in the
+//! integration tests and benchmarks of downstream crates.
+//!
+//! Everything generated is valid according to the same rules applied when
+//! parsing, and the same inputs always produce the same output, across runs
+//! and releases of the crate.
+//!
+//! ```text
+//! let origin = sample_fqdn(7);          // => acme.dev.
+//! let zone = sample_zone(origin, 100);  // => SOA, NS and 98 other records
+//! ```
+
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use crate::{
+    stagger::stable_hash, DomainName, FullyQualifiedDomainName, Record, RecordData, Type, Zone,
+};
+
+/// Labels from which the names of hosts and domains are composed.
+const WORDS: &[&str] = &[
+    "acme", "api", "app", "auth", "cdn", "db", "dev", "edge", "eu", "files", "git", "intranet",
+    "mail", "media", "prod", "shop", "staging", "status", "us", "vpn", "web", "www",
+];
+
+/// Top-level domains of generated names.
+const TLDS: &[&str] = &["com", "dev", "io", "net", "org"];
+
+/// Documentation prefixes from which IPv4 addresses are generated.
+const IPV4_PREFIXES: [[u8; 3]; 3] = [[192, 0, 2], [198, 51, 100], [203, 0, 113]];
+
+/// TTLs assigned to generated records.
+const TTLS: &[u32] = &[300, 3600, 86400];
+
+/// SplitMix64, which is small, fast and fully determined by its seed.
+struct Sampler(u64);
+
+impl Sampler {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a number in `0..bound`.
+    fn below(&mut self, bound: usize) -> usize {
+        (self.next() % bound as u64) as usize
+    }
+
+    fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+}
+
+/// Generates a domain name of two to four labels from the seed, such
+/// as `www.acme.org.`
+pub fn sample_fqdn(seed: u64) -> FullyQualifiedDomainName {
+    let mut sampler = Sampler(seed);
+
+    let mut labels: Vec<&str> = (0..=sampler.below(3))
+        .map(|_| *sampler.choose(WORDS))
+        .collect();
+    labels.push(*sampler.choose(TLDS));
+
+    FullyQualifiedDomainName::try_from(format!("{}.", labels.join(".")))
+        .expect("sample labels are valid")
+}
+
+/// Generates a zone of exactly `n_records` records within `origin`.
+///
+/// The zone starts with an SOA record, an NS record and the address of the
+/// name server it points to, followed by a mix of A, AAAA, CNAME, MX, SRV
+/// and TXT records, each owned by a distinct name, so that no CNAME shares
+/// its name with other records. The records are derived from the origin,
+/// such that the same origin always yields the same zone.
+///
+/// Names which would be too long for origins close to the maximum length
+/// are replaced by the origin itself, which only owns TXT records.
+pub fn sample_zone(origin: FullyQualifiedDomainName, n_records: usize) -> Zone {
+    let mut sampler = Sampler(stable_hash(origin.to_string().as_bytes()));
+    let mut zone = Zone::new(origin);
+
+    let name_server = zone.origin().try_prepend("ns1").ok();
+    let target = DomainName::Full(name_server.clone().unwrap_or_else(|| zone.origin().clone()));
+
+    for index in 0..n_records {
+        let owner = match index {
+            0 | 1 => Some(zone.origin().clone()),
+            2 => name_server.clone(),
+            _ => zone
+                .origin()
+                .try_prepend(&format!("{}{index}", sampler.choose(WORDS)))
+                .ok(),
+        };
+
+        let (fqdn, r#type, rdata) = match (index, owner) {
+            (0, Some(fqdn)) => (
+                fqdn,
+                Type::SOA,
+                RecordData::SOA {
+                    mname: target.clone(),
+                    rname: DomainName::Full(
+                        zone.origin()
+                            .try_prepend("hostmaster")
+                            .unwrap_or_else(|_| zone.origin().clone()),
+                    ),
+                    serial: 2024010100 + sampler.below(100) as u32,
+                    refresh: 7200,
+                    retry: 3600,
+                    expire: 1209600,
+                    minimum: 300,
+                },
+            ),
+            (1, Some(fqdn)) => (fqdn, Type::NS, RecordData::NS(target.clone())),
+            (_, Some(fqdn)) => match index {
+                2 => (fqdn, Type::A, RecordData::A(ipv4(index))),
+                _ => sample_rdata(&mut sampler, fqdn, &target, index),
+            },
+            (_, None) => (
+                zone.origin().clone(),
+                Type::TXT,
+                RecordData::TXT(vec![format!("sample={index}").into_bytes()]),
+            ),
+        };
+
+        zone.insert(Record {
+            fqdn,
+            class: zone.class(),
+            r#type,
+            ttl: *sampler.choose(TTLS),
+            rdata,
+        })
+        .expect("sample records are within the zone");
+    }
+
+    zone
+}
+
+/// Generates the record data of the record at `index`, owned by `fqdn`.
+fn sample_rdata(
+    sampler: &mut Sampler,
+    fqdn: FullyQualifiedDomainName,
+    target: &DomainName,
+    index: usize,
+) -> (FullyQualifiedDomainName, Type, RecordData) {
+    let (r#type, rdata) = match sampler.below(8) {
+        0..=2 => (Type::A, RecordData::A(ipv4(index))),
+        3 => (
+            Type::AAAA,
+            RecordData::AAAA(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, index as u16)),
+        ),
+        4 => (Type::CNAME, RecordData::CNAME(target.clone())),
+        5 => (
+            Type::MX,
+            RecordData::MX {
+                preference: 10 * (1 + sampler.below(3) as u16),
+                exchange: target.clone(),
+            },
+        ),
+        6 => (
+            Type::SRV,
+            RecordData::SRV {
+                priority: 10,
+                weight: sampler.below(100) as u16,
+                port: *sampler.choose(&[443, 5060, 5222]),
+                target: target.clone(),
+            },
+        ),
+        _ => (
+            Type::TXT,
+            RecordData::TXT(vec![
+                format!("v=sample{} id={index}", sampler.below(10)).into_bytes()
+            ]),
+        ),
+    };
+
+    (fqdn, r#type, rdata)
+}
+
+/// IPv4 address within one of the documentation prefixes.
+fn ipv4(index: usize) -> Ipv4Addr {
+    let [a, b, c] = IPV4_PREFIXES[(index / 254) % IPV4_PREFIXES.len()];
+
+    Ipv4Addr::new(a, b, c, (index % 254) as u8 + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        fqdn,
+        testing::{sample_fqdn, sample_zone},
+        Type,
+    };
+
+    #[test]
+    fn deterministic_samples() {
+        assert_eq!(sample_fqdn(7), sample_fqdn(7));
+        for seed in 0..64 {
+            sample_fqdn(seed).debug_validate();
+        }
+
+        let zone = sample_zone(fqdn!("example.org."), 200);
+        zone.debug_validate();
+        assert_eq!(zone.len(), 200);
+        assert_eq!(zone, sample_zone(fqdn!("example.org."), 200));
+        assert_eq!(
+            zone.iter()
+                .filter(|record| record.r#type == Type::SOA)
+                .count(),
+            1
+        );
+
+        // Every record is parsed back identically.
+        for record in zone.iter() {
+            assert_eq!(
+                &record.to_string().parse::<crate::Record>().unwrap(),
+                record
+            );
+        }
+
+        // Leaves no room for any labels below the origin.
+        let long = crate::FullyQualifiedDomainName::try_from(format!(
+            "{}.{}.",
+            vec!["a".repeat(63); 3].join("."),
+            "a".repeat(58)
+        ))
+        .unwrap();
+        assert_eq!(sample_zone(long, 10).len(), 10);
+    }
+}
@@ -0,0 +1,428 @@
+use std::{fmt::Display, str::FromStr};
+
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+#[cfg(feature = "serde")]
+use serde::{de::Error, Deserialize, Serialize};
+use thiserror::Error;
+
+/// Largest representable timestamp, `9999-12-31T23:59:59Z`, which is the
+/// last second expressible in RFC 3339.
+const MAX_SECONDS: u64 = 253_402_300_799;
+
+/// Produced when constructing or parsing a [`Timestamp`] fails.
+#[derive(Error, Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum TimestampError {
+    /// The string is not a valid RFC 3339 timestamp.
+    #[error("invalid RFC 3339 timestamp {0:?}")]
+    InvalidFormat(String),
+    /// The timestamp lies before the UNIX epoch, after the year 9999,
+    /// or does not fit in the field it is converted from.
+    #[error("timestamp out of range")]
+    OutOfRange,
+}
+
+/// Point in time with second precision, as carried in the signature
+/// inception and expiration fields of RRSIG records (RFC 4034), and
+/// the time signed field of TSIG records (RFC 8945).
+///
+/// Serialized as an RFC 3339 string in UTC, such as `2024-01-31T12:00:00Z`.
+#[derive(Default, Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Timestamp(u64);
+
+impl Timestamp {
+    /// Constructs a timestamp from seconds since the UNIX epoch.
+    pub fn from_unix(seconds: u64) -> Result<Self, TimestampError> {
+        if seconds > MAX_SECONDS {
+            return Err(TimestampError::OutOfRange);
+        }
+
+        Ok(Timestamp(seconds))
+    }
+
+    /// Seconds since the UNIX epoch.
+    pub fn unix(&self) -> u64 {
+        self.0
+    }
+
+    /// Interprets a 32-bit RRSIG inception or expiration field.
+    ///
+    /// The field wraps around every 136 years, so it is resolved using
+    /// serial number arithmetic as described in RFC 4034, section 3.1.5,
+    /// picking the point in time closest to `reference`, usually the
+    /// current time.
+    pub fn from_rrsig(field: u32, reference: Timestamp) -> Self {
+        const WRAP: i64 = 1 << 32;
+
+        let reference = reference.0 as i64;
+        let mut seconds = (reference & !(WRAP - 1)) | i64::from(field);
+
+        if seconds - reference > WRAP / 2 {
+            seconds -= WRAP;
+        } else if reference - seconds > WRAP / 2 {
+            seconds += WRAP;
+        }
+
+        if seconds < 0 {
+            seconds += WRAP;
+        } else if seconds as u64 > MAX_SECONDS {
+            seconds -= WRAP;
+        }
+
+        Timestamp(seconds as u64)
+    }
+
+    /// Encodes the timestamp as a 32-bit RRSIG inception or expiration
+    /// field, which is the number of seconds since the epoch modulo 2^32.
+    pub fn to_rrsig(&self) -> u32 {
+        self.0 as u32
+    }
+
+    /// Interprets a 48-bit TSIG time signed field.
+    pub fn from_tsig(field: u64) -> Result<Self, TimestampError> {
+        if field >= 1 << 48 {
+            return Err(TimestampError::OutOfRange);
+        }
+
+        Self::from_unix(field)
+    }
+
+    /// Encodes the timestamp as a 48-bit TSIG time signed field.
+    pub fn to_tsig(&self) -> u64 {
+        self.0
+    }
+
+    /// Calendar date in UTC, as (year, month, day).
+    pub(crate) fn date(&self) -> (i64, i64, i64) {
+        civil_from_days((self.0 / 86_400) as i64)
+    }
+}
+
+/// Number of days since the UNIX epoch for the given proleptic
+/// Gregorian calendar date.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Proleptic Gregorian calendar date for the given number of days
+/// since the UNIX epoch.
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month + 2) / 5 + 1;
+    let month = if month < 10 { month + 3 } else { month - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    (year, month, day)
+}
+
+fn days_in_month(year: i64, month: i64) -> i64 {
+    let leap = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+
+    match month {
+        2 if leap => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+impl TryFrom<&str> for Timestamp {
+    type Error = TimestampError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let invalid = || TimestampError::InvalidFormat(value.to_string());
+        let bytes = value.as_bytes();
+
+        let number = |range: std::ops::Range<usize>| -> Result<i64, TimestampError> {
+            let digits = bytes.get(range).ok_or_else(invalid)?;
+            if !digits.iter().all(u8::is_ascii_digit) {
+                return Err(invalid());
+            }
+
+            Ok(digits
+                .iter()
+                .fold(0, |total, digit| total * 10 + i64::from(digit - b'0')))
+        };
+
+        let separators = [(4, b'-'), (7, b'-'), (13, b':'), (16, b':')];
+        if separators
+            .iter()
+            .any(|(index, separator)| bytes.get(*index) != Some(separator))
+            || !matches!(bytes.get(10), Some(b'T' | b't' | b' '))
+        {
+            return Err(invalid());
+        }
+
+        let (year, month, day) = (number(0..4)?, number(5..7)?, number(8..10)?);
+        let (hour, minute, second) = (number(11..13)?, number(14..16)?, number(17..19)?);
+
+        if !(1..=12).contains(&month)
+            || !(1..=days_in_month(year, month)).contains(&day)
+            || hour > 23
+            || minute > 59
+            || second > 59
+        {
+            return Err(invalid());
+        }
+
+        // Fractional seconds are truncated, since DNS timestamps
+        // only have second precision.
+        let mut rest = &value[19..];
+        if let Some(fraction) = rest.strip_prefix('.') {
+            let digits = fraction.len()
+                - fraction
+                    .trim_start_matches(|c: char| c.is_ascii_digit())
+                    .len();
+            if digits == 0 {
+                return Err(invalid());
+            }
+
+            rest = &fraction[digits..];
+        }
+
+        let offset = match rest.as_bytes() {
+            [b'Z' | b'z'] => 0,
+            [sign @ (b'+' | b'-'), _, _, b':', _, _] => {
+                let start = value.len() - 5;
+                let (hours, minutes) = (number(start..start + 2)?, number(start + 3..start + 5)?);
+                if hours > 23 || minutes > 59 {
+                    return Err(invalid());
+                }
+
+                let offset = hours * 3600 + minutes * 60;
+                if *sign == b'-' {
+                    -offset
+                } else {
+                    offset
+                }
+            }
+            _ => return Err(invalid()),
+        };
+
+        let seconds =
+            days_from_civil(year, month, day) * 86_400 + hour * 3600 + minute * 60 + second
+                - offset;
+
+        u64::try_from(seconds)
+            .map_err(|_| TimestampError::OutOfRange)
+            .and_then(Timestamp::from_unix)
+    }
+}
+
+impl TryFrom<String> for Timestamp {
+    type Error = TimestampError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+
+impl FromStr for Timestamp {
+    type Err = TimestampError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl Display for Timestamp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let seconds = self.0 as i64;
+        let (year, month, day) = civil_from_days(seconds.div_euclid(86_400));
+        let time = seconds.rem_euclid(86_400);
+
+        write!(
+            f,
+            "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z",
+            time / 3600,
+            time / 60 % 60,
+            time % 60
+        )
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl JsonSchema for Timestamp {
+    fn schema_name() -> String {
+        String::from("Timestamp")
+    }
+
+    fn json_schema(_: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        schemars::schema::SchemaObject {
+            instance_type: Some(schemars::schema::InstanceType::String.into()),
+            format: Some(String::from("date-time")),
+            ..Default::default()
+        }
+        .into()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Timestamp {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+
+        Self::try_from(value).map_err(D::Error::custom)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Timestamp {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<Timestamp> for chrono::DateTime<chrono::Utc> {
+    fn from(value: Timestamp) -> Self {
+        // All timestamps up to the year 9999 are representable.
+        chrono::DateTime::from_timestamp(value.0 as i64, 0).unwrap_or_default()
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz: chrono::TimeZone> TryFrom<chrono::DateTime<Tz>> for Timestamp {
+    type Error = TimestampError;
+
+    fn try_from(value: chrono::DateTime<Tz>) -> Result<Self, Self::Error> {
+        u64::try_from(value.timestamp())
+            .map_err(|_| TimestampError::OutOfRange)
+            .and_then(Timestamp::from_unix)
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<Timestamp> for time::OffsetDateTime {
+    fn from(value: Timestamp) -> Self {
+        // All timestamps up to the year 9999 are representable.
+        time::OffsetDateTime::from_unix_timestamp(value.0 as i64)
+            .unwrap_or(time::OffsetDateTime::UNIX_EPOCH)
+    }
+}
+
+#[cfg(feature = "time")]
+impl TryFrom<time::OffsetDateTime> for Timestamp {
+    type Error = TimestampError;
+
+    fn try_from(value: time::OffsetDateTime) -> Result<Self, Self::Error> {
+        u64::try_from(value.unix_timestamp())
+            .map_err(|_| TimestampError::OutOfRange)
+            .and_then(Timestamp::from_unix)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{error::TimestampError, Timestamp};
+
+    #[test]
+    fn rfc3339() {
+        let timestamp = Timestamp::from_unix(1_706_702_400).unwrap();
+
+        assert_eq!(timestamp.to_string(), "2024-01-31T12:00:00Z");
+        assert_eq!("2024-01-31T12:00:00Z".parse(), Ok(timestamp));
+        assert_eq!("2024-01-31t13:30:00.250+01:30".parse(), Ok(timestamp));
+
+        assert_eq!(
+            "1970-01-01T00:00:00Z".parse(),
+            Ok(Timestamp::from_unix(0).unwrap())
+        );
+        assert_eq!(
+            "9999-12-31T23:59:59Z"
+                .parse::<Timestamp>()
+                .unwrap()
+                .to_string(),
+            "9999-12-31T23:59:59Z"
+        );
+
+        assert_eq!(
+            "1970-01-01T00:00:00+01:00".parse::<Timestamp>(),
+            Err(TimestampError::OutOfRange)
+        );
+        assert_eq!(
+            "2023-02-29T00:00:00Z".parse::<Timestamp>(),
+            Err(TimestampError::InvalidFormat(String::from(
+                "2023-02-29T00:00:00Z"
+            )))
+        );
+        assert!("2024-01-31T12:00:00".parse::<Timestamp>().is_err());
+    }
+
+    #[test]
+    fn rrsig_serial_arithmetic() {
+        let now = Timestamp::from_unix(1_706_702_400).unwrap();
+
+        assert_eq!(Timestamp::from_rrsig(now.to_rrsig(), now), now);
+
+        // After the 32-bit field wraps in 2106, values are still resolved
+        // relative to the reference.
+        let later = Timestamp::from_unix((1 << 32) + 1000).unwrap();
+        let earlier = Timestamp::from_unix((1 << 32) - 1000).unwrap();
+
+        assert_eq!(later.to_rrsig(), 1000);
+        assert_eq!(Timestamp::from_rrsig(1000, earlier), later);
+        assert_eq!(Timestamp::from_rrsig(earlier.to_rrsig(), later), earlier);
+    }
+
+    #[test]
+    fn tsig() {
+        assert_eq!(
+            Timestamp::from_tsig(1 << 48),
+            Err(TimestampError::OutOfRange)
+        );
+        assert_eq!(
+            Timestamp::from_tsig(1_706_702_400).map(|timestamp| timestamp.to_tsig()),
+            Ok(1_706_702_400)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let timestamp: Timestamp = serde_yaml::from_str("2024-01-31T12:00:00Z").unwrap();
+
+        assert_eq!(
+            serde_yaml::to_string(&timestamp).unwrap(),
+            "2024-01-31T12:00:00Z\n"
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn chrono() {
+        let timestamp = Timestamp::from_unix(1_706_702_400).unwrap();
+        let datetime = chrono::DateTime::<chrono::Utc>::from(timestamp);
+
+        assert_eq!(datetime.timestamp(), 1_706_702_400);
+        assert_eq!(Timestamp::try_from(datetime), Ok(timestamp));
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn time() {
+        let timestamp = Timestamp::from_unix(1_706_702_400).unwrap();
+        let datetime = time::OffsetDateTime::from(timestamp);
+
+        assert_eq!(datetime.unix_timestamp(), 1_706_702_400);
+        assert_eq!(Timestamp::try_from(datetime), Ok(timestamp));
+    }
+}
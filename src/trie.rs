@@ -0,0 +1,277 @@
+use std::collections::BTreeMap;
+
+use crate::{DomainSegment, FullyQualifiedDomainName};
+
+/// Map keyed by [`FullyQualifiedDomainName`], stored as a tree of labels
+/// starting from the root.
+///
+/// Lookups take time proportional to the number of labels in the name,
+/// rather than the number of entries, which makes it well suited for
+/// finding the zone a name belongs to:
+///
+/// ```text
+/// example.org.     -> zone A
+/// dev.example.org. -> zone B
+///
+/// www.dev.example.org. matches dev.example.org.
+/// api.example.org.     matches example.org.
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DomainTrie<T> {
+    root: Node<T>,
+    len: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Node<T> {
+    value: Option<T>,
+    children: BTreeMap<DomainSegment, Node<T>>,
+}
+
+impl<T> Default for Node<T> {
+    fn default() -> Self {
+        Node {
+            value: None,
+            children: BTreeMap::new(),
+        }
+    }
+}
+
+impl<T> Node<T> {
+    /// Appends all entries of the subtree to `entries` in canonical order,
+    /// `labels` being the reversed labels leading to this node.
+    fn collect<'a: 'b, 'b>(
+        &'a self,
+        labels: &mut Vec<&'b DomainSegment>,
+        entries: &mut Vec<(FullyQualifiedDomainName, &'a T)>,
+    ) {
+        if let Some(value) = &self.value {
+            entries.push((labels.iter().rev().copied().collect(), value));
+        }
+
+        for (label, child) in &self.children {
+            labels.push(label);
+            child.collect(labels, entries);
+            labels.pop();
+        }
+    }
+
+    /// Removes the value at the end of `labels`, pruning nodes
+    /// left without a value or children.
+    fn remove<'a>(&mut self, mut labels: impl Iterator<Item = &'a DomainSegment>) -> Option<T> {
+        let Some(label) = labels.next() else {
+            return self.value.take();
+        };
+
+        let child = self.children.get_mut(label)?;
+        let value = child.remove(labels);
+
+        if child.value.is_none() && child.children.is_empty() {
+            self.children.remove(label);
+        }
+
+        value
+    }
+}
+
+impl<T> Default for DomainTrie<T> {
+    fn default() -> Self {
+        DomainTrie {
+            root: Node::default(),
+            len: 0,
+        }
+    }
+}
+
+impl<T> DomainTrie<T> {
+    /// Constructs an empty trie.
+    pub fn new() -> Self {
+        DomainTrie::default()
+    }
+
+    fn node(&self, fqdn: &FullyQualifiedDomainName) -> Option<&Node<T>> {
+        fqdn.iter()
+            .rev()
+            .try_fold(&self.root, |node, label| node.children.get(label))
+    }
+
+    /// Inserts a value for the given name, returning the value it replaced, if any.
+    pub fn insert(&mut self, fqdn: &FullyQualifiedDomainName, value: T) -> Option<T> {
+        let node = fqdn.iter().rev().fold(&mut self.root, |node, label| {
+            node.children.entry(label.clone()).or_default()
+        });
+
+        let previous = node.value.replace(value);
+        if previous.is_none() {
+            self.len += 1;
+        }
+
+        previous
+    }
+
+    /// Removes the value for the given name.
+    pub fn remove(&mut self, fqdn: &FullyQualifiedDomainName) -> Option<T> {
+        let value = self.root.remove(fqdn.iter().rev());
+        if value.is_some() {
+            self.len -= 1;
+        }
+
+        value
+    }
+
+    /// Returns the value for exactly the given name, if present.
+    pub fn get(&self, fqdn: &FullyQualifiedDomainName) -> Option<&T> {
+        self.node(fqdn)?.value.as_ref()
+    }
+
+    /// Returns the value for exactly the given name for modification, if present.
+    pub fn get_mut(&mut self, fqdn: &FullyQualifiedDomainName) -> Option<&mut T> {
+        fqdn.iter()
+            .rev()
+            .try_fold(&mut self.root, |node, label| node.children.get_mut(label))?
+            .value
+            .as_mut()
+    }
+
+    /// Returns true if the trie contains a value for exactly the given name.
+    pub fn contains_key(&self, fqdn: &FullyQualifiedDomainName) -> bool {
+        self.get(fqdn).is_some()
+    }
+
+    /// Finds the longest name in the trie which is either equal to `fqdn`,
+    /// or one of its parents, returning it along with its value.
+    pub fn longest_suffix_match(
+        &self,
+        fqdn: &FullyQualifiedDomainName,
+    ) -> Option<(FullyQualifiedDomainName, &T)> {
+        let mut node = &self.root;
+        let mut best = node.value.as_ref().map(|value| (0, value));
+
+        for (depth, label) in fqdn.iter().rev().enumerate() {
+            let Some(child) = node.children.get(label) else {
+                break;
+            };
+
+            node = child;
+            if let Some(value) = &node.value {
+                best = Some((depth + 1, value));
+            }
+        }
+
+        best.map(|(depth, value)| {
+            let skip = fqdn.iter().len() - depth;
+            (fqdn.iter().skip(skip).collect(), value)
+        })
+    }
+
+    /// Iterates over the entries for `apex` and all of its subdomains,
+    /// in canonical order.
+    pub fn under(
+        &self,
+        apex: &FullyQualifiedDomainName,
+    ) -> impl Iterator<Item = (FullyQualifiedDomainName, &T)> + '_ {
+        let mut entries = Vec::new();
+
+        if let Some(node) = self.node(apex) {
+            let mut labels: Vec<_> = apex.iter().rev().collect();
+            node.collect(&mut labels, &mut entries);
+        }
+
+        entries.into_iter()
+    }
+
+    /// Iterates over all entries, in canonical order.
+    pub fn iter(&self) -> impl Iterator<Item = (FullyQualifiedDomainName, &T)> + '_ {
+        self.under(&FullyQualifiedDomainName::default())
+    }
+
+    /// Number of entries in the trie.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the trie contains no entries.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl<T> Extend<(FullyQualifiedDomainName, T)> for DomainTrie<T> {
+    fn extend<I: IntoIterator<Item = (FullyQualifiedDomainName, T)>>(&mut self, iter: I) {
+        for (fqdn, value) in iter {
+            self.insert(&fqdn, value);
+        }
+    }
+}
+
+impl<T> FromIterator<(FullyQualifiedDomainName, T)> for DomainTrie<T> {
+    fn from_iter<I: IntoIterator<Item = (FullyQualifiedDomainName, T)>>(iter: I) -> Self {
+        let mut trie = DomainTrie::new();
+        trie.extend(iter);
+        trie
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{fqdn, DomainTrie};
+
+    fn trie() -> DomainTrie<&'static str> {
+        [
+            (fqdn!("example.org."), "A"),
+            (fqdn!("dev.example.org."), "B"),
+            (fqdn!("example.com."), "C"),
+        ]
+        .into_iter()
+        .collect()
+    }
+
+    #[test]
+    fn longest_suffix_match() {
+        let trie = trie();
+
+        assert_eq!(
+            trie.longest_suffix_match(&fqdn!("www.dev.example.org.")),
+            Some((fqdn!("dev.example.org."), &"B"))
+        );
+        assert_eq!(
+            trie.longest_suffix_match(&fqdn!("api.example.org.")),
+            Some((fqdn!("example.org."), &"A"))
+        );
+        assert_eq!(
+            trie.longest_suffix_match(&fqdn!("example.org.")),
+            Some((fqdn!("example.org."), &"A"))
+        );
+        assert_eq!(trie.longest_suffix_match(&fqdn!("org.")), None);
+        assert_eq!(trie.get(&fqdn!("www.example.org.")), None);
+    }
+
+    #[test]
+    fn iteration_and_removal() {
+        let mut trie = trie();
+        trie.insert(&fqdn!("a.dev.example.org."), "D");
+        assert_eq!(trie.len(), 4);
+
+        assert_eq!(
+            trie.under(&fqdn!("example.org."))
+                .map(|(fqdn, value)| format!("{fqdn}={value}"))
+                .collect::<Vec<_>>(),
+            [
+                "example.org.=A",
+                "dev.example.org.=B",
+                "a.dev.example.org.=D"
+            ]
+        );
+
+        assert_eq!(trie.remove(&fqdn!("dev.example.org.")), Some("B"));
+        assert_eq!(trie.remove(&fqdn!("dev.example.org.")), None);
+        assert_eq!(trie.remove(&fqdn!("a.dev.example.org.")), Some("D"));
+        assert_eq!(trie.len(), 2);
+
+        assert_eq!(
+            trie.iter().map(|(fqdn, _)| fqdn).collect::<Vec<_>>(),
+            [fqdn!("example.com."), fqdn!("example.org.")]
+        );
+        assert!(trie.under(&fqdn!("dev.example.org.")).next().is_none());
+    }
+}
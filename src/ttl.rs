@@ -0,0 +1,264 @@
+use std::{fmt::Display, str::FromStr, time::Duration};
+
+#[cfg(feature = "schemars")]
+use schemars::{
+    schema::{InstanceType, Schema, SchemaObject, StringValidation, SubschemaValidation},
+    JsonSchema,
+};
+#[cfg(feature = "serde")]
+use serde::{de::Error as _, Deserialize, Serialize};
+use thiserror::Error;
+
+/// Produced when attempting to construct an invalid [`Ttl`].
+#[derive(Error, Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum TtlError {
+    /// The string is neither a number of seconds, nor a BIND duration.
+    #[error("invalid duration {0:?}")]
+    InvalidDuration(String),
+    /// The TTL exceeds the maximum of 2147483647 seconds.
+    #[error("ttl out of range {0} > 2147483647")]
+    OutOfRange(u64),
+}
+
+/// Time to live of a record, in seconds.
+///
+/// Besides plain numbers of seconds, TTLs can be parsed from BIND style
+/// durations, which consist of numbers suffixed by units of weeks (`w`),
+/// days (`d`), hours (`h`), minutes (`m`) or seconds (`s`), such as `1h30m`.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Ttl(u32);
+
+impl Ttl {
+    /// Largest permitted TTL, since RFC 2181 requires TTLs with
+    /// the most significant bit set to be treated as zero.
+    pub const MAX: Ttl = Ttl(i32::MAX as u32);
+
+    /// Constructs a TTL from a number of seconds, if it is within range.
+    pub const fn from_secs(seconds: u32) -> Result<Self, TtlError> {
+        if seconds > Self::MAX.0 {
+            return Err(TtlError::OutOfRange(seconds as u64));
+        }
+
+        Ok(Ttl(seconds))
+    }
+
+    /// Number of seconds.
+    pub const fn as_secs(&self) -> u32 {
+        self.0
+    }
+}
+
+/// Parses a number of seconds, or a BIND style duration such as `1h30m`,
+/// returning [`None`] if the value is invalid or overflows.
+pub(crate) fn parse_duration(value: &str) -> Option<u32> {
+    if value.is_empty() {
+        return None;
+    }
+
+    let mut total: u32 = 0;
+    let mut current: Option<u32> = None;
+
+    for c in value.chars() {
+        if let Some(digit) = c.to_digit(10) {
+            current = Some(current.unwrap_or(0).checked_mul(10)?.checked_add(digit)?);
+            continue;
+        }
+
+        let unit = match c.to_ascii_lowercase() {
+            's' => 1,
+            'm' => 60,
+            'h' => 60 * 60,
+            'd' => 24 * 60 * 60,
+            'w' => 7 * 24 * 60 * 60,
+            _ => return None,
+        };
+
+        total = total.checked_add(current.take()?.checked_mul(unit)?)?;
+    }
+
+    total.checked_add(current.unwrap_or(0))
+}
+
+impl TryFrom<&str> for Ttl {
+    type Error = TtlError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let seconds =
+            parse_duration(value).ok_or_else(|| TtlError::InvalidDuration(value.to_string()))?;
+
+        Ttl::from_secs(seconds)
+    }
+}
+
+impl TryFrom<String> for Ttl {
+    type Error = TtlError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+
+impl FromStr for Ttl {
+    type Err = TtlError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl TryFrom<u32> for Ttl {
+    type Error = TtlError;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        Ttl::from_secs(value)
+    }
+}
+
+impl From<Ttl> for u32 {
+    fn from(value: Ttl) -> Self {
+        value.0
+    }
+}
+
+impl TryFrom<Duration> for Ttl {
+    type Error = TtlError;
+
+    /// Converts the duration into a TTL, discarding fractional seconds.
+    fn try_from(value: Duration) -> Result<Self, Self::Error> {
+        let seconds = value.as_secs();
+
+        u32::try_from(seconds)
+            .map_err(|_| TtlError::OutOfRange(seconds))
+            .and_then(Ttl::from_secs)
+    }
+}
+
+impl From<Ttl> for Duration {
+    fn from(value: Ttl) -> Self {
+        Duration::from_secs(u64::from(value.0))
+    }
+}
+
+impl Display for Ttl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl JsonSchema for Ttl {
+    fn schema_name() -> String {
+        String::from("Ttl")
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> Schema {
+        let mut seconds = <u32 as JsonSchema>::json_schema(gen).into_object();
+        seconds.number().maximum = Some(f64::from(Ttl::MAX.0));
+
+        let duration = SchemaObject {
+            instance_type: Some(InstanceType::String.into()),
+            string: Some(Box::new(StringValidation {
+                pattern: Some(String::from("^([0-9]+[wdhmsWDHMS]?)+$")),
+                ..Default::default()
+            })),
+            ..Default::default()
+        };
+
+        SchemaObject {
+            subschemas: Some(Box::new(SubschemaValidation {
+                any_of: Some(vec![seconds.into(), duration.into()]),
+                ..Default::default()
+            })),
+            ..Default::default()
+        }
+        .into()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Ttl {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Repr {
+            Seconds(u64),
+            Duration(String),
+        }
+
+        match Repr::deserialize(deserializer)? {
+            Repr::Seconds(seconds) => u32::try_from(seconds)
+                .map_err(|_| TtlError::OutOfRange(seconds))
+                .and_then(Ttl::from_secs),
+            Repr::Duration(duration) => Ttl::try_from(duration),
+        }
+        .map_err(D::Error::custom)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Ttl {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::{error::TtlError, Ttl};
+
+    #[test]
+    fn parsing() {
+        assert_eq!("3600".parse(), Ttl::from_secs(3600));
+        assert_eq!("1h30m".parse(), Ttl::from_secs(5400));
+        assert_eq!("2D".parse(), Ttl::from_secs(172800));
+        assert_eq!("1w1s".parse(), Ttl::from_secs(604801));
+
+        for invalid in ["", "h", "1x", "1.5h", "99999999999"] {
+            assert_eq!(
+                invalid.parse::<Ttl>(),
+                Err(TtlError::InvalidDuration(invalid.to_string()))
+            );
+        }
+
+        assert_eq!(
+            "4000000000".parse::<Ttl>(),
+            Err(TtlError::OutOfRange(4000000000))
+        );
+    }
+
+    #[test]
+    fn conversions() {
+        let ttl = Ttl::try_from(Duration::from_millis(90_500)).unwrap();
+        assert_eq!(u32::from(ttl), 90);
+        assert_eq!(Duration::from(ttl), Duration::from_secs(90));
+
+        assert_eq!(
+            Ttl::try_from(Duration::from_secs(u64::from(u32::MAX) + 1)),
+            Err(TtlError::OutOfRange(u64::from(u32::MAX) + 1))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let ttls: Vec<Ttl> = serde_yaml::from_str("[300, 1h, \"5m\"]").unwrap();
+        assert_eq!(
+            ttls.iter().map(Ttl::as_secs).collect::<Vec<_>>(),
+            [300, 3600, 300]
+        );
+
+        assert_eq!(
+            serde_yaml::to_string(&ttls).unwrap(),
+            "- 300\n- 3600\n- 300\n"
+        );
+        assert!(serde_yaml::from_str::<Ttl>("4294967296").is_err());
+    }
+}
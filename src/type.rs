@@ -1,24 +1,20 @@
-use std::fmt::Display;
+use std::{fmt::Display, str::FromStr};
 
-use schemars::JsonSchema;
-use serde::{Deserialize, Serialize};
+#[cfg(feature = "schemars")]
+use schemars::{
+    schema::{InstanceType, Schema, SchemaObject, StringValidation},
+    JsonSchema,
+};
+#[cfg(feature = "serde")]
+use serde::{de::Error, Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::MnemonicRegistry;
 
 /// Domain Name System type.
 #[allow(clippy::upper_case_acronyms)]
-#[derive(
-    Default,
-    Serialize,
-    Deserialize,
-    JsonSchema,
-    Clone,
-    Copy,
-    Debug,
-    Hash,
-    PartialEq,
-    Eq,
-    PartialOrd,
-    Ord,
-)]
+#[cfg_attr(feature = "valuable", derive(valuable::Valuable))]
+#[derive(Default, Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub enum Type {
     /// Address record
     ///
@@ -39,12 +35,25 @@ pub enum Type {
     ///
     /// [1183](https://datatracker.ietf.org/doc/html/rfc1183)
     AFSDB,
+    /// All cached records
+    ///
+    /// Query type requesting all records of all types known to the name server, also written as `*`.
+    ///
+    /// [1035](https://datatracker.ietf.org/doc/html/rfc1035)
+    /// [8482](https://datatracker.ietf.org/doc/html/rfc8482)
+    ANY,
     /// Address Prefix List
     ///
     /// Specify lists of address ranges, e.g. in CIDR format, for various address families. Experimental.
     ///
     /// [3123](https://datatracker.ietf.org/doc/html/rfc3123)
     APL,
+    /// Authoritative Zone Transfer
+    ///
+    /// Query type requesting a transfer of the entire zone from the primary name server to secondary name servers.
+    ///
+    /// [5936](https://datatracker.ietf.org/doc/html/rfc5936)
+    AXFR,
     /// Certification Authority Authorization
     ///
     /// DNS Certification Authority Authorization, constraining acceptable CAs for a host/domain
@@ -145,6 +154,12 @@ pub enum Type {
     ///
     /// [4025](https://datatracker.ietf.org/doc/html/rfc4025)
     IPSECKEY,
+    /// Incremental Zone Transfer
+    ///
+    /// Query type requesting a transfer of only the changes made to a zone since a previous serial number.
+    ///
+    /// [1995](https://datatracker.ietf.org/doc/html/rfc1995)
+    IXFR,
     /// Key record
     ///
     /// Used only for SIG(0) (RFC 2931) and TKEY (RFC 2930).[5] RFC 3445 eliminated their use for application keys and limited their use to DNSSEC.[6] RFC 3755 designates DNSKEY as the replacement within DNSSEC.[7] RFC 4025 designates IPSECKEY as the replacement for use with IPsec.[8]
@@ -164,6 +179,12 @@ pub enum Type {
     ///
     /// [1876](https://datatracker.ietf.org/doc/html/rfc1876)
     LOC,
+    /// Mailbox or mail list information
+    ///
+    /// Specifies the mailboxes responsible for a mailing list or mailbox, and where errors should be reported. Obsolete.
+    ///
+    /// [1035](https://datatracker.ietf.org/doc/html/rfc1035)
+    MINFO,
     /// Mail exchange record
     ///
     /// List of mail exchange servers that accept email for a domain
@@ -183,6 +204,12 @@ pub enum Type {
     ///
     /// [1035](https://datatracker.ietf.org/doc/html/rfc1035)
     NS,
+    /// Network Service Access Point
+    ///
+    /// Maps a domain name to an OSI network service access point address. Historic.
+    ///
+    /// [1706](https://datatracker.ietf.org/doc/html/rfc1706)
+    NSAP,
     /// Next Secure record
     ///
     /// Part of DNSSEC—used to prove a name does not exist. Uses the same format as the (obsolete) NXT record.
@@ -201,12 +228,24 @@ pub enum Type {
     ///
     /// [5155](https://datatracker.ietf.org/doc/html/rfc5155)
     NSEC3PARAM,
+    /// Null record
+    ///
+    /// Holds up to 65535 octets of arbitrary data. Experimental and not permitted in zone files.
+    ///
+    /// [1035](https://datatracker.ietf.org/doc/html/rfc1035)
+    NULL,
     /// OpenPGP public key record
     ///
     /// A DNS-based Authentication of Named Entities (DANE) method for publishing and locating OpenPGP public keys in DNS for a specific email address using an OPENPGPKEY DNS resource record.
     ///
     /// [7929](https://datatracker.ietf.org/doc/html/rfc7929)
     OPENPGPKEY,
+    /// Option
+    ///
+    /// Pseudo-record carrying EDNS options, such as the advertised UDP payload size, in the additional section of messages.
+    ///
+    /// [6891](https://datatracker.ietf.org/doc/html/rfc6891)
+    OPT,
     /// PTR Resource Record
     ///
     /// Pointer to a canonical name. Unlike a CNAME, DNS processing stops and just the name is returned. The most common use is for implementing reverse DNS lookups, but other uses include such things as DNS-SD.
@@ -225,6 +264,12 @@ pub enum Type {
     ///
     /// [1183](https://datatracker.ietf.org/doc/html/rfc1183)
     RP,
+    /// Route Through
+    ///
+    /// Specifies an intermediate host which can route packets to a host without direct connectivity. Historic.
+    ///
+    /// [1183](https://datatracker.ietf.org/doc/html/rfc1183)
+    RT,
     /// Signature
     ///
     /// Signature record used in SIG(0) (RFC 2931) and TKEY (RFC 2930).[7] RFC 3755 designated RRSIG as the replacement for SIG for use within DNSSEC.[7]
@@ -296,12 +341,61 @@ pub enum Type {
     ///
     /// [7553](https://datatracker.ietf.org/doc/html/rfc7553)
     URI,
+    /// Well Known Service description
+    ///
+    /// Describes the services supported by a host on a particular protocol. Obsolete, superseded by SRV.
+    ///
+    /// [1035](https://datatracker.ietf.org/doc/html/rfc1035)
+    WKS,
+    /// X.25 address
+    ///
+    /// Maps a domain name to a PSDN (X.121) address. Historic.
+    ///
+    /// [1183](https://datatracker.ietf.org/doc/html/rfc1183)
+    X25,
     /// Message Digests for DNS Zones
     ///
     /// Provides a cryptographic message digest over DNS zone data at rest.
     ///
     /// [8976](https://datatracker.ietf.org/doc/html/rfc8976)
     ZONEMD,
+    /// Record type not covered by any of the other variants, identified
+    /// by its numeric code, constructed using [`Type::from_code`].
+    ///
+    /// Displayed and parsed using the generic `TYPE123` syntax, unless
+    /// a mnemonic is registered for it with the [`MnemonicRegistry`].
+    ///
+    /// [3597](https://datatracker.ietf.org/doc/html/rfc3597)
+    Unknown(UnknownType),
+}
+
+/// Code of a [`Type::Unknown`], which never identifies any of the other
+/// variants, such that every type has a single representation.
+#[cfg_attr(feature = "valuable", derive(valuable::Valuable))]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct UnknownType(u16);
+
+impl UnknownType {
+    /// Numeric code of the type.
+    pub const fn code(&self) -> u16 {
+        self.0
+    }
+}
+
+/// Broad classification of record types, see [`Type::category`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Category {
+    /// Address records, A and AAAA.
+    Address,
+    /// Records used for signing and authenticating zones with DNSSEC.
+    DnsSec,
+    /// Meta and query types, such as OPT, TSIG or ANY, which only appear
+    /// in DNS messages and never in zones.
+    Pseudo,
+    /// Types which have been obsoleted or moved to historic status.
+    Obsolete,
+    /// Any other type.
+    Data,
 }
 
 impl Type {
@@ -314,9 +408,15 @@ impl Type {
     pub fn is_afsdb(&self) -> bool {
         *self == Self::AFSDB
     }
+    pub fn is_any(&self) -> bool {
+        *self == Self::ANY
+    }
     pub fn is_apl(&self) -> bool {
         *self == Self::APL
     }
+    pub fn is_axfr(&self) -> bool {
+        *self == Self::AXFR
+    }
     pub fn is_caa(&self) -> bool {
         *self == Self::CAA
     }
@@ -368,6 +468,9 @@ impl Type {
     pub fn is_ipseckey(&self) -> bool {
         *self == Self::IPSECKEY
     }
+    pub fn is_ixfr(&self) -> bool {
+        *self == Self::IXFR
+    }
     pub fn is_key(&self) -> bool {
         *self == Self::KEY
     }
@@ -377,6 +480,9 @@ impl Type {
     pub fn is_loc(&self) -> bool {
         *self == Self::LOC
     }
+    pub fn is_minfo(&self) -> bool {
+        *self == Self::MINFO
+    }
     pub fn is_mx(&self) -> bool {
         *self == Self::MX
     }
@@ -386,6 +492,9 @@ impl Type {
     pub fn is_ns(&self) -> bool {
         *self == Self::NS
     }
+    pub fn is_nsap(&self) -> bool {
+        *self == Self::NSAP
+    }
     pub fn is_nsec(&self) -> bool {
         *self == Self::NSEC
     }
@@ -395,9 +504,15 @@ impl Type {
     pub fn is_nsec3param(&self) -> bool {
         *self == Self::NSEC3PARAM
     }
+    pub fn is_null(&self) -> bool {
+        *self == Self::NULL
+    }
     pub fn is_openpgpkey(&self) -> bool {
         *self == Self::OPENPGPKEY
     }
+    pub fn is_opt(&self) -> bool {
+        *self == Self::OPT
+    }
     pub fn is_ptr(&self) -> bool {
         *self == Self::PTR
     }
@@ -407,6 +522,9 @@ impl Type {
     pub fn is_rp(&self) -> bool {
         *self == Self::RP
     }
+    pub fn is_rt(&self) -> bool {
+        *self == Self::RT
+    }
     pub fn is_sig(&self) -> bool {
         *self == Self::SIG
     }
@@ -443,61 +561,436 @@ impl Type {
     pub fn is_uri(&self) -> bool {
         *self == Self::URI
     }
+    pub fn is_wks(&self) -> bool {
+        *self == Self::WKS
+    }
+    pub fn is_x25(&self) -> bool {
+        *self == Self::X25
+    }
     pub fn is_zonemd(&self) -> bool {
         *self == Self::ZONEMD
     }
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Self::Unknown(_))
+    }
+
+    /// Type which orders before all others, including unknown types,
+    /// for use as the lower bound of ranges of types.
+    pub const MIN: Type = Type::A;
+
+    /// Returns the category of the type.
+    ///
+    /// Types belonging to several categories are categorized as
+    /// [`Category::Pseudo`] before [`Category::Obsolete`], and
+    /// [`Category::Obsolete`] before any other, so DLV is obsolete
+    /// even though it is also [DNSSEC related](Type::is_dnssec_related).
+    pub const fn category(&self) -> Category {
+        if self.is_pseudo() {
+            Category::Pseudo
+        } else if self.is_obsolete() {
+            Category::Obsolete
+        } else if self.is_address() {
+            Category::Address
+        } else if self.is_dnssec_related() {
+            Category::DnsSec
+        } else {
+            Category::Data
+        }
+    }
+
+    /// Returns true for the A and AAAA address types.
+    pub const fn is_address(&self) -> bool {
+        matches!(self, Self::A | Self::AAAA)
+    }
+
+    /// Returns true for types used for signing and authenticating zones
+    /// with DNSSEC, including the delegation signer and key types.
+    pub const fn is_dnssec_related(&self) -> bool {
+        matches!(
+            self,
+            Self::CDNSKEY
+                | Self::CDS
+                | Self::DLV
+                | Self::DNSKEY
+                | Self::DS
+                | Self::KEY
+                | Self::NSEC
+                | Self::NSEC3
+                | Self::NSEC3PARAM
+                | Self::RRSIG
+                | Self::SIG
+                | Self::TA
+        )
+    }
+
+    /// Returns true for meta types, such as OPT, TSIG and TKEY, and query
+    /// types, such as AXFR and ANY, none of which can be stored in a zone.
+    ///
+    /// [6895](https://datatracker.ietf.org/doc/html/rfc6895#section-3.1)
+    pub const fn is_pseudo(&self) -> bool {
+        // OPT, followed by the range reserved for meta and query types.
+        matches!(self.code(), 41 | 128..=255)
+    }
+
+    /// Returns true for types which have been obsoleted or moved to
+    /// historic status, such as MD, WKS, A6, SPF and DLV.
+    pub const fn is_obsolete(&self) -> bool {
+        matches!(
+            self.code(),
+            3 | 4 | 7..=11 | 14 | 19..=23 | 26 | 27 | 30 | 38 | 99 | 32769
+        )
+    }
+
+    /// Returns true for types which are maintained by the zone itself, or
+    /// the software signing it, rather than written by its users: SOA,
+    /// ZONEMD and the signatures and denial of existence records of DNSSEC.
+    pub const fn is_zone_internal(&self) -> bool {
+        matches!(
+            self,
+            Self::NSEC | Self::NSEC3 | Self::NSEC3PARAM | Self::RRSIG | Self::SOA | Self::ZONEMD
+        )
+    }
+
+    /// Parses either the mnemonic of a type (such as `AAAA`), or the generic
+    /// `TYPE123` syntax, case-insensitively.
+    pub(crate) fn from_mnemonic(value: &str) -> Option<Self> {
+        let value = value.to_ascii_uppercase();
+
+        if value == "*" {
+            return Some(Self::ANY);
+        }
+
+        if let Some(r#type) = Self::from_known_mnemonic(&value) {
+            return Some(r#type);
+        }
+
+        let code = value.strip_prefix("TYPE")?;
+        if !code.starts_with(|c: char| c.is_ascii_digit()) {
+            return None;
+        }
+
+        Some(Self::from_code(code.parse().ok()?))
+    }
+}
+
+/// Generates the conversions between types, their codes and their
+/// mnemonics from a single table, such that they cannot disagree.
+macro_rules! known_types {
+    ($($variant:ident = $code:literal,)*) => {
+        impl Type {
+            /// Returns the numeric code identifying the type.
+            pub const fn code(&self) -> u16 {
+                match self {
+                    $(Self::$variant => $code,)*
+                    Self::Unknown(unknown) => unknown.0,
+                }
+            }
+
+            /// Returns the type identified by the numeric code, or [`Type::Unknown`]
+            /// if the code does not correspond to any other variant.
+            pub const fn from_code(code: u16) -> Self {
+                match code {
+                    $($code => Self::$variant,)*
+                    code => Self::Unknown(UnknownType(code)),
+                }
+            }
+
+            /// Mnemonic of the type, unless it is [`Type::Unknown`].
+            const fn known_mnemonic(&self) -> Option<&'static str> {
+                match self {
+                    $(Self::$variant => Some(stringify!($variant)),)*
+                    Self::Unknown(_) => None,
+                }
+            }
+
+            /// Type identified by the uppercase mnemonic, unless it is unknown.
+            fn from_known_mnemonic(value: &str) -> Option<Self> {
+                match value {
+                    $(stringify!($variant) => Some(Self::$variant),)*
+                    _ => None,
+                }
+            }
+        }
+
+        /// Mnemonics of all types, other than [`Type::Unknown`].
+        #[cfg(feature = "schemars")]
+        const MNEMONICS: &[&str] = &[$(stringify!($variant),)*];
+    };
+}
+
+known_types! {
+    A = 1,
+    AAAA = 28,
+    AFSDB = 18,
+    ANY = 255,
+    APL = 42,
+    AXFR = 252,
+    CAA = 257,
+    CDNSKEY = 60,
+    CDS = 59,
+    CERT = 37,
+    CNAME = 5,
+    CSYNC = 62,
+    DHCID = 49,
+    DLV = 32769,
+    DNAME = 39,
+    DNSKEY = 48,
+    DS = 43,
+    EUI48 = 108,
+    EUI64 = 109,
+    HINFO = 13,
+    HIP = 55,
+    HTTPS = 65,
+    IPSECKEY = 45,
+    IXFR = 251,
+    KEY = 25,
+    KX = 36,
+    LOC = 29,
+    MINFO = 14,
+    MX = 15,
+    NAPTR = 35,
+    NS = 2,
+    NSAP = 22,
+    NSEC = 47,
+    NSEC3 = 50,
+    NSEC3PARAM = 51,
+    NULL = 10,
+    OPENPGPKEY = 61,
+    OPT = 41,
+    PTR = 12,
+    RRSIG = 46,
+    RP = 17,
+    RT = 21,
+    SIG = 24,
+    SMIMEA = 53,
+    SOA = 6,
+    SRV = 33,
+    SSHFP = 44,
+    SVCB = 64,
+    TA = 32768,
+    TKEY = 249,
+    TLSA = 52,
+    TSIG = 250,
+    TXT = 16,
+    URI = 256,
+    WKS = 11,
+    X25 = 19,
+    ZONEMD = 63,
 }
 
 impl Display for Type {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Self::A => f.write_str("A"),
-            Self::AAAA => f.write_str("AAAA"),
-            Self::AFSDB => f.write_str("AFSDB"),
-            Self::APL => f.write_str("APL"),
-            Self::CAA => f.write_str("CAA"),
-            Self::CDNSKEY => f.write_str("CDNSKEY"),
-            Self::CDS => f.write_str("CDS"),
-            Self::CERT => f.write_str("CERT"),
-            Self::CNAME => f.write_str("CNAME"),
-            Self::CSYNC => f.write_str("CSYNC"),
-            Self::DHCID => f.write_str("DHCID"),
-            Self::DLV => f.write_str("DLV"),
-            Self::DNAME => f.write_str("DNAME"),
-            Self::DNSKEY => f.write_str("DNSKEY"),
-            Self::DS => f.write_str("DS"),
-            Self::EUI48 => f.write_str("EUI48"),
-            Self::EUI64 => f.write_str("EUI64"),
-            Self::HINFO => f.write_str("HINFO"),
-            Self::HIP => f.write_str("HIP"),
-            Self::HTTPS => f.write_str("HTTPS"),
-            Self::IPSECKEY => f.write_str("IPSECKEY"),
-            Self::KEY => f.write_str("KEY"),
-            Self::KX => f.write_str("KX"),
-            Self::LOC => f.write_str("LOC"),
-            Self::MX => f.write_str("MX"),
-            Self::NAPTR => f.write_str("NAPTR"),
-            Self::NS => f.write_str("NS"),
-            Self::NSEC => f.write_str("NSEC"),
-            Self::NSEC3 => f.write_str("NSEC3"),
-            Self::NSEC3PARAM => f.write_str("NSEC3PARAM"),
-            Self::OPENPGPKEY => f.write_str("OPENPGPKEY"),
-            Self::PTR => f.write_str("PTR"),
-            Self::RRSIG => f.write_str("RRSIG"),
-            Self::RP => f.write_str("RP"),
-            Self::SIG => f.write_str("SIG"),
-            Self::SMIMEA => f.write_str("SMIMEA"),
-            Self::SOA => f.write_str("SOA"),
-            Self::SRV => f.write_str("SRV"),
-            Self::SSHFP => f.write_str("SSHFP"),
-            Self::SVCB => f.write_str("SVCB"),
-            Self::TA => f.write_str("TA"),
-            Self::TKEY => f.write_str("TKEY"),
-            Self::TLSA => f.write_str("TLSA"),
-            Self::TSIG => f.write_str("TSIG"),
-            Self::TXT => f.write_str("TXT"),
-            Self::URI => f.write_str("URI"),
-            Self::ZONEMD => f.write_str("ZONEMD"),
+        if let Some(mnemonic) = self.known_mnemonic() {
+            return f.write_str(mnemonic);
+        }
+
+        match MnemonicRegistry::global().type_mnemonic(self.code()) {
+            Some(mnemonic) => f.write_str(&mnemonic),
+            None => write!(f, "TYPE{}", self.code()),
+        }
+    }
+}
+
+/// Produced when attempting to parse an unknown [`Type`].
+#[derive(Error, Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum TypeError {
+    /// The string is neither a known mnemonic, nor a generic `TYPE123` type.
+    #[error("unknown record type {0:?}")]
+    UnknownType(String),
+}
+
+impl TryFrom<&str> for Type {
+    type Error = TypeError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Type::from_mnemonic(value)
+            .or_else(|| {
+                MnemonicRegistry::global()
+                    .type_code(value)
+                    .map(Type::from_code)
+            })
+            .ok_or_else(|| TypeError::UnknownType(value.to_string()))
+    }
+}
+
+impl TryFrom<String> for Type {
+    type Error = TypeError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+
+impl FromStr for Type {
+    type Err = TypeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+/// Regular expression matching any valid [`Type`], without anchors.
+///
+/// Matches case-insensitively, as types are parsed, using character
+/// classes since JSON Schema does not support flags.
+#[cfg(feature = "schemars")]
+pub(crate) fn type_regex() -> String {
+    let case_insensitive = |mnemonic: &str| -> String {
+        mnemonic
+            .chars()
+            .map(|c| match c {
+                'A'..='Z' => format!("[{c}{}]", c.to_ascii_lowercase()),
+                c => c.to_string(),
+            })
+            .collect()
+    };
+
+    let mnemonics: Vec<String> = MNEMONICS.iter().map(|m| case_insensitive(m)).collect();
+
+    format!(
+        "({}|\\*|{}[0-9]+)",
+        mnemonics.join("|"),
+        case_insensitive("TYPE")
+    )
+}
+
+#[cfg(feature = "schemars")]
+impl JsonSchema for Type {
+    fn schema_name() -> String {
+        String::from("Type")
+    }
+
+    fn json_schema(_: &mut schemars::gen::SchemaGenerator) -> Schema {
+        SchemaObject {
+            instance_type: Some(InstanceType::String.into()),
+            string: Some(Box::new(StringValidation {
+                pattern: Some(format!("^{}$", type_regex())),
+                ..Default::default()
+            })),
+            ..Default::default()
+        }
+        .into()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Type {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+
+        Type::try_from(value.as_str()).map_err(D::Error::custom)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Type {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.to_string().serialize(serializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{error::TypeError, Category, Type};
+
+    #[test]
+    fn codes() {
+        assert_eq!(Type::AAAA.code(), 28);
+        assert_eq!(Type::from_code(28), Type::AAAA);
+        assert_eq!(Type::from_code(65280), Type::from_code(65280));
+
+        for code in 0..=u16::MAX {
+            let r#type = Type::from_code(code);
+            assert_eq!(r#type.code(), code);
+
+            // Known codes are never represented as unknown types, and every
+            // type round-trips through its textual representation.
+            assert_eq!(r#type.is_unknown(), r#type.known_mnemonic().is_none());
+            assert_eq!(r#type.to_string().parse(), Ok(r#type));
+            assert!(Type::MIN <= r#type);
         }
     }
+
+    #[test]
+    #[cfg(all(feature = "schemars", feature = "regex"))]
+    fn schema_regex() {
+        let regex = regex::Regex::new(&format!("^{}$", crate::r#type::type_regex())).unwrap();
+
+        for valid in ["AAAA", "aaaa", "NSEC3PARAM", "*", "TYPE65280", "type65280"] {
+            assert!(regex.is_match(valid), "{valid}");
+        }
+        for invalid in ["BOGUS", "TYPE", "A A"] {
+            assert!(!regex.is_match(invalid), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn categories() {
+        assert_eq!(Type::AAAA.category(), Category::Address);
+        assert_eq!(Type::RRSIG.category(), Category::DnsSec);
+        assert_eq!(Type::TSIG.category(), Category::Pseudo);
+        assert_eq!(Type::from_code(253).category(), Category::Pseudo);
+        assert_eq!(Type::DLV.category(), Category::Obsolete);
+        assert_eq!(Type::from_code(99).category(), Category::Obsolete);
+        assert_eq!(Type::MX.category(), Category::Data);
+        assert_eq!(Type::from_code(65280).category(), Category::Data);
+
+        assert!(Type::DLV.is_dnssec_related());
+        assert!(Type::SOA.is_zone_internal());
+        assert!(!Type::DNSKEY.is_zone_internal());
+    }
+
+    #[test]
+    fn meta_types() {
+        assert_eq!("*".parse(), Ok(Type::ANY));
+        assert_eq!(Type::ANY.to_string(), "ANY");
+        assert_eq!("TYPE41".parse(), Ok(Type::OPT));
+        assert_eq!(Type::from_code(252), Type::AXFR);
+
+        for r#type in [Type::ANY, Type::AXFR, Type::IXFR, Type::OPT] {
+            assert_eq!(r#type.category(), Category::Pseudo);
+        }
+
+        for r#type in [
+            Type::MINFO,
+            Type::NSAP,
+            Type::NULL,
+            Type::RT,
+            Type::WKS,
+            Type::X25,
+        ] {
+            assert_eq!(r#type.to_string().parse(), Ok(r#type));
+            assert!(r#type.is_obsolete());
+        }
+    }
+
+    #[test]
+    fn generic_syntax() {
+        assert_eq!(Type::from_code(65280).to_string(), "TYPE65280");
+        assert_eq!("type65280".parse(), Ok(Type::from_code(65280)));
+        assert_eq!("TYPE28".parse(), Ok(Type::AAAA));
+        assert_eq!("aaaa".parse(), Ok(Type::AAAA));
+
+        for invalid in ["TYPE", "TYPE+1", "TYPE65536", "BOGUS"] {
+            assert_eq!(
+                invalid.parse::<Type>(),
+                Err(TypeError::UnknownType(invalid.to_string()))
+            );
+        }
+
+        #[cfg(feature = "serde")]
+        assert_eq!(
+            serde_yaml::from_str::<Type>(&serde_yaml::to_string(&Type::from_code(65280)).unwrap())
+                .unwrap(),
+            Type::from_code(65280)
+        );
+        #[cfg(feature = "serde")]
+        assert_eq!(serde_yaml::from_str::<Type>("MX").unwrap(), Type::MX);
+    }
 }
@@ -0,0 +1,176 @@
+use std::{collections::BTreeSet, fmt::Display, str::FromStr};
+
+#[cfg(feature = "schemars")]
+use schemars::{
+    schema::{InstanceType, Schema, SchemaObject, StringValidation},
+    JsonSchema,
+};
+#[cfg(feature = "serde")]
+use serde::{de::Error, Deserialize, Serialize};
+
+#[cfg(feature = "schemars")]
+use crate::r#type::type_regex;
+use crate::{error::TypeError, Type};
+
+/// Matches record [`Type`]s, either all of them (`*`), or any of
+/// a set of types separated by `|`, such as `A|AAAA`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TypePattern {
+    /// Matches all types.
+    Any,
+    /// Matches any of the contained types.
+    Types(BTreeSet<Type>),
+}
+
+impl TypePattern {
+    /// Returns true if the pattern matches the given type.
+    pub fn matches(&self, r#type: Type) -> bool {
+        match self {
+            TypePattern::Any => true,
+            TypePattern::Types(types) => types.contains(&r#type),
+        }
+    }
+}
+
+impl From<Type> for TypePattern {
+    fn from(value: Type) -> Self {
+        TypePattern::Types(BTreeSet::from([value]))
+    }
+}
+
+impl FromIterator<Type> for TypePattern {
+    fn from_iter<T: IntoIterator<Item = Type>>(iter: T) -> Self {
+        TypePattern::Types(iter.into_iter().collect())
+    }
+}
+
+impl TryFrom<&str> for TypePattern {
+    type Error = TypeError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        if value.trim() == "*" {
+            return Ok(TypePattern::Any);
+        }
+
+        value
+            .split('|')
+            .map(|r#type| Type::try_from(r#type.trim()))
+            .collect()
+    }
+}
+
+impl TryFrom<String> for TypePattern {
+    type Error = TypeError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+
+impl FromStr for TypePattern {
+    type Err = TypeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl Display for TypePattern {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TypePattern::Any => f.write_str("*"),
+            TypePattern::Types(types) => {
+                for (index, r#type) in types.iter().enumerate() {
+                    if index > 0 {
+                        f.write_str("|")?;
+                    }
+
+                    r#type.fmt(f)?;
+                }
+
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl JsonSchema for TypePattern {
+    fn schema_name() -> String {
+        String::from("TypePattern")
+    }
+
+    fn json_schema(_: &mut schemars::gen::SchemaGenerator) -> Schema {
+        let r#type = type_regex();
+
+        SchemaObject {
+            instance_type: Some(InstanceType::String.into()),
+            string: Some(Box::new(StringValidation {
+                pattern: Some(format!(r"^(\*|{type}(\|{type})*)$")),
+                ..Default::default()
+            })),
+            ..Default::default()
+        }
+        .into()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for TypePattern {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+
+        TypePattern::try_from(value).map_err(D::Error::custom)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for TypePattern {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.to_string().serialize(serializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{error::TypeError, Type, TypePattern};
+
+    #[test]
+    fn parse_and_match() {
+        let pattern: TypePattern = "AAAA | a".parse().unwrap();
+
+        assert!(pattern.matches(Type::A));
+        assert!(pattern.matches(Type::AAAA));
+        assert!(!pattern.matches(Type::CNAME));
+        assert_eq!(pattern.to_string(), "A|AAAA");
+
+        assert!(TypePattern::Any.matches(Type::from_code(65280)));
+        assert_eq!("*".parse(), Ok(TypePattern::Any));
+
+        assert_eq!(
+            "A|".parse::<TypePattern>(),
+            Err(TypeError::UnknownType(String::new()))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let patterns: Vec<TypePattern> = serde_yaml::from_str("[\"*\", A|AAAA, MX]").unwrap();
+
+        assert_eq!(
+            patterns,
+            [
+                TypePattern::Any,
+                TypePattern::from_iter([Type::A, Type::AAAA]),
+                TypePattern::from(Type::MX)
+            ]
+        );
+    }
+}
@@ -0,0 +1,70 @@
+use thiserror::Error;
+
+use crate::{fqdn::FullyQualifiedDomainNameError, FullyQualifiedDomainName};
+
+/// Produced when converting between internationalized and
+/// punycode-encoded domain names fails.
+#[derive(Error, Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum IdnaError {
+    /// The name is not a valid IDNA2008 domain name, or contains
+    /// an `xn--` label which is not valid punycode.
+    #[error("invalid internationalized domain name")]
+    InvalidName,
+    /// The encoded name is not a valid fully qualified domain name.
+    #[error("{0}")]
+    FullyQualifiedDomainNameError(#[from] FullyQualifiedDomainNameError),
+}
+
+impl FullyQualifiedDomainName {
+    /// Parses a fully qualified domain name which may contain non-ASCII
+    /// labels, such as `bücher.example.`, encoding them as punycode.
+    ///
+    /// Labels which are already punycode-encoded (`xn--...`) are validated.
+    pub fn from_unicode(value: &str) -> Result<Self, IdnaError> {
+        let ascii = idna::domain_to_ascii(value).map_err(|_| IdnaError::InvalidName)?;
+
+        Ok(FullyQualifiedDomainName::try_from(ascii)?)
+    }
+
+    /// Renders the domain name with all punycode-encoded (`xn--...`)
+    /// labels decoded.
+    pub fn to_unicode(&self) -> Result<String, IdnaError> {
+        let (unicode, result) = idna::domain_to_unicode(&self.to_string());
+        result.map_err(|_| IdnaError::InvalidName)?;
+
+        Ok(unicode)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{error::IdnaError, FullyQualifiedDomainName};
+
+    #[test]
+    fn round_trip() {
+        let fqdn = FullyQualifiedDomainName::from_unicode("Bücher.example.").unwrap();
+
+        assert_eq!(fqdn.to_string(), "xn--bcher-kva.example.");
+        assert_eq!(fqdn.to_unicode().unwrap(), "bücher.example.");
+
+        assert_eq!(
+            FullyQualifiedDomainName::from_unicode("xn--bcher-kva.example."),
+            Ok(fqdn)
+        );
+    }
+
+    #[test]
+    fn invalid_punycode() {
+        assert_eq!(
+            FullyQualifiedDomainName::from_unicode("xn--a.example."),
+            Err(IdnaError::InvalidName)
+        );
+
+        assert_eq!(
+            FullyQualifiedDomainName::try_from("xn--a.example.")
+                .unwrap()
+                .to_unicode(),
+            Err(IdnaError::InvalidName)
+        );
+    }
+}
@@ -0,0 +1,253 @@
+use std::collections::BTreeMap;
+
+use thiserror::Error;
+
+use crate::{
+    diff::Diff, error::PolicyViolation, Class, FullyQualifiedDomainName, RecordIdent, Zone,
+    ZonePolicy,
+};
+
+/// Produced when a view cannot be added to [`ZoneViews`].
+#[derive(Error, Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ViewError {
+    /// The view's origin differs from the origin shared by all views.
+    #[error("view {view} has origin {found}, expected {expected}")]
+    OriginMismatch {
+        view: String,
+        expected: FullyQualifiedDomainName,
+        found: FullyQualifiedDomainName,
+    },
+    /// The view's class differs from the class shared by all views.
+    #[error("view {view} has class {found}, expected {expected}")]
+    ClassMismatch {
+        view: String,
+        expected: Class,
+        found: Class,
+    },
+}
+
+/// Named views of a single zone, such as `internal` and `external`,
+/// for split-horizon deployments.
+///
+/// All views share the same origin and class, but each holds
+/// its own set of records.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ZoneViews {
+    origin: FullyQualifiedDomainName,
+    class: Class,
+    views: BTreeMap<String, Zone>,
+}
+
+/// Identities of all records in the zone.
+fn idents(zone: Option<&Zone>) -> Vec<RecordIdent> {
+    zone.into_iter()
+        .flat_map(Zone::iter)
+        .map(|record| record.ident())
+        .collect()
+}
+
+impl ZoneViews {
+    /// Constructs an empty set of [`Class::IN`] views with the given origin.
+    pub fn new(origin: FullyQualifiedDomainName) -> Self {
+        ZoneViews::with_class(origin, Class::IN)
+    }
+
+    /// Constructs an empty set of views with the given origin and class.
+    pub fn with_class(origin: FullyQualifiedDomainName, class: Class) -> Self {
+        ZoneViews {
+            origin,
+            class,
+            views: BTreeMap::new(),
+        }
+    }
+
+    /// Origin shared by all views.
+    pub fn origin(&self) -> &FullyQualifiedDomainName {
+        &self.origin
+    }
+
+    /// Class shared by all views.
+    pub fn class(&self) -> Class {
+        self.class
+    }
+
+    /// Adds a view, returning the view with the same name which it replaced, if any.
+    pub fn insert(
+        &mut self,
+        name: impl Into<String>,
+        zone: Zone,
+    ) -> Result<Option<Zone>, ViewError> {
+        let name = name.into();
+
+        if zone.origin() != &self.origin {
+            return Err(ViewError::OriginMismatch {
+                view: name,
+                expected: self.origin.clone(),
+                found: zone.origin().clone(),
+            });
+        }
+
+        if zone.class() != self.class {
+            return Err(ViewError::ClassMismatch {
+                view: name,
+                expected: self.class,
+                found: zone.class(),
+            });
+        }
+
+        Ok(self.views.insert(name, zone))
+    }
+
+    /// Returns the view with the given name, adding an empty one if missing.
+    pub fn view_or_default(&mut self, name: impl Into<String>) -> &mut Zone {
+        self.views
+            .entry(name.into())
+            .or_insert_with(|| Zone::with_class(self.origin.clone(), self.class))
+    }
+
+    /// Removes the view with the given name.
+    pub fn remove(&mut self, name: &str) -> Option<Zone> {
+        self.views.remove(name)
+    }
+
+    /// Returns the view with the given name, if present.
+    pub fn get(&self, name: &str) -> Option<&Zone> {
+        self.views.get(name)
+    }
+
+    /// Returns the view with the given name for modification, if present.
+    pub fn get_mut(&mut self, name: &str) -> Option<&mut Zone> {
+        self.views.get_mut(name)
+    }
+
+    /// Iterates over all views along with their names, ordered by name.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Zone)> + '_ {
+        self.views.iter().map(|(name, zone)| (name.as_str(), zone))
+    }
+
+    /// Number of views.
+    pub fn len(&self) -> usize {
+        self.views.len()
+    }
+
+    /// Returns true if there are no views.
+    pub fn is_empty(&self) -> bool {
+        self.views.is_empty()
+    }
+
+    /// Computes the changes required to go from view `from` to view `to`,
+    /// treating missing views as empty.
+    pub fn diff_views(&self, from: &str, to: &str) -> Diff {
+        Diff::between(&idents(self.get(from)), &idents(self.get(to)))
+    }
+
+    /// Computes the changes required to go from `current` to `self`,
+    /// per view.
+    ///
+    /// Views present in only one of the two are diffed against an empty
+    /// view, and views without any changes are omitted.
+    pub fn diff<'a>(&'a self, current: &'a ZoneViews) -> BTreeMap<&'a str, Diff> {
+        let names = self.views.keys().chain(current.views.keys());
+
+        names
+            .map(|name| {
+                let diff = Diff::between(&idents(current.get(name)), &idents(self.get(name)));
+                (name.as_str(), diff)
+            })
+            .filter(|(_, diff)| !diff.is_empty())
+            .collect()
+    }
+
+    /// Evaluates the policy against each view, returning the violations
+    /// of all views which have any.
+    pub fn evaluate(&self, policy: &ZonePolicy) -> BTreeMap<&str, Vec<PolicyViolation>> {
+        self.iter()
+            .map(|(name, zone)| (name, policy.evaluate(zone)))
+            .filter(|(_, violations)| !violations.is_empty())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::{PolicyViolation, ViewError},
+        fqdn,
+        record::record,
+        Class, Zone, ZonePolicy, ZoneViews,
+    };
+
+    fn views() -> ZoneViews {
+        let mut views = ZoneViews::new(fqdn!("example.org."));
+
+        let internal = views.view_or_default("internal");
+        internal
+            .insert(record("www.example.org. 300 IN A 10.0.0.1"))
+            .unwrap();
+        internal
+            .insert(record("db.example.org. 300 IN A 10.0.0.2"))
+            .unwrap();
+
+        let external = views.view_or_default("external");
+        external
+            .insert(record("www.example.org. 300 IN A 192.0.2.1"))
+            .unwrap();
+
+        views
+    }
+
+    #[test]
+    fn diffing() {
+        let views = views();
+
+        let diff = views.diff_views("external", "internal");
+        assert_eq!(diff.added.len(), 2);
+        assert_eq!(diff.removed.len(), 1);
+
+        let mut current = views.clone();
+        current.remove("internal");
+        current
+            .get_mut("external")
+            .unwrap()
+            .insert(record("old.example.org. 300 IN A 192.0.2.2"))
+            .unwrap();
+
+        let diffs = views.diff(&current);
+        assert_eq!(diffs.keys().collect::<Vec<_>>(), [&"external", &"internal"]);
+        assert_eq!(diffs["external"].removed.len(), 1);
+        assert_eq!(diffs["internal"].added.len(), 2);
+        assert!(views.diff(&views).is_empty());
+    }
+
+    #[test]
+    fn validation() {
+        let mut views = views();
+
+        assert_eq!(
+            views.insert("other", Zone::new(fqdn!("example.com."))),
+            Err(ViewError::OriginMismatch {
+                view: String::from("other"),
+                expected: fqdn!("example.org."),
+                found: fqdn!("example.com."),
+            })
+        );
+        assert!(matches!(
+            views.insert("other", Zone::with_class(fqdn!("example.org."), Class::CH)),
+            Err(ViewError::ClassMismatch { .. })
+        ));
+
+        let policy = ZonePolicy {
+            max_records: Some(1),
+            ..Default::default()
+        };
+
+        let violations = views.evaluate(&policy);
+        assert_eq!(
+            violations.into_iter().collect::<Vec<_>>(),
+            [(
+                "internal",
+                vec![PolicyViolation::TooManyRecords { count: 2, max: 1 }]
+            )]
+        );
+    }
+}
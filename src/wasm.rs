@@ -0,0 +1,79 @@
+//! JavaScript bindings for validating names, patterns and record identities
+//! client-side, using exactly the same rules as the rest of the crate.
+
+use wasm_bindgen::prelude::*;
+
+use crate::{
+    error::TypeError, Canonicalize, DomainName, FullyQualifiedDomainName,
+    PartiallyQualifiedDomainName, Pattern, RecordIdent, Suggest, Type,
+};
+
+/// Converts a parse error into a [`JsError`], including the suggestion
+/// for how to fix the input, if one is available.
+fn js_error<E: std::fmt::Display + Suggest>(err: E, input: &str) -> JsError {
+    match err.suggestion(input) {
+        Some(suggestion) => JsError::new(&format!("{err}: {suggestion}")),
+        None => JsError::new(&err.to_string()),
+    }
+}
+
+/// Parses a fully qualified domain name, returning its normalized form.
+#[wasm_bindgen(js_name = parseFullyQualifiedDomainName)]
+pub fn parse_fully_qualified_domain_name(input: &str) -> Result<String, JsError> {
+    FullyQualifiedDomainName::try_from(input)
+        .map(|fqdn| fqdn.to_string())
+        .map_err(|err| js_error(err, input))
+}
+
+/// Parses a partially qualified domain name, returning its normalized form.
+#[wasm_bindgen(js_name = parsePartiallyQualifiedDomainName)]
+pub fn parse_partially_qualified_domain_name(input: &str) -> Result<String, JsError> {
+    PartiallyQualifiedDomainName::try_from(input)
+        .map(|pqdn| pqdn.to_string())
+        .map_err(|err| js_error(err, input))
+}
+
+/// Parses either a fully or partially qualified domain name, returning its
+/// normalized form.
+#[wasm_bindgen(js_name = parseDomainName)]
+pub fn parse_domain_name(input: &str) -> Result<String, JsError> {
+    DomainName::try_from(input)
+        .map(|name| name.to_string())
+        .map_err(|err| js_error(err, input))
+}
+
+/// Parses a pattern, returning its normalized form.
+#[wasm_bindgen(js_name = parsePattern)]
+pub fn parse_pattern(input: &str) -> Result<String, JsError> {
+    Pattern::try_from(input)
+        .map(|pattern| pattern.to_string())
+        .map_err(|err| js_error(err, input))
+}
+
+/// Returns true if `pattern` matches the fully qualified domain name `fqdn`.
+#[wasm_bindgen(js_name = patternMatches)]
+pub fn pattern_matches(pattern: &str, fqdn: &str) -> Result<bool, JsError> {
+    let pattern = Pattern::try_from(pattern).map_err(|err| js_error(err, pattern))?;
+    let fqdn = FullyQualifiedDomainName::try_from(fqdn).map_err(|err| js_error(err, fqdn))?;
+
+    Ok(pattern.matches(&fqdn))
+}
+
+/// Validates the parts of a record identity, returning the canonical record data.
+#[wasm_bindgen(js_name = validateRecordIdent)]
+pub fn validate_record_ident(fqdn: &str, r#type: &str, rdata: &str) -> Result<String, JsError> {
+    let fqdn = FullyQualifiedDomainName::try_from(fqdn).map_err(|err| js_error(err, fqdn))?;
+
+    let r#type: Type = r#type
+        .parse()
+        .map_err(|err: TypeError| JsError::new(&err.to_string()))?;
+
+    let ident = RecordIdent::builder()
+        .fqdn(fqdn)
+        .r#type(r#type)
+        .rdata(rdata)
+        .build()
+        .map_err(|err| JsError::new(&err.to_string()))?;
+
+    Ok(ident.canonicalize().rdata.to_string())
+}
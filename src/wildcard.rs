@@ -0,0 +1,97 @@
+use crate::{
+    DomainName, DomainNameRef, FqdnRef, FullyQualifiedDomainName, PartiallyQualifiedDomainName,
+};
+
+/// Determines whether names containing a wildcard are accepted when parsing.
+///
+/// Some names must never be wildcards, such as those of reverse zones, or
+/// hostnames used in certificates. Rejecting them when parsing enforces
+/// this at the boundary, rather than when the name is eventually used.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum WildcardPolicy {
+    /// Wildcards are accepted as the first label of a name.
+    #[default]
+    Allow,
+    /// Names containing a wildcard are rejected.
+    Reject,
+}
+
+impl WildcardPolicy {
+    /// Returns true if a name which does or does not contain a
+    /// wildcard is permitted by this policy.
+    pub(crate) fn permits(&self, contains_wildcard: bool) -> bool {
+        *self == WildcardPolicy::Allow || !contains_wildcard
+    }
+}
+
+impl FullyQualifiedDomainName {
+    /// Returns true if the name is a wildcard, such as `*.example.org.`
+    pub fn contains_wildcard(&self) -> bool {
+        self.iter().any(|segment| segment.is_wildcard())
+    }
+}
+
+impl PartiallyQualifiedDomainName {
+    /// Returns true if the name is a wildcard, such as `*.example`
+    pub fn contains_wildcard(&self) -> bool {
+        self.iter().any(|segment| segment.is_wildcard())
+    }
+}
+
+impl DomainName {
+    /// Returns true if the name is a wildcard.
+    pub fn contains_wildcard(&self) -> bool {
+        match self {
+            DomainName::Full(full) => full.contains_wildcard(),
+            DomainName::Partial(partial) => partial.contains_wildcard(),
+        }
+    }
+}
+
+impl FqdnRef<'_> {
+    /// Returns true if the name is a wildcard, such as `*.example.org.`
+    pub fn contains_wildcard(&self) -> bool {
+        self.iter().any(|label| label == "*")
+    }
+}
+
+impl DomainNameRef<'_> {
+    /// Returns true if the name is a wildcard.
+    pub fn contains_wildcard(&self) -> bool {
+        self.iter().any(|label| label == "*")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::{DomainNameError, FullyQualifiedDomainNameError},
+        DomainName, DomainNameRef, FullyQualifiedDomainName, WildcardPolicy,
+    };
+
+    #[test]
+    fn policy() {
+        let wildcard =
+            FullyQualifiedDomainName::parse_with("*.example.org.", WildcardPolicy::Allow).unwrap();
+        assert!(wildcard.contains_wildcard());
+
+        assert_eq!(
+            FullyQualifiedDomainName::parse_with("*.example.org.", WildcardPolicy::Reject),
+            Err(FullyQualifiedDomainNameError::WildcardNotPermitted)
+        );
+        assert_eq!(
+            DomainName::parse_with("*.example", WildcardPolicy::Reject),
+            Err(DomainNameError::WildcardNotPermitted)
+        );
+
+        let name = DomainName::parse_with("www.example", WildcardPolicy::Reject).unwrap();
+        assert!(!name.contains_wildcard());
+
+        assert!(DomainNameRef::try_from("*.example")
+            .unwrap()
+            .contains_wildcard());
+        assert!(!DomainNameRef::try_from("www.example.")
+            .unwrap()
+            .contains_wildcard());
+    }
+}
@@ -0,0 +1,350 @@
+use std::collections::HashMap;
+
+use thiserror::Error;
+
+use crate::{
+    segment::{DomainSegment, DomainSegmentError},
+    FullyQualifiedDomainName,
+};
+
+/// Maximum length of a domain name in wire format, including length octets.
+const MAX_WIRE_LENGTH: usize = 255;
+
+/// Highest offset which can be the target of a compression pointer.
+const MAX_POINTER: usize = 0x3fff;
+
+/// Produced when decoding a wire-format domain name fails.
+#[derive(Error, Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum WireError {
+    /// The input ended before the terminating root label.
+    #[error("unexpected end of input")]
+    Truncated,
+    /// A label uses one of the reserved label types (`0b01` or `0b10`).
+    #[error("reserved label type {0:#04x}")]
+    ReservedLabelType(u8),
+    /// A compression pointer does not point strictly backwards,
+    /// which could otherwise lead to infinite loops.
+    #[error("invalid compression pointer to offset {0}")]
+    InvalidPointer(usize),
+    /// The decoded name is longer than the permitted 255 octets.
+    #[error("domain name too long")]
+    TooLong,
+    /// One of the labels is not a valid domain segment.
+    #[error("{0}")]
+    SegmentError(#[from] DomainSegmentError),
+    /// Wildcards must only appear in the very first label.
+    #[error("non-leading wildcard")]
+    NonLeadingWildcard,
+}
+
+impl FullyQualifiedDomainName {
+    /// Encodes the domain name in RFC 1035 wire format, as a sequence of
+    /// length-prefixed labels terminated by the zero-length root label.
+    ///
+    /// No compression is applied.
+    pub fn to_wire(&self) -> Vec<u8> {
+        let mut wire = Vec::with_capacity(self.len() + 1);
+
+        for segment in self.iter() {
+            wire.push(segment.len() as u8);
+            wire.extend_from_slice(segment.as_raw());
+        }
+
+        wire.push(0);
+        wire
+    }
+
+    /// Decodes a wire-format domain name at the start of `wire`, returning
+    /// the name and the number of bytes it occupies.
+    ///
+    /// Compression pointers are interpreted relative to the start of `wire`,
+    /// see [`FullyQualifiedDomainName::from_wire_at`] for decoding names
+    /// within a larger message.
+    pub fn from_wire(wire: &[u8]) -> Result<(Self, usize), WireError> {
+        Self::from_wire_at(wire, 0)
+    }
+
+    /// Decodes a wire-format domain name starting at `offset` within `message`,
+    /// returning the name and the number of bytes it occupies at `offset`.
+    ///
+    /// Compression pointers are followed, and interpreted as offsets
+    /// from the start of `message`.
+    pub fn from_wire_at(message: &[u8], offset: usize) -> Result<(Self, usize), WireError> {
+        let mut segments = Vec::new();
+        let mut position = offset;
+        let mut consumed = None;
+        let mut length = 0;
+
+        loop {
+            let label = *message.get(position).ok_or(WireError::Truncated)?;
+
+            match label >> 6 {
+                0b00 if label == 0 => break,
+                0b00 => {
+                    let end = position + 1 + label as usize;
+                    let bytes = message.get(position + 1..end).ok_or(WireError::Truncated)?;
+
+                    length += bytes.len() + 1;
+                    if length + 1 > MAX_WIRE_LENGTH {
+                        return Err(WireError::TooLong);
+                    }
+
+                    let segment = DomainSegment::from_raw(bytes)?;
+
+                    if segment.is_wildcard() && !segments.is_empty() {
+                        return Err(WireError::NonLeadingWildcard);
+                    }
+
+                    segments.push(segment);
+                    position = end;
+                }
+                0b11 => {
+                    let low = *message.get(position + 1).ok_or(WireError::Truncated)?;
+                    let target = usize::from(u16::from_be_bytes([label & 0b0011_1111, low]));
+
+                    if target >= position {
+                        return Err(WireError::InvalidPointer(target));
+                    }
+
+                    consumed.get_or_insert(position + 2 - offset);
+                    position = target;
+                }
+                _ => return Err(WireError::ReservedLabelType(label)),
+            }
+        }
+
+        let consumed = consumed.unwrap_or_else(|| position + 1 - offset);
+
+        Ok((FullyQualifiedDomainName::from_iter(segments), consumed))
+    }
+}
+
+/// Encodes many names into a single buffer, compressing names which share
+/// a suffix with a previously encoded name using pointers (RFC 1035, 4.1.4).
+///
+/// ```text
+/// encoder.encode(&"example.org.")     => 0   \x07example\x03org\x00
+/// encoder.encode(&"www.example.org.") => 13  \x03www\xc0\x00
+/// ```
+///
+/// Pointers are relative to the start of the buffer, so the buffer must
+/// be placed at the start of a message for them to remain valid.
+#[derive(Default, Debug, Clone)]
+pub struct NameEncoder {
+    buffer: Vec<u8>,
+    offsets: Vec<usize>,
+    suffixes: HashMap<Vec<DomainSegment>, u16>,
+}
+
+impl NameEncoder {
+    /// Constructs an encoder with an empty buffer.
+    pub fn new() -> Self {
+        NameEncoder::default()
+    }
+
+    /// Appends the name to the buffer, returning the offset at which it starts.
+    pub fn encode(&mut self, fqdn: &FullyQualifiedDomainName) -> usize {
+        let offset = self.buffer.len();
+        self.offsets.push(offset);
+
+        for (index, segment) in fqdn.iter().enumerate() {
+            let suffix = &fqdn.as_ref()[index..];
+
+            if let Some(target) = self.suffixes.get(suffix) {
+                self.buffer
+                    .extend_from_slice(&(0b1100_0000_0000_0000 | target).to_be_bytes());
+                return offset;
+            }
+
+            if self.buffer.len() <= MAX_POINTER {
+                self.suffixes
+                    .insert(suffix.to_vec(), self.buffer.len() as u16);
+            }
+
+            self.buffer.push(segment.len() as u8);
+            self.buffer.extend_from_slice(segment.as_raw());
+        }
+
+        self.buffer.push(0);
+        offset
+    }
+
+    /// Offsets of all encoded names within the buffer, in the order
+    /// they were encoded.
+    pub fn offsets(&self) -> &[usize] {
+        &self.offsets
+    }
+
+    /// Encoded names.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.buffer
+    }
+
+    /// Consumes the encoder, returning the encoded names.
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.buffer
+    }
+
+    /// Number of encoded names.
+    pub fn len(&self) -> usize {
+        self.offsets.len()
+    }
+
+    /// Returns true if no names have been encoded.
+    pub fn is_empty(&self) -> bool {
+        self.offsets.is_empty()
+    }
+}
+
+/// Decodes the consecutive names of a buffer produced by a [`NameEncoder`],
+/// yielding each name along with the offset at which it starts.
+///
+/// Decoding stops after the first error.
+#[derive(Debug, Clone)]
+pub struct NameDecoder<'a> {
+    buffer: &'a [u8],
+    position: usize,
+}
+
+impl<'a> NameDecoder<'a> {
+    /// Constructs a decoder for the names in `buffer`.
+    pub fn new(buffer: &'a [u8]) -> Self {
+        NameDecoder {
+            buffer,
+            position: 0,
+        }
+    }
+}
+
+impl Iterator for NameDecoder<'_> {
+    type Item = Result<(usize, FullyQualifiedDomainName), WireError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.position >= self.buffer.len() {
+            return None;
+        }
+
+        let offset = self.position;
+
+        match FullyQualifiedDomainName::from_wire_at(self.buffer, offset) {
+            Ok((fqdn, consumed)) => {
+                self.position += consumed;
+                Some(Ok((offset, fqdn)))
+            }
+            Err(err) => {
+                self.position = self.buffer.len();
+                Some(Err(err))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{error::WireError, FullyQualifiedDomainName, NameDecoder, NameEncoder};
+
+    #[test]
+    fn round_trip() {
+        let fqdn = FullyQualifiedDomainName::try_from("www.example.org.").unwrap();
+        let wire = fqdn.to_wire();
+
+        assert_eq!(wire, b"\x03www\x07example\x03org\x00");
+        assert_eq!(
+            FullyQualifiedDomainName::from_wire(&wire),
+            Ok((fqdn, wire.len()))
+        );
+
+        assert_eq!(
+            FullyQualifiedDomainName::from_wire(b"\x00"),
+            Ok((FullyQualifiedDomainName::default(), 1))
+        );
+    }
+
+    #[test]
+    fn compression() {
+        // "example.org." at offset 0, followed by "www" pointing back to it.
+        let message = b"\x07example\x03org\x00\x03www\xc0\x00";
+
+        assert_eq!(
+            FullyQualifiedDomainName::from_wire_at(message, 13),
+            Ok((
+                FullyQualifiedDomainName::try_from("www.example.org.").unwrap(),
+                6
+            ))
+        );
+
+        // Pointers must point backwards.
+        assert_eq!(
+            FullyQualifiedDomainName::from_wire(b"\xc0\x00"),
+            Err(WireError::InvalidPointer(0))
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            FullyQualifiedDomainName::from_wire(b"\x03www"),
+            Err(WireError::Truncated)
+        );
+        assert_eq!(
+            FullyQualifiedDomainName::from_wire(b"\x40"),
+            Err(WireError::ReservedLabelType(0x40))
+        );
+        assert_eq!(
+            FullyQualifiedDomainName::from_wire(b"\x03www\x01*\x00"),
+            Err(WireError::NonLeadingWildcard)
+        );
+        assert!(matches!(
+            FullyQualifiedDomainName::from_wire(b"\x02w-\x00"),
+            Err(WireError::SegmentError(_))
+        ));
+        assert_eq!(
+            FullyQualifiedDomainName::from_wire(b"\x03w\xffw\x00")
+                .map(|(fqdn, _)| fqdn.to_string()),
+            Ok(String::from("w\\255w."))
+        );
+        assert_eq!(
+            FullyQualifiedDomainName::from_wire(b"\x03w w\x00").map(|(fqdn, _)| fqdn.to_string()),
+            Ok(String::from("w\\032w."))
+        );
+
+        let long: Vec<u8> = std::iter::repeat_n(b"\x3f".iter().chain(&[b'a'; 63]), 4)
+            .flatten()
+            .copied()
+            .chain([0])
+            .collect();
+
+        assert_eq!(
+            FullyQualifiedDomainName::from_wire(&long),
+            Err(WireError::TooLong)
+        );
+    }
+
+    #[test]
+    fn batch_encoding() {
+        let names: Vec<_> = [
+            "example.org.",
+            "www.example.org.",
+            "www.example.org.",
+            "org.",
+        ]
+        .into_iter()
+        .map(|name| FullyQualifiedDomainName::try_from(name).unwrap())
+        .collect();
+
+        let mut encoder = NameEncoder::new();
+        for name in &names {
+            encoder.encode(name);
+        }
+
+        assert_eq!(encoder.offsets(), &[0, 13, 19, 21]);
+        assert_eq!(
+            encoder.as_bytes(),
+            b"\x07example\x03org\x00\x03www\xc0\x00\xc0\x0d\xc0\x08"
+        );
+
+        let decoded: Result<Vec<_>, _> = NameDecoder::new(encoder.as_bytes()).collect();
+        let expected: Vec<_> = encoder.offsets().iter().copied().zip(names).collect();
+        assert_eq!(decoded, Ok(expected));
+    }
+}
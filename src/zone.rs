@@ -0,0 +1,505 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use thiserror::Error;
+
+use crate::{Annotated, Class, FullyQualifiedDomainName, Record, RecordIdent, Type};
+
+/// Produced when attempting to insert an invalid record into a [`Zone`].
+#[derive(Error, Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ZoneError {
+    /// The record's fqdn is neither the zone's origin, nor a subdomain of it.
+    #[error("{0} is not within the zone")]
+    OutOfZone(FullyQualifiedDomainName),
+    /// The record's class differs from the zone's class.
+    #[error("cannot insert {found} record into {expected} zone")]
+    ClassMismatch { expected: Class, found: Class },
+    /// The record's data does not belong to a record of its type, such as
+    /// an A record holding the data of an MX record.
+    #[error("record data of {fqdn} is not valid for its type {expected}")]
+    TypeMismatch {
+        fqdn: FullyQualifiedDomainName,
+        expected: Type,
+    },
+}
+
+/// Produced when two zones cannot be merged.
+#[derive(Error, Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum MergeError {
+    /// The zones being merged have different origins.
+    #[error("cannot merge zone {right} into {left}")]
+    OriginMismatch {
+        left: FullyQualifiedDomainName,
+        right: FullyQualifiedDomainName,
+    },
+    /// The zones being merged have different classes.
+    #[error("cannot merge {right} zone into {left} zone")]
+    ClassMismatch { left: Class, right: Class },
+    /// The zones define conflicting record sets, and the merge was
+    /// performed using [`MergeStrategy::Error`].
+    #[error("{} conflicting records", .0.len())]
+    Conflicts(BTreeSet<RecordIdent>),
+}
+
+/// Determines how conflicting record sets are resolved when merging zones.
+///
+/// Two zones conflict when both define a record set for the same
+/// (fqdn, class, type), but the records within those sets differ.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MergeStrategy {
+    /// Keep the record set of the zone being merged into.
+    PreferLeft,
+    /// Keep the record set of the zone being merged.
+    PreferRight,
+    /// Fail the merge, reporting all conflicting records.
+    #[default]
+    Error,
+    /// Combine both record sets, using the lowest TTL of either
+    /// for all records in the resulting set.
+    MergeRecordSets,
+}
+
+/// Collection of records sharing a common origin and class.
+///
+/// Records may carry their [`Provenance`](crate::Provenance), see [`Zone::insert_annotated`],
+/// which is preserved when merging zones, but ignored when comparing them.
+///
+/// Converting a zone into a `Vec<RecordIdent>` drops the class and TTL of
+/// every record, see [`Zone::from_idents`] for the reverse. Converting it
+/// into a `Vec<Record>` retains them, see [`Zone::from_records`].
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Zone {
+    origin: FullyQualifiedDomainName,
+    class: Class,
+    records: BTreeMap<RecordIdent, Annotated<Record>>,
+}
+
+pub(crate) type RecordSetKey<'a> = (&'a FullyQualifiedDomainName, Class, Type);
+
+type RecordSets<'a> = BTreeMap<RecordSetKey<'a>, BTreeSet<&'a Annotated<Record>>>;
+
+impl Zone {
+    /// Constructs an empty [`Class::IN`] zone with the given origin.
+    pub fn new(origin: FullyQualifiedDomainName) -> Self {
+        Zone::with_class(origin, Class::IN)
+    }
+
+    /// Constructs an empty zone with the given origin and class.
+    pub fn with_class(origin: FullyQualifiedDomainName, class: Class) -> Self {
+        Zone {
+            origin,
+            class,
+            records: BTreeMap::new(),
+        }
+    }
+
+    /// Origin of the zone.
+    pub fn origin(&self) -> &FullyQualifiedDomainName {
+        &self.origin
+    }
+
+    /// Class of all records in the zone.
+    pub fn class(&self) -> Class {
+        self.class
+    }
+
+    /// Inserts a record into the zone, returning the record with the same
+    /// identity which it replaced, if any.
+    pub fn insert(&mut self, record: Record) -> Result<Option<Record>, ZoneError> {
+        self.insert_annotated(Annotated::from(record))
+            .map(|replaced| replaced.map(Annotated::into_inner))
+    }
+
+    /// Inserts a record along with its provenance, returning the record with
+    /// the same identity which it replaced, if any, along with its provenance.
+    pub fn insert_annotated(
+        &mut self,
+        record: Annotated<Record>,
+    ) -> Result<Option<Annotated<Record>>, ZoneError> {
+        if record.fqdn != self.origin && !record.fqdn.is_subdomain_of(&self.origin) {
+            return Err(ZoneError::OutOfZone(record.into_inner().fqdn));
+        }
+
+        if record.class != self.class {
+            return Err(ZoneError::ClassMismatch {
+                expected: self.class,
+                found: record.class,
+            });
+        }
+
+        if !record.rdata.is_valid_for(record.r#type) {
+            return Err(ZoneError::TypeMismatch {
+                expected: record.r#type,
+                fqdn: record.into_inner().fqdn,
+            });
+        }
+
+        Ok(self.records.insert(record.ident(), record))
+    }
+
+    /// Removes the record with the given identity from the zone.
+    pub fn remove(&mut self, ident: &RecordIdent) -> Option<Record> {
+        self.records.remove(ident).map(Annotated::into_inner)
+    }
+
+    /// Returns the record with the given identity, if present.
+    pub fn get(&self, ident: &RecordIdent) -> Option<&Record> {
+        self.get_annotated(ident).map(|record| &record.value)
+    }
+
+    /// Returns the record with the given identity along with its
+    /// provenance, if present.
+    pub fn get_annotated(&self, ident: &RecordIdent) -> Option<&Annotated<Record>> {
+        self.records.get(ident)
+    }
+
+    /// Iterates over all records in the zone, ordered by identity.
+    pub fn iter(&self) -> impl Iterator<Item = &Record> + '_ {
+        self.iter_annotated().map(|record| &record.value)
+    }
+
+    /// Iterates over all records in the zone along with their provenance,
+    /// ordered by identity.
+    pub fn iter_annotated(&self) -> impl Iterator<Item = &Annotated<Record>> + '_ {
+        self.records.values()
+    }
+
+    /// Number of records in the zone.
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    /// Returns true if the zone contains no records.
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    /// Iterates over the identities of all records in the zone, in order.
+    pub fn idents(&self) -> impl Iterator<Item = &RecordIdent> + '_ {
+        self.records.keys()
+    }
+
+    /// Constructs a zone of the given class from records, such that
+    /// converting the zone back into a `Vec<Record>` yields the same records.
+    pub fn from_records(
+        origin: FullyQualifiedDomainName,
+        class: Class,
+        records: impl IntoIterator<Item = Record>,
+    ) -> Result<Zone, ZoneError> {
+        let mut zone = Zone::with_class(origin, class);
+
+        for record in records {
+            zone.insert(record)?;
+        }
+
+        Ok(zone)
+    }
+
+    /// Constructs a [`Class::IN`] zone from record identities, all of which
+    /// are given the same `ttl`.
+    ///
+    /// This cannot restore the TTLs of a zone converted into record
+    /// identities, see [`Zone::from_records`] for a lossless alternative.
+    pub fn from_idents(
+        origin: FullyQualifiedDomainName,
+        idents: impl IntoIterator<Item = RecordIdent>,
+        ttl: u32,
+    ) -> Result<Zone, ZoneError> {
+        let mut zone = Zone::new(origin);
+
+        for ident in idents {
+            zone.insert(ident.into_record(zone.class, ttl))?;
+        }
+
+        Ok(zone)
+    }
+
+    /// Asserts that every record belongs in the zone, and is stored
+    /// under its own identity.
+    ///
+    /// Intended to be called by fuzzers and property tests after
+    /// operations such as [`Zone::merge`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the invariants upheld by [`Zone::insert`] are violated.
+    pub fn debug_validate(&self) {
+        self.origin.debug_validate();
+
+        for (ident, record) in &self.records {
+            record.fqdn.debug_validate();
+
+            assert_eq!(ident, &record.ident(), "record stored under wrong identity");
+            assert!(
+                record.fqdn == self.origin || record.fqdn.is_subdomain_of(&self.origin),
+                "{} is not within {}",
+                record.fqdn,
+                self.origin
+            );
+            assert_eq!(record.class, self.class, "record class differs from zone");
+            assert!(
+                record.rdata.is_valid_for(record.r#type),
+                "record data of {} is not valid for {}",
+                record.fqdn,
+                record.r#type
+            );
+        }
+    }
+
+    pub(crate) fn record_sets(&self) -> RecordSets<'_> {
+        let mut sets: BTreeMap<_, BTreeSet<_>> = BTreeMap::new();
+
+        for record in self.records.values() {
+            sets.entry((&record.fqdn, record.class, record.r#type))
+                .or_default()
+                .insert(record);
+        }
+
+        sets
+    }
+
+    /// Returns the identities of all records which are part of record sets
+    /// defined differently in `self` and `other`.
+    pub fn conflicts(&self, other: &Zone) -> BTreeSet<RecordIdent> {
+        let right = other.record_sets();
+
+        self.record_sets()
+            .into_iter()
+            .filter_map(|(key, left)| Some((left, right.get(&key)?)))
+            .flat_map(|(left, right)| {
+                left.symmetric_difference(right)
+                    .map(|record| record.ident())
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    /// Merges the records of `self` and `other` into a new zone, resolving
+    /// conflicting record sets according to `strategy`.
+    pub fn merge(&self, other: &Zone, strategy: MergeStrategy) -> Result<Zone, MergeError> {
+        if self.origin != other.origin {
+            return Err(MergeError::OriginMismatch {
+                left: self.origin.clone(),
+                right: other.origin.clone(),
+            });
+        }
+
+        if self.class != other.class {
+            return Err(MergeError::ClassMismatch {
+                left: self.class,
+                right: other.class,
+            });
+        }
+
+        let conflicts = self.conflicts(other);
+        if strategy == MergeStrategy::Error && !conflicts.is_empty() {
+            return Err(MergeError::Conflicts(conflicts));
+        }
+
+        let mut left = self.record_sets();
+        let mut merged = Zone::with_class(self.origin.clone(), self.class);
+
+        for (key, right) in other.record_sets() {
+            let records: Vec<Annotated<Record>> = match (left.remove(&key), strategy) {
+                (None, _) | (Some(_), MergeStrategy::PreferRight) => {
+                    right.into_iter().cloned().collect()
+                }
+                (Some(left), MergeStrategy::PreferLeft | MergeStrategy::Error) => {
+                    left.into_iter().cloned().collect()
+                }
+                (Some(left), MergeStrategy::MergeRecordSets) => {
+                    let ttl = left.iter().chain(&right).map(|record| record.ttl).min();
+
+                    left.union(&right)
+                        .map(|record| {
+                            (*record).clone().map(|record| Record {
+                                ttl: ttl.unwrap_or(record.ttl),
+                                ..record
+                            })
+                        })
+                        .collect()
+                }
+            };
+
+            merged
+                .records
+                .extend(records.into_iter().map(|record| (record.ident(), record)));
+        }
+
+        merged.records.extend(
+            left.into_values()
+                .flatten()
+                .map(|record| (record.ident(), record.clone())),
+        );
+
+        Ok(merged)
+    }
+}
+
+impl From<Zone> for Vec<RecordIdent> {
+    fn from(value: Zone) -> Self {
+        value.records.into_keys().collect()
+    }
+}
+
+impl From<&Zone> for Vec<RecordIdent> {
+    fn from(value: &Zone) -> Self {
+        value.idents().cloned().collect()
+    }
+}
+
+impl From<Zone> for Vec<Record> {
+    fn from(value: Zone) -> Self {
+        value
+            .records
+            .into_values()
+            .map(Annotated::into_inner)
+            .collect()
+    }
+}
+
+impl From<&Zone> for Vec<Record> {
+    fn from(value: &Zone) -> Self {
+        value.iter().cloned().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        record::record,
+        zone::{MergeError, MergeStrategy, ZoneError},
+        Class, FullyQualifiedDomainName, Record, RecordIdent, Type, Zone,
+    };
+
+    fn zone(records: impl IntoIterator<Item = Record>) -> Zone {
+        let mut zone = Zone::new(FullyQualifiedDomainName::try_from("example.org.").unwrap());
+        for record in records {
+            zone.insert(record).unwrap();
+        }
+        zone
+    }
+
+    #[test]
+    fn out_of_zone() {
+        let mut zone = zone([]);
+        assert_eq!(
+            zone.insert(record("www.example.com. 300 IN A 10.0.0.1")),
+            Err(ZoneError::OutOfZone(
+                FullyQualifiedDomainName::try_from("www.example.com.").unwrap()
+            ))
+        );
+    }
+
+    #[test]
+    fn class_mismatch() {
+        let mut zone = zone([]);
+        let mut record = record("www.example.org. 300 IN A 10.0.0.1");
+        record.class = Class::CH;
+
+        assert_eq!(
+            zone.insert(record),
+            Err(ZoneError::ClassMismatch {
+                expected: Class::IN,
+                found: Class::CH
+            })
+        );
+
+        let chaos = Zone::with_class(zone.origin().clone(), Class::CH);
+        assert_eq!(
+            zone.merge(&chaos, MergeStrategy::PreferLeft),
+            Err(MergeError::ClassMismatch {
+                left: Class::IN,
+                right: Class::CH
+            })
+        );
+    }
+
+    #[test]
+    fn type_mismatch() {
+        let mut zone = zone([]);
+        let mut record = record("www.example.org. 300 IN A 10.0.0.1");
+        record.r#type = Type::AAAA;
+
+        assert_eq!(
+            zone.insert(record),
+            Err(ZoneError::TypeMismatch {
+                fqdn: FullyQualifiedDomainName::try_from("www.example.org.").unwrap(),
+                expected: Type::AAAA
+            })
+        );
+    }
+
+    #[test]
+    fn merge_strategies() {
+        let left = zone([
+            record("www.example.org. 300 IN A 10.0.0.1"),
+            record("example.org. 300 IN MX 10 mail.example.org."),
+        ]);
+
+        let right = zone([
+            record("www.example.org. 60 IN A 10.0.0.2"),
+            record("api.example.org. 300 IN A 10.0.0.3"),
+        ]);
+
+        let conflicts = left.conflicts(&right);
+        assert_eq!(conflicts.len(), 2);
+
+        assert_eq!(
+            left.merge(&right, MergeStrategy::Error),
+            Err(MergeError::Conflicts(conflicts))
+        );
+
+        let merged = left.merge(&right, MergeStrategy::PreferLeft).unwrap();
+        assert_eq!(merged.len(), 3);
+        assert!(merged.get(&left.iter().last().unwrap().ident()).is_some());
+
+        let merged = left.merge(&right, MergeStrategy::PreferRight).unwrap();
+        assert_eq!(merged.len(), 3);
+        assert!(merged
+            .iter()
+            .any(|record| record.rdata.to_string() == "10.0.0.2" && record.ttl == 60));
+
+        let merged = left.merge(&right, MergeStrategy::MergeRecordSets).unwrap();
+        merged.debug_validate();
+        assert_eq!(merged.len(), 4);
+        assert!(merged
+            .iter()
+            .filter(|record| record.fqdn == *"www.example.org.")
+            .all(|record| record.ttl == 60));
+    }
+
+    #[test]
+    fn identical_zones_do_not_conflict() {
+        let left = zone([record("www.example.org. 300 IN A 10.0.0.1")]);
+
+        assert_eq!(
+            left.merge(&left.clone(), MergeStrategy::Error).unwrap(),
+            left
+        );
+    }
+
+    #[test]
+    fn ident_conversions() {
+        let zone = zone([
+            record("www.example.org. 300 IN A 10.0.0.1"),
+            record("example.org. 60 IN MX 10 mail.example.org."),
+        ]);
+
+        let idents = Vec::<RecordIdent>::from(&zone);
+        assert_eq!(idents, zone.idents().cloned().collect::<Vec<_>>());
+        assert_eq!(
+            RecordIdent::from(zone.iter().next().unwrap().clone()),
+            idents[0]
+        );
+
+        let restored = Zone::from_idents(zone.origin().clone(), idents, 300).unwrap();
+        assert_eq!(restored.len(), 2);
+        assert!(restored.iter().all(|record| record.ttl == 300));
+        assert_eq!(Vec::<RecordIdent>::from(restored), Vec::from(&zone));
+
+        let records = Vec::<Record>::from(&zone);
+        let restored = Zone::from_records(zone.origin().clone(), zone.class(), records).unwrap();
+        assert_eq!(restored, zone);
+        assert_eq!(Vec::<Record>::from(restored), Vec::from(&zone));
+    }
+}
@@ -0,0 +1,274 @@
+use std::{fmt::Display, net::IpAddr};
+
+use thiserror::Error;
+
+use crate::FullyQualifiedDomainName;
+
+/// Produced when a [`ZoneStatement`] cannot be rendered.
+#[derive(Error, Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ZoneStatementError {
+    /// Control characters cannot be represented within quoted strings
+    /// of either configuration syntax.
+    #[error("control character in quoted string: {0:?}")]
+    ControlCharacter(String),
+}
+
+/// Name server software for which configuration is generated.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ServerFlavor {
+    /// ISC BIND `named.conf` syntax.
+    #[default]
+    Bind,
+    /// NLnet Labs NSD `nsd.conf` syntax.
+    Nsd,
+}
+
+/// Role of the server for a zone.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ZoneRole {
+    /// The server loads the zone from its file, and serves transfers to the peers.
+    #[default]
+    Primary,
+    /// The server transfers the zone from the peers, storing it in its file.
+    Secondary,
+}
+
+/// Server configuration declaring a single zone, such as:
+///
+/// ```text
+/// zone "example.org" {
+///     type master;
+///     file "/var/lib/bind/example.org.zone";
+/// };
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ZoneStatement {
+    pub origin: FullyQualifiedDomainName,
+    /// Path of the zone file, as seen by the server.
+    pub file: String,
+    pub role: ZoneRole,
+    /// For primaries, the secondaries which may transfer the zone and are
+    /// notified of changes. For secondaries, the primaries to transfer from.
+    pub peers: Vec<IpAddr>,
+}
+
+impl ZoneStatement {
+    /// Constructs the statement for a primary zone without peers.
+    pub fn new(origin: FullyQualifiedDomainName, file: impl Into<String>) -> Self {
+        ZoneStatement {
+            origin,
+            file: file.into(),
+            role: ZoneRole::Primary,
+            peers: Vec::new(),
+        }
+    }
+
+    /// Sets the role of the server for the zone.
+    pub fn with_role(mut self, role: ZoneRole) -> Self {
+        self.role = role;
+        self
+    }
+
+    /// Sets the peers of the server for the zone.
+    pub fn with_peers(mut self, peers: impl IntoIterator<Item = IpAddr>) -> Self {
+        self.peers = peers.into_iter().collect();
+        self
+    }
+
+    /// Renders the statement in the configuration syntax of the given server.
+    ///
+    /// Fails if the name of the zone or the path of its file contains
+    /// control characters, which cannot be quoted.
+    pub fn display(
+        &self,
+        flavor: ServerFlavor,
+    ) -> Result<ZoneStatementDisplay<'_>, ZoneStatementError> {
+        for value in [self.name(), self.file.clone()] {
+            if value.chars().any(char::is_control) {
+                return Err(ZoneStatementError::ControlCharacter(value));
+            }
+        }
+
+        Ok(ZoneStatementDisplay {
+            statement: self,
+            flavor,
+        })
+    }
+
+    /// Name of the zone as written in configuration files, without the
+    /// trailing dot, except for the root zone.
+    fn name(&self) -> String {
+        match self.origin.to_string().strip_suffix('.') {
+            Some("") | None => String::from("."),
+            Some(name) => name.to_string(),
+        }
+    }
+}
+
+/// Quotes the value as a string in either configuration syntax, escaping
+/// only quotes and backslashes.
+///
+/// Control characters must have been rejected beforehand.
+fn quote(value: &str) -> String {
+    let mut quoted = String::with_capacity(value.len() + 2);
+
+    quoted.push('"');
+    for character in value.chars() {
+        if matches!(character, '"' | '\\') {
+            quoted.push('\\');
+        }
+        quoted.push(character);
+    }
+    quoted.push('"');
+
+    quoted
+}
+
+/// Renders a [`ZoneStatement`], produced by [`ZoneStatement::display`].
+pub struct ZoneStatementDisplay<'a> {
+    statement: &'a ZoneStatement,
+    flavor: ServerFlavor,
+}
+
+impl ZoneStatementDisplay<'_> {
+    fn bind(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let statement = self.statement;
+
+        writeln!(f, "zone {} {{", quote(&statement.name()))?;
+
+        match statement.role {
+            ZoneRole::Primary => writeln!(f, "    type master;")?,
+            ZoneRole::Secondary => writeln!(f, "    type slave;")?,
+        }
+
+        writeln!(f, "    file {};", quote(&statement.file))?;
+
+        if !statement.peers.is_empty() {
+            let peers: String = statement
+                .peers
+                .iter()
+                .map(|peer| format!(" {peer};"))
+                .collect();
+
+            match statement.role {
+                ZoneRole::Primary => {
+                    writeln!(f, "    allow-transfer {{{peers} }};")?;
+                    writeln!(f, "    also-notify {{{peers} }};")?;
+                }
+                ZoneRole::Secondary => writeln!(f, "    masters {{{peers} }};")?,
+            }
+        }
+
+        writeln!(f, "}};")
+    }
+
+    fn nsd(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let statement = self.statement;
+
+        writeln!(f, "zone:")?;
+        writeln!(f, "    name: {}", quote(&statement.name()))?;
+        writeln!(f, "    zonefile: {}", quote(&statement.file))?;
+
+        let options: &[&str] = match statement.role {
+            ZoneRole::Primary => &["notify", "provide-xfr"],
+            ZoneRole::Secondary => &["allow-notify", "request-xfr"],
+        };
+
+        for option in options {
+            for peer in &statement.peers {
+                writeln!(f, "    {option}: {peer} NOKEY")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for ZoneStatementDisplay<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.flavor {
+            ServerFlavor::Bind => self.bind(f),
+            ServerFlavor::Nsd => self.nsd(f),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{IpAddr, Ipv4Addr};
+
+    use crate::{
+        error::ZoneStatementError, FullyQualifiedDomainName, ServerFlavor, ZoneRole, ZoneStatement,
+    };
+
+    fn statement() -> ZoneStatement {
+        ZoneStatement::new(
+            FullyQualifiedDomainName::try_from("example.org.").unwrap(),
+            "/var/lib/dns/example.org.zone",
+        )
+    }
+
+    #[test]
+    fn bind() {
+        assert_eq!(
+            statement().display(ServerFlavor::Bind).unwrap().to_string(),
+            "zone \"example.org\" {\n    type master;\n    file \"/var/lib/dns/example.org.zone\";\n};\n"
+        );
+
+        let secondary = statement()
+            .with_role(ZoneRole::Secondary)
+            .with_peers([IpAddr::V4(Ipv4Addr::new(192, 0, 2, 1))]);
+
+        assert_eq!(
+            secondary.display(ServerFlavor::Bind).unwrap().to_string(),
+            "zone \"example.org\" {\n    type slave;\n    file \"/var/lib/dns/example.org.zone\";\n    masters { 192.0.2.1; };\n};\n"
+        );
+    }
+
+    #[test]
+    fn nsd() {
+        let primary = statement().with_peers([
+            IpAddr::V4(Ipv4Addr::new(192, 0, 2, 1)),
+            IpAddr::V4(Ipv4Addr::new(192, 0, 2, 2)),
+        ]);
+
+        assert_eq!(
+            primary.display(ServerFlavor::Nsd).unwrap().to_string(),
+            "zone:
+    name: \"example.org\"
+    zonefile: \"/var/lib/dns/example.org.zone\"
+    notify: 192.0.2.1 NOKEY
+    notify: 192.0.2.2 NOKEY
+    provide-xfr: 192.0.2.1 NOKEY
+    provide-xfr: 192.0.2.2 NOKEY
+"
+        );
+
+        let root = ZoneStatement::new(FullyQualifiedDomainName::default(), "root.zone");
+        assert!(root
+            .display(ServerFlavor::Nsd)
+            .unwrap()
+            .to_string()
+            .contains("name: \".\""));
+    }
+
+    #[test]
+    fn quoting() {
+        let mut statement = statement();
+        statement.file = String::from("C:\\zones\\\"example\".zone");
+
+        assert!(statement
+            .display(ServerFlavor::Bind)
+            .unwrap()
+            .to_string()
+            .contains("file \"C:\\\\zones\\\\\\\"example\\\".zone\";"));
+
+        statement.file = String::from("example.org\n.zone");
+        assert_eq!(
+            statement.display(ServerFlavor::Nsd).err(),
+            Some(ZoneStatementError::ControlCharacter(String::from(
+                "example.org\n.zone"
+            )))
+        );
+    }
+}
@@ -0,0 +1,761 @@
+//! Parsing and writing of RFC 1035 master files ("zone files").
+
+use thiserror::Error;
+
+use crate::{
+    error::{DomainNameError, RecordDataError},
+    ttl::parse_duration,
+    Class, DomainName, FullyQualifiedDomainName, Record, RecordData, TtlStagger, Type,
+};
+
+/// Produced when a zone file cannot be parsed.
+///
+/// Line numbers start at 1, and refer to the line on which the offending
+/// entry starts.
+#[derive(Error, Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ZoneFileError {
+    /// Parentheses are not balanced.
+    #[error("line {0}: unbalanced parentheses")]
+    UnbalancedParentheses(usize),
+    /// A quoted character string is missing its closing quote.
+    #[error("line {0}: unterminated character string")]
+    UnterminatedString(usize),
+    /// Directive is not one of `$ORIGIN`, `$TTL` or `$INCLUDE`.
+    #[error("line {0}: unknown directive {1}")]
+    UnknownDirective(usize, String),
+    /// Directive is missing its argument.
+    #[error("line {0}: missing argument for {1}")]
+    MissingArgument(usize, String),
+    /// Record omits its owner, but no previous record defines one.
+    #[error("line {0}: missing owner name")]
+    MissingOwner(usize),
+    /// Record omits its TTL, but neither a `$TTL` directive nor a previous
+    /// record defines one.
+    #[error("line {0}: missing ttl")]
+    MissingTtl(usize),
+    /// Record does not specify a known record type.
+    #[error("line {0}: missing or unknown record type")]
+    MissingType(usize),
+    /// TTL is not a valid number of seconds.
+    #[error("line {0}: invalid ttl {1:?}")]
+    InvalidTtl(usize, String),
+    /// Owner name, or name given in a directive, is invalid.
+    #[error("line {line}: invalid name {name:?}: {error}")]
+    InvalidName {
+        line: usize,
+        name: String,
+        error: DomainNameError,
+    },
+    /// Record data is not valid for the record type.
+    #[error("line {line}: invalid record data: {error}")]
+    InvalidRecordData { line: usize, error: RecordDataError },
+    /// File referenced by an `$INCLUDE` directive could not be read.
+    #[error("line {line}: failed to include {path}: {message}")]
+    Include {
+        line: usize,
+        path: String,
+        message: String,
+    },
+    /// File referenced by an `$INCLUDE` directive is already being included,
+    /// directly or through other files.
+    #[error("line {line}: {path} includes itself")]
+    RecursiveInclude { line: usize, path: String },
+    /// `$INCLUDE` directives are nested more than
+    /// [`ZoneFileParser::MAX_INCLUDE_DEPTH`] levels deep.
+    #[error("line {line}: including {path} exceeds the maximum include depth")]
+    IncludeTooDeep { line: usize, path: String },
+}
+
+/// Parser for RFC 1035 master files.
+///
+/// Supports the `$ORIGIN`, `$TTL` and `$INCLUDE` directives, comments,
+/// parentheses spanning multiple lines, omitted owners, TTLs and classes,
+/// as well as names relative to the current origin, including `@`.
+pub struct ZoneFileParser<'a> {
+    origin: FullyQualifiedDomainName,
+    include: Box<Include<'a>>,
+    /// Paths of the files currently being included, outermost first.
+    includes: Vec<String>,
+}
+
+type Include<'a> = dyn FnMut(&str) -> std::io::Result<String> + 'a;
+
+impl<'a> ZoneFileParser<'a> {
+    /// Maximum nesting depth of `$INCLUDE` directives.
+    pub const MAX_INCLUDE_DEPTH: usize = 16;
+
+    /// Constructs a parser with the given initial origin.
+    ///
+    /// Files referenced by `$INCLUDE` directives are read from the filesystem,
+    /// see [`ZoneFileParser::include_with`] for overriding this.
+    pub fn new(origin: FullyQualifiedDomainName) -> Self {
+        ZoneFileParser {
+            origin,
+            include: Box::new(|path: &str| std::fs::read_to_string(path)),
+            includes: Vec::new(),
+        }
+    }
+
+    /// Resolves the contents of files referenced by `$INCLUDE` directives
+    /// using `include`, instead of reading them from the filesystem.
+    pub fn include_with(
+        mut self,
+        include: impl FnMut(&str) -> std::io::Result<String> + 'a,
+    ) -> Self {
+        self.include = Box::new(include);
+        self
+    }
+
+    /// Parses the zone file, returning the records in order of appearance.
+    pub fn parse(&mut self, input: &str) -> Result<Vec<Record>, ZoneFileError> {
+        let mut state = State {
+            origin: self.origin.clone(),
+            default_ttl: None,
+            owner: None,
+            ttl: None,
+            class: Class::default(),
+        };
+
+        let mut records = Vec::new();
+        self.parse_into(input, &mut state, &mut records)?;
+        Ok(records)
+    }
+
+    fn parse_into(
+        &mut self,
+        input: &str,
+        state: &mut State,
+        records: &mut Vec<Record>,
+    ) -> Result<(), ZoneFileError> {
+        for entry in entries(input)? {
+            match entry.tokens[0].as_str() {
+                directive if directive.starts_with('$') && !entry.inherits_owner => {
+                    self.directive(&entry, state, records)?
+                }
+                _ => records.push(state.record(&entry)?),
+            }
+        }
+
+        Ok(())
+    }
+
+    fn directive(
+        &mut self,
+        entry: &Entry,
+        state: &mut State,
+        records: &mut Vec<Record>,
+    ) -> Result<(), ZoneFileError> {
+        let directive = entry.tokens[0].to_ascii_uppercase();
+        let argument = entry
+            .tokens
+            .get(1)
+            .ok_or_else(|| ZoneFileError::MissingArgument(entry.line, directive.clone()))?;
+
+        match directive.as_str() {
+            "$ORIGIN" => state.origin = state.resolve(entry.line, argument)?,
+            "$TTL" => state.default_ttl = Some(ttl(entry.line, argument)?),
+            "$INCLUDE" => {
+                if self.includes.contains(argument) {
+                    return Err(ZoneFileError::RecursiveInclude {
+                        line: entry.line,
+                        path: argument.clone(),
+                    });
+                }
+
+                if self.includes.len() >= Self::MAX_INCLUDE_DEPTH {
+                    return Err(ZoneFileError::IncludeTooDeep {
+                        line: entry.line,
+                        path: argument.clone(),
+                    });
+                }
+
+                let contents = (self.include)(argument).map_err(|err| ZoneFileError::Include {
+                    line: entry.line,
+                    path: argument.clone(),
+                    message: err.to_string(),
+                })?;
+
+                // Directives within the included file do not affect the including file.
+                let mut included = state.clone();
+                if let Some(origin) = entry.tokens.get(2) {
+                    included.origin = state.resolve(entry.line, origin)?;
+                }
+
+                self.includes.push(argument.clone());
+                let result = self.parse_into(&contents, &mut included, records);
+                self.includes.pop();
+                result?;
+            }
+            _ => return Err(ZoneFileError::UnknownDirective(entry.line, directive)),
+        }
+
+        Ok(())
+    }
+}
+
+/// Parses the zone file using the given initial origin, reading files
+/// referenced by `$INCLUDE` directives from the filesystem.
+pub fn parse(input: &str, origin: FullyQualifiedDomainName) -> Result<Vec<Record>, ZoneFileError> {
+    ZoneFileParser::new(origin).parse(input)
+}
+
+#[derive(Clone)]
+struct State {
+    origin: FullyQualifiedDomainName,
+    default_ttl: Option<u32>,
+    owner: Option<FullyQualifiedDomainName>,
+    ttl: Option<u32>,
+    class: Class,
+}
+
+impl State {
+    fn resolve(&self, line: usize, name: &str) -> Result<FullyQualifiedDomainName, ZoneFileError> {
+        if name == "@" {
+            return Ok(self.origin.clone());
+        }
+
+        DomainName::try_from(name)
+            .and_then(|name| name.try_with_origin(&self.origin))
+            .map_err(|error| ZoneFileError::InvalidName {
+                line,
+                name: name.to_string(),
+                error,
+            })
+    }
+
+    fn record(&mut self, entry: &Entry) -> Result<Record, ZoneFileError> {
+        let mut tokens = entry.tokens.iter();
+
+        let owner = if entry.inherits_owner {
+            self.owner
+                .clone()
+                .ok_or(ZoneFileError::MissingOwner(entry.line))?
+        } else {
+            let owner = tokens
+                .next()
+                .ok_or(ZoneFileError::MissingOwner(entry.line))?;
+            self.resolve(entry.line, owner)?
+        };
+
+        let (mut ttl, mut class, mut r#type) = (None, None, None);
+
+        // TTL and class may appear in either order, both preceding the type.
+        for token in tokens.by_ref() {
+            if ttl.is_none() && token.starts_with(|c: char| c.is_ascii_digit()) {
+                ttl = Some(self::ttl(entry.line, token)?);
+            } else if let (None, Ok(parsed)) = (class, token.parse()) {
+                class = Some(parsed);
+            } else {
+                r#type = token.parse().ok();
+                break;
+            }
+        }
+
+        let r#type = r#type.ok_or(ZoneFileError::MissingType(entry.line))?;
+
+        let ttl = ttl
+            .or(self.default_ttl)
+            .or(self.ttl)
+            .ok_or(ZoneFileError::MissingTtl(entry.line))?;
+
+        let class = class.unwrap_or(self.class);
+
+        let origin = self.origin.to_string();
+        let mut rdata: Vec<String> = tokens
+            .map(|token| match token.as_str() {
+                "@" if r#type != Type::TXT => origin.clone(),
+                token => token.to_string(),
+            })
+            .collect();
+
+        // SOA timers may use the same unit syntax as TTLs.
+        if r#type == Type::SOA && rdata.len() == 7 {
+            for timer in &mut rdata[3..] {
+                *timer = self::ttl(entry.line, timer)?.to_string();
+            }
+        }
+
+        let rdata = RecordData::parse(r#type, &rdata.join(" "))
+            .map_err(|error| ZoneFileError::InvalidRecordData {
+                line: entry.line,
+                error,
+            })?
+            .with_origin(&self.origin);
+
+        self.owner = Some(owner.clone());
+        self.ttl = Some(ttl);
+        self.class = class;
+
+        Ok(Record {
+            fqdn: owner,
+            class,
+            r#type,
+            ttl,
+            rdata,
+        })
+    }
+}
+
+/// Single logical entry of a zone file, possibly spanning multiple lines.
+struct Entry {
+    line: usize,
+    /// Entry started with whitespace, so the owner of the previous record applies.
+    inherits_owner: bool,
+    /// Whitespace-separated tokens, with quoted strings kept intact including
+    /// their quotes, and comments and parentheses removed.
+    tokens: Vec<String>,
+}
+
+fn entries(input: &str) -> Result<Vec<Entry>, ZoneFileError> {
+    let mut entries = Vec::new();
+
+    let mut line = 1;
+    let mut entry = Entry {
+        line,
+        inherits_owner: false,
+        tokens: Vec::new(),
+    };
+    let mut token = String::new();
+
+    let (mut depth, mut quoted, mut escaped, mut comment, mut line_start) =
+        (0usize, false, false, false, true);
+
+    for c in input.chars() {
+        if line_start && depth == 0 && entry.tokens.is_empty() {
+            entry.line = line;
+            entry.inherits_owner = c == ' ' || c == '\t';
+        }
+        line_start = false;
+
+        if c == '\n' {
+            if quoted {
+                return Err(ZoneFileError::UnterminatedString(line));
+            }
+
+            finish(&mut token, &mut entry.tokens);
+            comment = false;
+            line += 1;
+            line_start = true;
+
+            if depth == 0 && !entry.tokens.is_empty() {
+                entries.push(std::mem::replace(
+                    &mut entry,
+                    Entry {
+                        line,
+                        inherits_owner: false,
+                        tokens: Vec::new(),
+                    },
+                ));
+            }
+
+            continue;
+        }
+
+        if comment {
+            continue;
+        }
+
+        if quoted {
+            token.push(c);
+
+            match (escaped, c) {
+                (false, '\\') => escaped = true,
+                (false, '"') => quoted = false,
+                _ => escaped = false,
+            }
+
+            continue;
+        }
+
+        // Escaped characters, such as `\;`, are part of the token.
+        if escaped {
+            token.push(c);
+            escaped = false;
+            continue;
+        }
+
+        match c {
+            '\\' => {
+                token.push(c);
+                escaped = true;
+            }
+            ';' => {
+                finish(&mut token, &mut entry.tokens);
+                comment = true;
+            }
+            '(' => {
+                finish(&mut token, &mut entry.tokens);
+                depth += 1;
+            }
+            ')' => {
+                finish(&mut token, &mut entry.tokens);
+                depth = depth
+                    .checked_sub(1)
+                    .ok_or(ZoneFileError::UnbalancedParentheses(line))?;
+            }
+            '"' => {
+                token.push(c);
+                quoted = true;
+            }
+            c if c.is_whitespace() => finish(&mut token, &mut entry.tokens),
+            c => token.push(c),
+        }
+    }
+
+    if quoted {
+        return Err(ZoneFileError::UnterminatedString(line));
+    }
+
+    if depth != 0 {
+        return Err(ZoneFileError::UnbalancedParentheses(entry.line));
+    }
+
+    finish(&mut token, &mut entry.tokens);
+    if !entry.tokens.is_empty() {
+        entries.push(entry);
+    }
+
+    Ok(entries)
+}
+
+fn finish(token: &mut String, tokens: &mut Vec<String>) {
+    if !token.is_empty() {
+        tokens.push(std::mem::take(token));
+    }
+}
+
+/// Parses a TTL, either as a plain number of seconds, or using
+/// the BIND unit syntax such as `1h30m`.
+fn ttl(line: usize, value: &str) -> Result<u32, ZoneFileError> {
+    parse_duration(value).ok_or_else(|| ZoneFileError::InvalidTtl(line, value.to_string()))
+}
+
+/// Renders [`Record`]s as an RFC 1035 master file.
+///
+/// ```text
+/// $ORIGIN example.org.
+/// $TTL 3600
+/// @   NS ns1
+/// www A  192.168.0.1
+/// ```
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct ZoneFileWriter {
+    origin: Option<FullyQualifiedDomainName>,
+    ttl: Option<u32>,
+    align: bool,
+    stagger: Option<TtlStagger>,
+}
+
+impl ZoneFileWriter {
+    /// Constructs a writer which renders all names fully qualified,
+    /// with explicit TTLs and unaligned columns.
+    pub fn new() -> Self {
+        ZoneFileWriter::default()
+    }
+
+    /// Emits an `$ORIGIN` directive, rendering the origin itself as `@`
+    /// and subdomains of it as relative names.
+    pub fn origin(mut self, origin: FullyQualifiedDomainName) -> Self {
+        self.origin = Some(origin);
+        self
+    }
+
+    /// Emits a `$TTL` directive, omitting the TTL of all records
+    /// which have this TTL.
+    pub fn ttl(mut self, ttl: u32) -> Self {
+        self.ttl = Some(ttl);
+        self
+    }
+
+    /// Pads all columns to the width of their widest value.
+    pub fn align(mut self, align: bool) -> Self {
+        self.align = align;
+        self
+    }
+
+    /// Staggers record TTLs before rendering them.
+    pub fn stagger(mut self, stagger: TtlStagger) -> Self {
+        self.stagger = Some(stagger);
+        self
+    }
+
+    /// Renders the records in the given order.
+    pub fn write<'a>(&self, records: impl IntoIterator<Item = &'a Record>) -> String {
+        let mut output = String::new();
+
+        if let Some(origin) = &self.origin {
+            output.push_str(&format!("$ORIGIN {origin}\n"));
+        }
+
+        if let Some(ttl) = self.ttl {
+            output.push_str(&format!("$TTL {ttl}\n"));
+        }
+
+        let rows: Vec<[String; 5]> = records
+            .into_iter()
+            .map(|record| {
+                let ttl = match self.stagger {
+                    Some(stagger) => stagger.ttl(record),
+                    None => record.ttl,
+                };
+
+                let ttl = match self.ttl {
+                    Some(default) if default == ttl => String::new(),
+                    _ => ttl.to_string(),
+                };
+
+                let (owner, rdata) = match &self.origin {
+                    Some(origin) => (
+                        record.fqdn.relative_display(origin).to_string(),
+                        record.rdata.relative_to(origin),
+                    ),
+                    None => (record.fqdn.to_string(), record.rdata.clone()),
+                };
+
+                [
+                    owner,
+                    ttl,
+                    record.class.to_string(),
+                    record.r#type.to_string(),
+                    rdata.to_string(),
+                ]
+            })
+            .collect();
+
+        let mut widths = [0; 4];
+        if self.align {
+            for row in &rows {
+                for (width, column) in widths.iter_mut().zip(row) {
+                    *width = (*width).max(column.len());
+                }
+            }
+        }
+
+        for row in &rows {
+            let mut line = String::new();
+
+            for (index, column) in row.iter().enumerate() {
+                let width = widths.get(index).copied().unwrap_or_default();
+
+                if column.is_empty() && width == 0 {
+                    continue;
+                }
+
+                if index > 0 {
+                    line.push(' ');
+                }
+
+                line.push_str(&format!("{column:<width$}"));
+            }
+
+            output.push_str(line.trim_end());
+            output.push('\n');
+        }
+
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        zonefile::{ZoneFileError, ZoneFileParser, ZoneFileWriter},
+        Class, FullyQualifiedDomainName, RecordData, TtlStagger, Type,
+    };
+
+    fn origin() -> FullyQualifiedDomainName {
+        FullyQualifiedDomainName::try_from("example.org.").unwrap()
+    }
+
+    #[test]
+    fn parse_zone() {
+        let zone = r#"
+$TTL 1h
+@   IN  SOA ns1 hostmaster (
+            2024010101 ; serial
+            1d         ; refresh
+            2h         ; retry
+            4w         ; expire
+            300 )      ; minimum
+    IN  NS  ns1
+    IN  NS  ns2.example.com.
+    IN  MX  10 @
+www 300 A   192.168.0.1
+        A   192.168.0.2
+txt IN  TXT "hello; world" "(quoted)"
+$ORIGIN sub.example.org.
+api     AAAA 2001:db8::1
+"#;
+
+        let records = ZoneFileParser::new(origin()).parse(zone).unwrap();
+
+        let rendered: Vec<_> = records
+            .iter()
+            .map(|record| {
+                format!(
+                    "{} {} {} {} {}",
+                    record.fqdn, record.ttl, record.class, record.r#type, record.rdata
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            rendered,
+            [
+                "example.org. 3600 IN SOA ns1.example.org. hostmaster.example.org. 2024010101 86400 7200 2419200 300",
+                "example.org. 3600 IN NS ns1.example.org.",
+                "example.org. 3600 IN NS ns2.example.com.",
+                "example.org. 3600 IN MX 10 example.org.",
+                "www.example.org. 300 IN A 192.168.0.1",
+                "www.example.org. 3600 IN A 192.168.0.2",
+                "txt.example.org. 3600 IN TXT \"hello; world\" \"(quoted)\"",
+                "api.sub.example.org. 3600 IN AAAA 2001:db8::1",
+            ]
+        );
+    }
+
+    #[test]
+    fn include() {
+        let records = ZoneFileParser::new(origin())
+            .include_with(|path| {
+                assert_eq!(path, "hosts.zone");
+                Ok(String::from("$TTL 60\nwww A 10.0.0.1\n"))
+            })
+            .parse("$INCLUDE hosts.zone internal.example.org.\nmail 300 A 10.0.0.2\n")
+            .unwrap();
+
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].fqdn, *"www.internal.example.org.");
+        assert_eq!(records[0].ttl, 60);
+        assert_eq!(records[1].fqdn, *"mail.example.org.");
+        assert_eq!(records[1].class, Class::IN);
+        assert_eq!(
+            records[1].rdata,
+            RecordData::parse(Type::A, "10.0.0.2").unwrap()
+        );
+    }
+
+    #[test]
+    fn recursive_include() {
+        let mut parser = ZoneFileParser::new(origin()).include_with(|path| match path {
+            "a.zone" => Ok(String::from("$INCLUDE b.zone\n")),
+            _ => Ok(String::from("$INCLUDE a.zone\n")),
+        });
+
+        assert_eq!(
+            parser.parse("$INCLUDE a.zone\n"),
+            Err(ZoneFileError::RecursiveInclude {
+                line: 1,
+                path: String::from("a.zone")
+            })
+        );
+
+        let mut depth = 0;
+        let mut parser = ZoneFileParser::new(origin()).include_with(|_| {
+            depth += 1;
+            Ok(format!("$INCLUDE {depth}.zone\n"))
+        });
+
+        assert!(matches!(
+            parser.parse("$INCLUDE 0.zone\n"),
+            Err(ZoneFileError::IncludeTooDeep { .. })
+        ));
+    }
+
+    #[test]
+    fn escaped_comment() {
+        let records = ZoneFileParser::new(origin())
+            .parse("a\\;b 60 A 10.0.0.1 ; comment\n")
+            .unwrap();
+
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].fqdn.to_string(), "a\\;b.example.org.");
+    }
+
+    #[test]
+    fn errors() {
+        let parse = |input| ZoneFileParser::new(origin()).parse(input);
+
+        assert_eq!(
+            parse("www 60 A 10.0.0.1\n\n  SOA (ns1 hostmaster 1 2 3 4 5\n"),
+            Err(ZoneFileError::UnbalancedParentheses(3))
+        );
+        assert_eq!(
+            parse("  60 A 10.0.0.1"),
+            Err(ZoneFileError::MissingOwner(1))
+        );
+        assert_eq!(parse("www A 10.0.0.1"), Err(ZoneFileError::MissingTtl(1)));
+        assert_eq!(
+            parse("www 60 BOGUS 10.0.0.1"),
+            Err(ZoneFileError::MissingType(1))
+        );
+        assert_eq!(
+            parse("$GENERATE 1-10 host$ A 10.0.0.$"),
+            Err(ZoneFileError::UnknownDirective(
+                1,
+                String::from("$GENERATE")
+            ))
+        );
+        assert!(matches!(
+            parse("$TTL 60\nwww A 10.0.0.256"),
+            Err(ZoneFileError::InvalidRecordData { line: 2, .. })
+        ));
+    }
+
+    #[test]
+    fn write_zone() {
+        let records = ZoneFileParser::new(origin())
+            .parse(
+                "$TTL 3600\n\
+                 @ NS ns1\n\
+                 @ MX 10 mail.example.com.\n\
+                 www 300 A 192.168.0.1\n\
+                 api CNAME www\n",
+            )
+            .unwrap();
+
+        assert_eq!(
+            ZoneFileWriter::new().write(&records),
+            "example.org. 3600 IN NS ns1.example.org.\n\
+             example.org. 3600 IN MX 10 mail.example.com.\n\
+             www.example.org. 300 IN A 192.168.0.1\n\
+             api.example.org. 3600 IN CNAME www.example.org.\n"
+        );
+
+        let written = ZoneFileWriter::new()
+            .origin(origin())
+            .ttl(3600)
+            .align(true)
+            .write(&records);
+
+        assert_eq!(
+            written,
+            "$ORIGIN example.org.\n\
+             $TTL 3600\n\
+             @       IN NS    ns1\n\
+             @       IN MX    10 mail.example.com.\n\
+             www 300 IN A     192.168.0.1\n\
+             api     IN CNAME www\n"
+        );
+
+        assert_eq!(
+            ZoneFileParser::new(origin()).parse(&written).unwrap(),
+            records
+        );
+    }
+
+    #[test]
+    fn write_staggered() {
+        let stagger = TtlStagger::new(1, 300);
+        let records = ZoneFileParser::new(origin())
+            .parse("$TTL 3600\nwww A 192.168.0.1\nwww A 192.168.0.2")
+            .unwrap();
+
+        let staggered = ZoneFileParser::new(origin())
+            .parse(&ZoneFileWriter::new().stagger(stagger).write(&records))
+            .unwrap();
+
+        assert_eq!(staggered[0].ttl, stagger.ttl(&records[0]));
+        assert_eq!(staggered[0].ttl, staggered[1].ttl);
+    }
+}